@@ -0,0 +1,43 @@
+use std::collections::BTreeSet;
+use std::ops::Bound::Excluded;
+use aoc_common::input::numbers;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        numbers(input)
+    }
+
+    fn part_one(_numbers: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part_two(numbers: &Self::Parsed) -> Answer {
+        let mut seen: BTreeSet<i32> = BTreeSet::new();
+
+        for &number in numbers {
+            let diff = 2020 - number;
+            let range = {
+                if diff > number {
+                    (Excluded(number), Excluded(diff))
+                } else {
+                    (Excluded(diff), Excluded(number))
+                }
+            };
+
+            for check in seen.range(range) {
+                let left = diff - check;
+                if seen.contains(&left) {
+                    return (number * check * left).into();
+                }
+            }
+            seen.insert(number);
+        }
+
+        Answer::Unsolved
+    }
+}
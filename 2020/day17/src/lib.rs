@@ -1,23 +1,16 @@
-use std::path::Path;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
+#![allow(clippy::explicit_counter_loop)]
+
 use std::collections::HashSet;
+use aoc_common::{Answer, Result, Solution};
 
 type Grid = HashSet<(i32, i32, i32, i32)>;
 type Point = (i32, i32, i32, i32);
 
-fn get_file_lines(file_name: &str) -> Lines<BufReader<File>> {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
-
-fn read_initial_grid(file_name: &str) -> Grid {
+fn read_initial_grid(input: &str) -> Grid {
     let mut grid = Grid::new();
-    let mut lines = get_file_lines(file_name);
 
     let mut y = 0;
-    while let Some(Ok(line)) = lines.next() {
+    for line in input.lines() {
         let mut x = 0;
         for c in line.chars() {
             if c == '#' {
@@ -71,25 +64,34 @@ fn execute_one_cycle(grid: &mut Grid) {
 
     let orig = grid.clone();
     for center in orig.iter() {
-        let count = count_adjacent(&orig, &center);
-        if count < 2 || count > 3 {
-            grid.remove(&center);
+        let count = count_adjacent(&orig, center);
+        if !(2..=3).contains(&count) {
+            grid.remove(center);
         }
-        check_inactive(&orig, grid, &mut inactive_checked, &center);
+        check_inactive(&orig, grid, &mut inactive_checked, center);
     }
 }
 
-fn test_input(file_name: &str) {
-    let mut grid = read_initial_grid(file_name);
+pub struct Day;
 
-    for _ in 0..6 {
-        execute_one_cycle(&mut grid);
+impl Solution for Day {
+    type Parsed = Grid;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(read_initial_grid(input))
     }
 
-    println!("For {}, active after 6 cycles is {}", file_name, grid.len());
-}
+    fn part_one(grid: &Self::Parsed) -> Answer {
+        let mut grid = grid.clone();
 
-fn main() {
-    test_input("sample.txt");
-    test_input("input.txt");
+        for _ in 0..6 {
+            execute_one_cycle(&mut grid);
+        }
+
+        grid.len().into()
+    }
+
+    fn part_two(_grid: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
 }
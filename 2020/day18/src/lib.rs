@@ -1,14 +1,9 @@
-use std::path::Path;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
+#![allow(clippy::needless_return, clippy::redundant_closure)]
+
 use std::slice::Iter;
 use std::iter::Peekable;
-
-fn get_file_lines(file_name: &str) -> Lines<BufReader<File>> {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Token {
@@ -69,15 +64,11 @@ fn skip_whitespace(chars: &mut Peekable<Iter<char>>) {
 }
 fn read_number(chars: &mut Peekable<Iter<char>>) -> i32 {
     let mut number_str = String::new();
-    loop {
-        if let Some(c) = chars.peek() {
-            if !c.is_numeric() {
-                break;
-            }
-            number_str.push(**c);
-        } else {
+    while let Some(c) = chars.peek() {
+        if !c.is_numeric() {
             break;
         }
+        number_str.push(**c);
         chars.next();
     }
     number_str.parse::<i32>().unwrap()
@@ -156,22 +147,31 @@ fn apply_part_two_rules(tokens: Vec<Token>) -> Vec<Token> {
     new_tokens
 }
 
-fn test_input(file_name: &str, print_each: bool) {
-    let results = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| parse_tokens(line))
-        .map(|tokens| apply_part_two_rules(tokens))
-        .map(|tokens| evaluate(tokens));
-    
-    if print_each {
-        println!("Results for {}:", file_name);
-        results.for_each(|result| println!("{}", result));
-    } else {
-        println!("For {}, Sum of all: {}", file_name, results.sum::<i64>());
-    }
+fn sum_results(lines: &[String], advanced: bool) -> i64 {
+    lines.iter()
+        .map(|line| parse_tokens(line.clone()))
+        .map(|tokens| match advanced {
+            true => apply_part_two_rules(tokens),
+            false => tokens
+        })
+        .map(|tokens| evaluate(tokens))
+        .sum::<i64>()
 }
 
-fn main() {
-    test_input("sample.txt", true);
-    test_input("input.txt", false);
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        sum_results(lines, false).into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        sum_results(lines, true).into()
+    }
 }
@@ -1,17 +1,12 @@
-use std::path::Path;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
+#![allow(clippy::new_ret_no_self, clippy::ptr_arg)]
+
 use std::collections::{HashSet, HashMap};
 use std::hash::{Hash, Hasher};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 type Rules = HashMap<String, Box<dyn Rule>>;
 
-fn get_file_lines(file_name: &str) -> Lines<BufReader<File>> {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
-
 trait Rule {
     fn matches(&self, input: VecIter, rule_lookup: &Rules) -> HashSet<VecIter>;
 }
@@ -137,7 +132,7 @@ fn parse_and(text: &String, parsed: &mut Rules) {
 }
 
 fn parse_or(id: &String, text: &String, parsed: &mut Rules) {
-    if text.chars().nth(0).unwrap() == '"' {
+    if text.starts_with('\"') {
         let literal = text.chars().nth(1).unwrap();
         parsed.insert(id.clone(), LiteralRule::new(literal));
     } else {
@@ -164,9 +159,8 @@ fn parse_rules(rule_strings: &HashMap<String, String>) -> Rules {
     parsed
 }
 
-fn test_input(file_name: &str, part_one: bool) {
-    let mut lines = get_file_lines(file_name)
-        .flat_map(|line| line.ok());
+fn count_matches(lines: &[String], part_one: bool) -> usize {
+    let mut lines = lines.iter();
     
     let mut rule_strings = (&mut lines)
         .take_while(|line| !line.is_empty())
@@ -185,7 +179,7 @@ fn test_input(file_name: &str, part_one: bool) {
 
     let zero = rules.get("0").unwrap();
 
-    let matches = lines.map(|line| line.chars().collect::<Vec<char>>())
+    lines.map(|line| line.chars().collect::<Vec<char>>())
         .filter(|line| {
             let iter = VecIter::new(line.clone());
             let matches = zero.matches(iter, &rules)
@@ -194,14 +188,23 @@ fn test_input(file_name: &str, part_one: bool) {
                 .count();
             matches > 0
         })
-        .count();
-    
-    println!("For {}, matches is {}", file_name, matches);
+        .count()
 }
 
-fn main() {
-    test_input("sample.txt", true);
-    test_input("input.txt", true);
-    test_input("sample.txt", false);
-    test_input("input.txt", false);
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        count_matches(lines, true).into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        count_matches(lines, false).into()
+    }
 }
@@ -1,28 +1,40 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
 use regex::Regex;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
-fn main() {
-    let path = Path::new("input.txt");
-    let file = File::open(path).unwrap();
+fn count_valid<F>(lines: &[String], is_valid: F) -> i32 
+    where F: Fn((i32, i32, char, &str)) -> bool 
+{
     let parser = Regex::new(r"([0-9]+)-([0-9]+) ([a-z]): ([a-z]+)").unwrap();
     
-    let mut valid_one: i32 = 0;
-    let mut valid_two: i32 = 0;
+    let mut valid: i32 = 0;
 
-    io::BufReader::new(file)
-        .lines()
+    lines.iter()
         .for_each(|line| {
-            let line_str = line.unwrap();
-            let tokens = parser.captures(line_str.as_str()).unwrap();
+            let tokens = parser.captures(line.as_str()).unwrap();
             let params = parse_tokens(tokens);
-            valid_one += valid_count(is_valid_password_pt1(params));
-            valid_two += valid_count(is_valid_password_pt2(params));
+            valid += valid_count(is_valid(params));
         });
     
-    println!("Valid Count Part One: {}", valid_one);
-    println!("Valid Count Part Two: {}", valid_two);
+    valid
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        count_valid(lines, is_valid_password_pt1).into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        count_valid(lines, is_valid_password_pt2).into()
+    }
 }
 
 fn valid_count(valid: bool) -> i32 {
@@ -32,10 +44,10 @@ fn valid_count(valid: bool) -> i32 {
     }
 }
 
-fn parse_tokens(tokens: regex::Captures) -> (i32, i32, char, &str) {
+fn parse_tokens(tokens: regex::Captures<'_>) -> (i32, i32, char, &str) {
     let min = tokens.get(1).unwrap().as_str().parse::<i32>().unwrap();
     let max = tokens.get(2).unwrap().as_str().parse::<i32>().unwrap();
-    let letter = tokens.get(3).unwrap().as_str().chars().nth(0).unwrap();
+    let letter = tokens.get(3).unwrap().as_str().chars().next().unwrap();
     let password = tokens.get(4).unwrap().as_str();
     (min, max, letter, password)
 }
@@ -54,4 +66,4 @@ fn is_valid_password_pt2(params: (i32, i32, char, &str)) -> bool {
     let first = chars[(first_pos as usize) - 1];
     let second = chars[(second_pos as usize) - 1];
     first != second && (first == letter || second == letter)
-}
\ No newline at end of file
+}
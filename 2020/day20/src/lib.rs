@@ -1,15 +1,13 @@
-use std::path::Path;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
-use std::collections::{HashSet, HashMap};
-
-type Input = Lines<BufReader<File>>;
+#![allow(
+    clippy::needless_range_loop,
+    clippy::println_empty_string,
+    clippy::ptr_arg,
+    clippy::redundant_closure,
+)]
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use std::collections::{HashSet, HashMap};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 #[derive(Debug, Clone)]
 struct Edge {
@@ -26,9 +24,7 @@ impl Edge {
         }
     }
     fn flip(&mut self) -> Edge {
-        let swap = self.forward;
-        self.forward = self.reverse;
-        self.reverse = swap;
+        std::mem::swap(&mut self.forward, &mut self.reverse);
         self.clone()
     }
     fn is(&self, edge: u16) -> bool {
@@ -64,7 +60,7 @@ impl Tile {
         }
     }
     fn has_adjacent_border(&self, edge: &u16) -> bool {
-        let edges = vec![&self.top, &self.right, &self.bottom, &self.left];
+        let edges = [&self.top, &self.right, &self.bottom, &self.left];
         for i in 0..4 {
             let check = &edges[i];
             if check.is(*edge) {
@@ -74,7 +70,7 @@ impl Tile {
         false
     }
     fn has_adjacent_edge(&self, one: &u16, two: &u16) -> bool {
-        let edges = vec![&self.top, &self.right, &self.bottom, &self.left];
+        let edges = [&self.top, &self.right, &self.bottom, &self.left];
         for i in 0..4 {
             let check = &edges[i];
             if check.is(*one) {
@@ -124,16 +120,16 @@ impl Tile {
         self.right = right;
         self.flipped = !self.flipped;
     }
-    fn from_ascii(lines: &mut Input) -> Option<Tile> {
+    fn from_ascii<I: Iterator<Item = String>>(lines: &mut I) -> Option<Tile> {
         let id: u32;
-        if let Some(Ok(line)) = lines.next() {
+        if let Some(line) = lines.next() {
             id = line.split(" ")
                 .last().unwrap().strip_suffix(':')
                 .unwrap().parse::<u32>().unwrap();
         } else {
             return None;
         }
-        let tile_strings = lines.flat_map(|line| line.ok())
+        let tile_strings = lines.by_ref()
             .take_while(|line| !line.is_empty())
             .collect::<Vec<String>>();
 
@@ -206,37 +202,29 @@ impl TileInfo {
 
     fn get_tile_with_edge<F>(&self, edge: &u16, borders: usize, placed: &HashSet<u32>, filter: F) -> u32
     where F: Fn(&&Tile) -> bool {
-        self.edges.get(&edge).unwrap().iter()
+        self.edges.get(edge).unwrap().iter()
             .filter(|id| !placed.contains(id))
-            .map(|id| self.tiles.get(&id).unwrap())
+            .map(|id| self.tiles.get(id).unwrap())
             .filter(|tile| tile.borders == borders)
             .find(filter)
-            .expect(
-                format!(
-                    "No tile found with edge {} and borders {} - all edges: {:?} - tiles: {:?}", 
-                    edge, borders, self.edges.get(&edge), 
-                    self.edges.get(&edge).unwrap().iter().map(|id| self.tiles.get(&id).unwrap()).collect::<Vec<&Tile>>()
-                ).as_str()
-            )
+            .unwrap_or_else(|| panic!("No tile found with edge {} and borders {} - all edges: {:?} - tiles: {:?}", 
+                    edge, borders, self.edges.get(edge), 
+                    self.edges.get(edge).unwrap().iter().map(|id| self.tiles.get(id).unwrap()).collect::<Vec<&Tile>>()))
             .id
     }
 
     fn get_tile_with_adjacent_edges<F>(&self, one: &u16, two: &u16, borders: usize, placed: &HashSet<u32>, filter: F) -> u32
     where F: Fn(&&Tile) -> bool {
-        self.edges.get(&one).unwrap().iter()
+        self.edges.get(one).unwrap().iter()
             .filter(|id| !placed.contains(id))
-            .map(|id| self.tiles.get(&id).unwrap())
+            .map(|id| self.tiles.get(id).unwrap())
             .filter(|tile| tile.borders == borders)
             .filter(|tile| tile.has_adjacent_edge(one, two))
             .find(filter)
-            .expect(
-                format!(
-                    "No tile found with edges {}, {} and borders {} - edges one: {:?} - edges two: {:?} - tiles one: {:?} - tiles two: {:?}", 
-                    one, two, borders, self.edges.get(&one), self.edges.get(&two), 
-                    self.edges.get(&one).unwrap().iter().map(|id| self.tiles.get(&id).unwrap()).collect::<Vec<&Tile>>(),
-                    self.edges.get(&two).unwrap().iter().map(|id| self.tiles.get(&id).unwrap()).collect::<Vec<&Tile>>()
-                ).as_str()
-            )
+            .unwrap_or_else(|| panic!("No tile found with edges {}, {} and borders {} - edges one: {:?} - edges two: {:?} - tiles one: {:?} - tiles two: {:?}", 
+                    one, two, borders, self.edges.get(one), self.edges.get(two), 
+                    self.edges.get(one).unwrap().iter().map(|id| self.tiles.get(id).unwrap()).collect::<Vec<&Tile>>(),
+                    self.edges.get(two).unwrap().iter().map(|id| self.tiles.get(id).unwrap()).collect::<Vec<&Tile>>()))
             .id
     }
 
@@ -352,7 +340,7 @@ impl CompleteImage {
 
     fn flip(points: &HashSet<(usize, usize)>, size: usize) -> HashSet<(usize, usize)> {
         let max = size - 1;
-        points.into_iter().map(|(x, y)| {
+        points.iter().map(|(x, y)| {
             (max - *x, *y)
         }).collect::<HashSet<(usize, usize)>>()
     }
@@ -366,7 +354,7 @@ impl CompleteImage {
     // 4            =>  4 
     fn rotate(points: &HashSet<(usize, usize)>, size: usize) -> HashSet<(usize, usize)> {
         let max = size - 1;
-        points.into_iter().map(|(x, y)| {
+        points.iter().map(|(x, y)| {
             let x_new = max - *y;
             let y_new = *x;
             (x_new, y_new)
@@ -447,128 +435,139 @@ where F: Fn(&Tile) -> bool {
     tile.right.forward
 }
 
-fn test_input(file_name: &str) {
-    let mut lines = get_file_lines(file_name);
+fn read_tiles(lines: &[String]) -> TileInfo {
+    let mut lines = lines.iter().cloned();
     let mut tiles = TileInfo::new();
 
     while let Some(tile) = Tile::from_ascii(&mut lines) {
         tiles.add(tile);
     }
 
-    println!("For {}, Have {} tiles", file_name, tiles.tiles.len());
+    tiles
+}
 
-    let corners = tiles.assign_borders();
-    assert_eq!(4, corners.len());
+pub struct Day;
 
-    let corner_product = corners.iter()
-        .fold(1_u64, |product, id| product * *id as u64);
-    
-    println!("For {}, corner product is: {}", file_name, corner_product);
-    
-    let mut image = TempImage::new((tiles.tiles.len() as f64).sqrt() as usize);
-    println!("Square is {}", image.square);
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-    let mut below_edges = vec![];
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
 
-    // add the first tile (the top left corner)
-    let mut prev = align_and_add(corners[0], &mut image, &mut tiles, &mut below_edges, |tile| tile.top.border && tile.left.border, 0);
-    
-    // the rest of the tiles for the first row
-    for i in 1..image.square {
-        let num_borders = calc_required_borders(i, image.square, 2, 1);
-        let id = tiles.get_tile_with_edge(&prev, num_borders, &image.placed, |tile| tile.has_adjacent_border(&prev));
-        prev = align_and_add(id, &mut image, &mut tiles, &mut below_edges, |tile| tile.left.forward == prev, prev);
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let mut tiles = read_tiles(lines);
+
+        let corners = tiles.assign_borders();
+        assert_eq!(4, corners.len());
+
+        corners.iter()
+            .fold(1_u64, |product, id| product * *id as u64)
+            .into()
     }
-    
-    // do the middle rows
-    for _ in 2..image.square {
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut tiles = read_tiles(lines);
+
+        let corners = tiles.assign_borders();
+        assert_eq!(4, corners.len());
+
+        let mut image = TempImage::new((tiles.tiles.len() as f64).sqrt() as usize);
+
+        let mut below_edges = vec![];
+
+        // add the first tile (the top left corner)
+        let mut prev = align_and_add(corners[0], &mut image, &mut tiles, &mut below_edges, |tile| tile.top.border && tile.left.border, 0);
+
+        // the rest of the tiles for the first row
+        for i in 1..image.square {
+            let num_borders = calc_required_borders(i, image.square, 2, 1);
+            let id = tiles.get_tile_with_edge(&prev, num_borders, &image.placed, |tile| tile.has_adjacent_border(&prev));
+            prev = align_and_add(id, &mut image, &mut tiles, &mut below_edges, |tile| tile.left.forward == prev, prev);
+        }
+
+        // do the middle rows
+        for _ in 2..image.square {
+            image.next_row();
+
+            let mut new_below_edges = vec![];
+            // first tile
+            {
+                let above_edge = below_edges[0];
+                let id = tiles.get_tile_with_edge(&above_edge, 1, &image.placed, |tile| tile.has_adjacent_border(&above_edge));
+                prev = align_and_add(id, &mut image, &mut tiles, &mut new_below_edges, |tile| tile.left.border && tile.top.forward == above_edge, prev);
+            }
+
+            // rest of the tiles
+            for i in 1..image.square {
+                let num_borders = calc_required_borders(i, image.square, 1, 0);
+                let above_edge = below_edges[i];
+                let id = tiles.get_tile_with_adjacent_edges(&prev, &above_edge, num_borders, &image.placed, |_| true);
+                prev = align_and_add(id, &mut image, &mut tiles, &mut new_below_edges, |tile| tile.left.forward == prev, prev);
+            }
+
+            below_edges = new_below_edges;
+        }
+
+        // do the last row
         image.next_row();
 
-        let mut new_below_edges = vec![];
-        // first tile
+        let mut _new_below_edges = Vec::new();
+
+        // first tile 
         {
             let above_edge = below_edges[0];
-            let id = tiles.get_tile_with_edge(&above_edge, 1, &image.placed, |tile| tile.has_adjacent_border(&above_edge));
-            prev = align_and_add(id, &mut image, &mut tiles, &mut new_below_edges, |tile| tile.left.border && tile.top.forward == above_edge, prev);
+            let id = tiles.get_tile_with_edge(&above_edge, 2, &image.placed, |_| true);
+            prev = align_and_add(id, &mut image, &mut tiles, &mut _new_below_edges, |tile| tile.top.forward == above_edge, prev);
         }
 
         // rest of the tiles
         for i in 1..image.square {
-            let num_borders = calc_required_borders(i, image.square, 1, 0);
+            let num_borders = calc_required_borders(i, image.square, 2, 1);
             let above_edge = below_edges[i];
             let id = tiles.get_tile_with_adjacent_edges(&prev, &above_edge, num_borders, &image.placed, |_| true);
-            prev = align_and_add(id, &mut image, &mut tiles, &mut new_below_edges, |tile| tile.left.forward == prev, prev);
+            prev = align_and_add(id, &mut image, &mut tiles, &mut _new_below_edges, |tile| tile.left.forward == prev, prev);
         }
 
-        below_edges = new_below_edges;
-    }
-
-    // do the last row
-    image.next_row();
+        let mut complete = CompleteImage::new(image, tiles);
 
-    let mut _new_below_edges = Vec::new();
+        let sea_monster_lines = ["                  # ",
+            "#    ##    ##    ###",
+            " #  #  #  #  #  #   "].iter()
+        .map(|line| line.chars().collect::<Vec<char>>())
+        .collect::<Vec<Vec<char>>>();
 
-    // first tile 
-    {
-        let above_edge = below_edges[0];
-        let id = tiles.get_tile_with_edge(&above_edge, 2, &image.placed, |_| true);
-        prev = align_and_add(id, &mut image, &mut tiles, &mut _new_below_edges, |tile| tile.top.forward == above_edge, prev);
-    }
-    
-    // rest of the tiles
-    for i in 1..image.square {
-        let num_borders = calc_required_borders(i, image.square, 2, 1);
-        let above_edge = below_edges[i];
-        let id = tiles.get_tile_with_adjacent_edges(&prev, &above_edge, num_borders, &image.placed, |_| true);
-        prev = align_and_add(id, &mut image, &mut tiles, &mut _new_below_edges, |tile| tile.left.forward == prev, prev);
-    }
-
-    let mut complete = CompleteImage::new(image, tiles);
-
-    let sea_monster_lines = vec![
-        "                  # ",
-        "#    ##    ##    ###",
-        " #  #  #  #  #  #   "
-    ].iter()
-    .map(|line| line.chars().collect::<Vec<char>>())
-    .collect::<Vec<Vec<char>>>();
-
-    let mut sea_monster = HashSet::new();
-    for y in 0..3 {
-        let line = &sea_monster_lines[y];
-        for x in 0..line.len() {
-            if line[x] == '#' {
-                sea_monster.insert((x, y));
+        let mut sea_monster = HashSet::new();
+        for y in 0..3 {
+            let line = &sea_monster_lines[y];
+            for x in 0..line.len() {
+                if line[x] == '#' {
+                    sea_monster.insert((x, y));
+                }
             }
         }
-    }
 
-    let (width, height) = sea_monster.iter().fold((0, 0), |max, point| {
-        (max.0.max(point.0), max.1.max(point.1))
-    });
-    let search_region = (complete.size - width, complete.size - height);
+        let (width, height) = sea_monster.iter().fold((0, 0), |max, point| {
+            (max.0.max(point.0), max.1.max(point.1))
+        });
+        let search_region = (complete.size - width, complete.size - height);
 
-    fn rotate_and_find_monster(file_name: &str, complete: &mut CompleteImage, sea_monster: &HashSet<(usize, usize)>, search_region: (usize, usize)) -> bool {
-        for _ in 0..4 {
-            let instances = complete.count_instances(&sea_monster, search_region);
-            if instances > 0 {
-                let roughness = complete.points.len() - (instances * sea_monster.len());
-                println!("For {}, roughness is {} with {} monsters", file_name, roughness, instances);
-                return true;
+        fn rotate_and_find_monster(complete: &mut CompleteImage, sea_monster: &HashSet<(usize, usize)>, search_region: (usize, usize)) -> Option<usize> {
+            for _ in 0..4 {
+                let instances = complete.count_instances(sea_monster, search_region);
+                if instances > 0 {
+                    return Some(complete.points.len() - (instances * sea_monster.len()));
+                }
+                complete.rotate_self();
             }
-            complete.rotate_self();
+            None
         }
-        false
-    }
-    
-    if rotate_and_find_monster(file_name, &mut complete, &sea_monster, search_region) {
-        return;
-    }
-    complete.flip_self();
-    rotate_and_find_monster(file_name, &mut complete, &sea_monster, search_region);
-}
 
-fn main() {
-    test_input("sample.txt");
-    test_input("input.txt");
+        if let Some(roughness) = rotate_and_find_monster(&mut complete, &sea_monster, search_region) {
+            return roughness.into();
+        }
+        complete.flip_self();
+        rotate_and_find_monster(&mut complete, &sea_monster, search_region)
+            .map_or(Answer::Unsolved, Answer::from)
+    }
 }
@@ -1,15 +1,8 @@
-use std::path::Path;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
-use std::collections::{HashSet, HashMap, BTreeMap};
-
-type Input = Lines<BufReader<File>>;
+#![allow(clippy::assign_op_pattern)]
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use std::collections::{HashSet, HashMap, BTreeMap};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 fn parse_ingredients(line: String) -> (HashSet<String>, HashSet<String>) {
     let pieces = line.split(" (contains ").collect::<Vec<&str>>();
@@ -27,13 +20,11 @@ fn parse_ingredients(line: String) -> (HashSet<String>, HashSet<String>) {
     (ingredients, allergens)
 }
 
-fn test_input(file_name: &str) {
-    let lines = get_file_lines(file_name);
-
+fn identify_allergens(lines: &[String]) -> (HashMap<String, usize>, HashMap<String, String>) {
     let mut candidates = HashMap::<String, HashSet<String>>::new();
 
-    let ingredients_and_allergens = lines.flat_map(|line| line.ok())
-        .map(|line| parse_ingredients(line));
+    let ingredients_and_allergens = lines.iter()
+        .map(|line| parse_ingredients(line.clone()));
     
     let mut ingredient_counts = HashMap::<String, usize>::new();
     
@@ -77,26 +68,39 @@ fn test_input(file_name: &str) {
         });
     }
 
-    let safe_ingredient_counts = ingredient_counts.iter()
-        .filter(|ingredient| !ingredient_to_allergen.contains_key(ingredient.0))
-        .map(|ingredient| (ingredient.0.clone(), *ingredient.1))
-        .collect::<HashMap<String, usize>>();
-    
-    let safe_occurrences = safe_ingredient_counts.values().sum::<usize>();
-    println!("For {}, there are {} safe ingredients with {} occurrences", file_name, safe_ingredient_counts.len(), safe_occurrences);
-
-    let canonical_dangerous_ingredients = ingredient_to_allergen.iter()
-        .map(|entry| (entry.1.clone(), entry.0.clone()))
-        .collect::<BTreeMap<String, String>>()
-        .iter()
-        .map(|entry| entry.1.clone())
-        .collect::<Vec<String>>()
-        .join(",");
-    
-    println!("For {}, the canonical dangerous ingredient list is: {}", file_name, canonical_dangerous_ingredients);
+    (ingredient_counts, ingredient_to_allergen)
 }
 
-fn main() {
-    test_input("sample.txt");
-    test_input("input.txt");
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let (ingredient_counts, ingredient_to_allergen) = identify_allergens(lines);
+
+        let safe_ingredient_counts = ingredient_counts.iter()
+            .filter(|ingredient| !ingredient_to_allergen.contains_key(ingredient.0))
+            .map(|ingredient| (ingredient.0.clone(), *ingredient.1))
+            .collect::<HashMap<String, usize>>();
+
+        safe_ingredient_counts.values().sum::<usize>().into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let (_, ingredient_to_allergen) = identify_allergens(lines);
+
+        ingredient_to_allergen.iter()
+            .map(|entry| (entry.1.clone(), entry.0.clone()))
+            .collect::<BTreeMap<String, String>>()
+            .iter()
+            .map(|entry| entry.1.clone())
+            .collect::<Vec<String>>()
+            .join(",")
+            .into()
+    }
 }
@@ -1,18 +1,10 @@
-use std::path::Path;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
 use std::collections::{HashSet, LinkedList};
+use aoc_common::input::{lines, Lines};
+use aoc_common::{Answer, Result, Solution};
 
-fn get_file_lines(file_name: &str) -> Lines<BufReader<File>> {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
-
-fn read_deck(lines: &mut Lines<BufReader<File>>) -> LinkedList<usize> {
+fn read_deck(lines: &mut Lines) -> LinkedList<usize> {
     lines.next(); // skip player label
-    lines.flat_map(|line| line.ok())
-        .take_while(|line| !line.is_empty())
+    lines.take_while(|line| !line.is_empty())
         .map(|line| line.parse::<usize>().unwrap())
         .collect::<LinkedList<usize>>()
 }
@@ -79,15 +71,19 @@ fn recursive_combat(player1: LinkedList<usize>, player2: LinkedList<usize>) -> W
     }
 }
 
-fn test_input(file_name: &str) {
-    let mut lines = get_file_lines(file_name);
+type Decks = (LinkedList<usize>, LinkedList<usize>);
 
-    let mut player1 = read_deck(&mut lines);
-    let mut player2 = read_deck(&mut lines);
+fn play(decks: &Decks, recursive: bool) -> usize {
+    let (mut player1, mut player2) = decks.clone();
 
     while let Some(p1_card) = player1.pop_front() {
         if let Some(p2_card) = player2.pop_front() {
-            let (winner, card1, card2) = match play_recursive_combat_round(p1_card, p2_card, &player1, &player2) {
+            let round_winner = match recursive {
+                true => play_recursive_combat_round(p1_card, p2_card, &player1, &player2),
+                false if p1_card > p2_card => Winner::One,
+                false => Winner::Two
+            };
+            let (winner, card1, card2) = match round_winner {
                 Winner::One => (&mut player1, p1_card, p2_card),
                 Winner::Two => (&mut player2, p2_card, p1_card)
             };
@@ -104,14 +100,28 @@ fn test_input(file_name: &str) {
         false => player1
     };
 
-    let score = (0..winner.len()).zip(winner.iter().rev())
+    (0..winner.len()).zip(winner.iter().rev())
         .map(|(mult, card)| (mult + 1) * card)
-        .sum::<usize>();
-    
-    println!("For {}, score is: {}", file_name, score);
+        .sum::<usize>()
 }
 
-fn main() {
-    test_input("sample.txt");
-    test_input("input.txt");
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Decks;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let mut lines = lines(input).into_iter();
+        let player1 = read_deck(&mut lines);
+        let player2 = read_deck(&mut lines);
+        Ok((player1, player2))
+    }
+
+    fn part_one(decks: &Self::Parsed) -> Answer {
+        play(decks, false).into()
+    }
+
+    fn part_two(decks: &Self::Parsed) -> Answer {
+        play(decks, true).into()
+    }
 }
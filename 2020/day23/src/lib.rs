@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+use aoc_common::{Answer, Result, Solution};
+
+fn move_cups(current: i32, cups: &mut BTreeMap<i32, i32>) -> i32 {
+    let one = cups.remove(&current).unwrap();
+    let two = cups.remove(&one).unwrap();
+    let three = cups.remove(&two).unwrap();
+    let cur_next = cups.remove(&three).unwrap();
+
+    cups.insert(current, cur_next);
+
+    let (dest_cup, three_next) = if let Some(smaller) =  cups.range(0..current).last() {
+        smaller
+    } else {
+        cups.iter().last().unwrap()
+    };
+    let dest_cup = *dest_cup;
+    let three_next = *three_next;
+    
+    cups.insert(dest_cup, one);
+    cups.insert(one, two);
+    cups.insert(two, three);
+    cups.insert(three, three_next);
+
+    cur_next
+}
+
+fn get_final_order(cups: &BTreeMap<i32, i32>) -> String {
+    let mut final_order = String::new();
+    let mut next = 1;
+    loop {
+        let current = *cups.get(&next).unwrap();
+        if current == 1 {
+            break;
+        }
+        final_order.push_str(current.to_string().as_str());
+        next = current;
+    }
+
+    final_order
+}
+
+fn read_cups(input: &str) -> Vec<i32> {
+    input.trim()
+        .chars()
+        .map(|c| String::from(c).parse::<i32>().unwrap())
+        .collect::<Vec<i32>>()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(read_cups(input))
+    }
+
+    fn part_one(cups_list: &Self::Parsed) -> Answer {
+        let mut cups = BTreeMap::new();
+        for i in 0..(cups_list.len() - 1) {
+            cups.insert(cups_list[i], cups_list[i + 1]);
+        }
+        cups.insert(cups_list[cups_list.len() - 1], cups_list[0]);
+
+        let mut current = cups_list[0];
+        for _ in 0..100 {
+            current = move_cups(current, &mut cups);
+        }
+
+        get_final_order(&cups).into()
+    }
+
+    fn part_two(cups_list: &Self::Parsed) -> Answer {
+        let mut cups = BTreeMap::new();
+        for i in 0..(cups_list.len() - 1) {
+            cups.insert(cups_list[i], cups_list[i + 1]);
+        }
+        cups.insert(cups_list[cups_list.len() - 1], (cups_list.len() + 1) as i32);
+
+        let total: i32 = 1000000;
+        for i in (cups_list.len() + 1)..(total as usize) {
+            let i = i as i32;
+            cups.insert(i, i + 1);
+        }
+
+        cups.insert(total, cups_list[0]);
+
+        let moves = 10000000;
+        let mut current = cups_list[0];
+        for _ in 0..moves {
+            current = move_cups(current, &mut cups);
+        }
+
+        let one_next = cups.get(&1).unwrap();
+        let two_next = cups.get(one_next).unwrap();
+        (*one_next as i64 * *two_next as i64).into()
+    }
+}
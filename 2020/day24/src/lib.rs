@@ -1,13 +1,5 @@
-use std::path::Path;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
 use std::collections::HashSet;
-
-fn get_file_lines(file_name: &str) -> Lines<BufReader<File>> {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::{Answer, Result, Solution};
 
 fn translate(point: &mut (i32, i32), x: i32, y: i32) {
     point.0 += x;
@@ -82,11 +74,10 @@ fn flip_tiles(black_tiles: HashSet<(i32, i32)>) -> HashSet<(i32, i32)> {
     new_tiles
 }
 
-fn test_input(file_name: &str) {
+fn read_black_tiles(input: &str) -> HashSet<(i32, i32)> {
     let mut black_tiles = HashSet::<(i32, i32)>::new();
-    let points = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| parse_point(&line));
+    let points = input.lines()
+        .map(parse_point);
     
     for point in points {
         if !black_tiles.remove(&point) {
@@ -94,16 +85,29 @@ fn test_input(file_name: &str) {
         }
     }
 
-    println!("For {}, there are {} tiles black side up", file_name, black_tiles.len());
+    black_tiles
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = HashSet<(i32, i32)>;
 
-    for _ in 0..100 {
-        black_tiles = flip_tiles(black_tiles);
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(read_black_tiles(input))
     }
 
-    println!("For {}, after 100 days there are {} tiles black side up", file_name, black_tiles.len());
-}
+    fn part_one(black_tiles: &Self::Parsed) -> Answer {
+        black_tiles.len().into()
+    }
+
+    fn part_two(black_tiles: &Self::Parsed) -> Answer {
+        let mut black_tiles = black_tiles.clone();
 
-fn main() {
-    test_input("sample.txt");
-    test_input("input.txt");
+        for _ in 0..100 {
+            black_tiles = flip_tiles(black_tiles);
+        }
+
+        black_tiles.len().into()
+    }
 }
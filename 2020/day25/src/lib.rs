@@ -0,0 +1,48 @@
+#![allow(clippy::assign_op_pattern)]
+
+use aoc_common::input::numbers;
+use aoc_common::{Answer, Result, Solution};
+
+const SUBJECT: u64 = 7;
+
+fn find_loop_count(pub_key: u64) -> usize {
+    let mut value = 1;
+    let mut loops = 0;
+    while value != pub_key {
+        value = value * SUBJECT;
+        value = value % 20201227;
+        loops += 1;
+    }
+    loops
+}
+fn run_loop(pub_key: u64, times: usize) -> u64 {
+    let mut value = 1;
+    for _ in 0..times {
+        value = value * pub_key;
+        value = value % 20201227;
+    }
+    value
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        numbers(input)
+    }
+
+    fn part_one(pub_keys: &Self::Parsed) -> Answer {
+        let pub_key_one = pub_keys[0];
+        let pub_key_two = pub_keys[1];
+
+        let key_one_loop_size = find_loop_count(pub_key_one);
+        run_loop(pub_key_two, key_one_loop_size).into()
+    }
+
+    fn part_two(_pub_keys: &Self::Parsed) -> Answer {
+        // the last day only has one puzzle
+        Answer::Unsolved
+    }
+}
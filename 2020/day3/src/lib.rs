@@ -0,0 +1,51 @@
+#![allow(clippy::ptr_arg)]
+
+use aoc_common::input::char_grid;
+use aoc_common::{Answer, Result, Solution};
+
+const TREE: char = '#';
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(char_grid(input))
+    }
+
+    fn part_one(grid: &Self::Parsed) -> Answer {
+        count_trees(grid, 3, 1).into()
+    }
+
+    fn part_two(grid: &Self::Parsed) -> Answer {
+        let slopes: Vec<(usize, usize)> = vec![
+            (1, 1), 
+            (3, 1), 
+            (5, 1), 
+            (7, 1), 
+            (1, 2)
+        ];
+
+        slopes.iter()
+            .map(|(dx, dy)| count_trees(grid, *dx, *dy))
+            .product::<usize>()
+            .into()
+    }
+}
+
+fn is_tree(grid: &Vec<Vec<char>>, x: usize, y: usize) -> bool {
+    let row = grid.get(y).unwrap();
+    let item = row.get(x % row.len()).unwrap();
+    *item == TREE
+}
+
+fn count_trees(grid: &Vec<Vec<char>>, dx: usize, dy: usize) -> usize {
+    let steps = grid.len() / dy;
+    (0..steps).filter(|index| {
+        let x = index * dx;
+        let y = index * dy;
+        is_tree(grid, x, y)
+    })
+    .count()
+}
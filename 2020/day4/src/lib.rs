@@ -1,9 +1,9 @@
-use std::path::Path;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
+#![allow(clippy::redundant_closure)]
+
 use std::collections::HashMap;
-use std::iter::Peekable;
 use regex::Regex;
+use aoc_common::input::paragraphs;
+use aoc_common::{Answer, Result, Solution};
 
 const HAS_ALL: u32 = 127;
 
@@ -37,10 +37,9 @@ fn split_copy(line: String, split_char: char) -> Vec<String> {
         .collect::<Vec<String>>()
 }
 
-fn is_next_passport_valid(token_map: &Mappings, lines: &mut Peekable<Lines<BufReader<File>>>) -> bool {
-    lines.filter_map(|line| line.ok())
-        .take_while(|line| !line.is_empty())
-        .flat_map(|line| split_copy(line, ' '))
+fn is_passport_valid(token_map: &Mappings, passport: &[String]) -> bool {
+    passport.iter()
+        .flat_map(|line| split_copy(line.clone(), ' '))
         .map(|token| split_copy(token, ':'))
         .filter_map(|token| {
             token_map.get(token[0].as_str())
@@ -54,14 +53,8 @@ fn is_next_passport_valid(token_map: &Mappings, lines: &mut Peekable<Lines<BufRe
         .fold(0, |sum, val| sum | val) == HAS_ALL
 }
 
-fn main() {
-    let path = Path::new("input.txt");
-    let file = File::open(path).unwrap();
-
-    let mut valid: i32 = 0;
-    let mut lines = BufReader::new(file).lines().peekable();
-    
-    let mappings: Vec<Mapping> = vec![
+fn count_valid(passports: &[Vec<String>], check_values: bool) -> usize {
+    let mut mappings: Vec<Mapping> = vec![
         Mapping::new("cid", 0, r".*"),
         Mapping::new("iyr", 1, r"^20(1[0-9]|20)$"),
         Mapping::new("byr", 2, r"^(19[2-9][0-9]|200[0-2])$"),
@@ -72,15 +65,35 @@ fn main() {
         Mapping::new("pid", 64, r"^[0-9]{9}$"),
     ];
 
+    if !check_values {
+        mappings = mappings.into_iter()
+            .map(|mapping| Mapping::new(mapping.field, mapping.flag, r".*"))
+            .collect();
+    }
+
     let token_map: Mappings = mappings.into_iter()
         .map(|mapping| (mapping.field, mapping))
         .collect();
 
-    while lines.peek().is_some() {
-        if is_next_passport_valid(&token_map, &mut lines) {
-            valid += 1;
-        }
+    passports.iter()
+        .filter(|passport| is_passport_valid(&token_map, passport))
+        .count()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Vec<String>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(paragraphs(input))
     }
 
-    println!("Valid: {}", valid);
+    fn part_one(passports: &Self::Parsed) -> Answer {
+        count_valid(passports, false).into()
+    }
+
+    fn part_two(passports: &Self::Parsed) -> Answer {
+        count_valid(passports, true).into()
+    }
 }
@@ -0,0 +1,64 @@
+#![allow(clippy::let_and_return, clippy::ptr_arg)]
+
+use std::cmp::max;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+fn read_seat_ids(input: &str) -> Vec<u32> {
+    let mut seat_ids: Vec<u32> = lines(input).into_iter()
+        .map(|line| line.chars().collect::<Vec<char>>())
+        .map(|code| get_seat_id(&code))
+        .collect();
+
+    seat_ids.sort_unstable();
+    seat_ids
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(read_seat_ids(input))
+    }
+
+    fn part_one(seat_ids: &Self::Parsed) -> Answer {
+        (*seat_ids.last().unwrap()).into()
+    }
+
+    fn part_two(seat_ids: &Self::Parsed) -> Answer {
+        let mut last: u32 = *seat_ids.first().unwrap();
+        let my_seat = 1 + seat_ids.iter()
+            .take_while(|seat| {
+                let found = **seat - last != 2;
+                last = **seat;
+                found
+            })
+            .last()
+            .unwrap();
+
+        my_seat.into()
+    }
+}
+
+fn compute_position(max_pos: u32, upper_char: char, code: &Vec<char>, code_start: usize, code_end: usize) -> u32 {
+    (code_start..code_end)
+        .map(|index| (index - code_start, code.get(index).unwrap()))
+        .fold(0, |offset, item| {
+            let (index, direction) = item;
+            let group_size = max(1, max_pos >> (index + 1));
+            if *direction == upper_char {
+                offset + group_size
+            } else {
+                offset
+            }
+        })
+}
+
+fn get_seat_id(code: &Vec<char>) -> u32 {
+    let row = compute_position(128, 'B', code, 0, 7);
+    let col = compute_position(8, 'R', code, 7, 10);
+    let seat_id = (row * 8) + col;
+    seat_id
+}
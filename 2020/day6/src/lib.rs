@@ -0,0 +1,34 @@
+use std::collections::HashSet;
+use aoc_common::input::paragraphs;
+use aoc_common::{Answer, Result, Solution};
+
+fn get_group_yes_count(group: &[String]) -> usize {
+    group.iter()
+        .map(|line| line.chars().collect::<HashSet<char>>())
+        .reduce(|set, answers| {
+            set.intersection(&answers).copied()
+                .collect::<HashSet<char>>()
+        })
+        .map_or(0, |set| set.len())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Vec<String>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(paragraphs(input))
+    }
+
+    fn part_one(_groups: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part_two(groups: &Self::Parsed) -> Answer {
+        groups.iter()
+            .map(|group| get_group_yes_count(group))
+            .sum::<usize>()
+            .into()
+    }
+}
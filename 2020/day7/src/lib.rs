@@ -1,11 +1,12 @@
-use std::path::Path;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+#![allow(clippy::len_zero, clippy::ptr_arg, clippy::redundant_closure)]
+
 use std::collections::HashMap;
 use std::collections::HashSet;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 #[derive(Debug, Clone)]
-struct Bag {
+pub struct Bag {
     color: String,
     contents: Vec<BagContent>
 }
@@ -81,8 +82,7 @@ fn get_transitive_map(bags: &Vec<Bag>, bags_by_contents: HashMap::<String, Vec<S
                 explore_parents = explore_parents.iter()
                     .filter(|parent| explored.insert((*parent).clone()))
                     .flat_map(|parent| bags_by_contents.get(parent))
-                    .flat_map(|parents| parents)
-                    .map(|parent| parent.clone())
+                    .flatten().cloned()
                     .collect::<Vec<String>>();
             }
 
@@ -116,28 +116,35 @@ fn get_bags_by_contents(bags: &Vec<Bag>) -> HashMap::<String, Vec<String>> {
     bags_by_contents
 }
 
-fn main() {
-    let path = Path::new("input.txt");
-    let file = File::open(path).unwrap();
-
-    let bags = BufReader::new(file).lines()
-        .flat_map(|line| line.ok())
+fn read_bags(input: &str) -> Vec<Bag> {
+    lines(input).into_iter()
         .map(|line| Bag::from_description(line))
-        .collect::<Vec<Bag>>();
+        .collect::<Vec<Bag>>()
+}
 
-    let bags_by_contents = get_bags_by_contents(&bags);
-    let transitive_bags_by_contents = get_transitive_map(&bags, bags_by_contents);
-    
-    let bags_that_can_hold_shiny_gold = transitive_bags_by_contents.get("shiny gold").unwrap().len();
-    
-    println!("{} bags can hold at least one shiny gold bag", bags_that_can_hold_shiny_gold);
+pub struct Day;
 
-    let bags_by_color = bags.iter()
-        .map(|bag| (bag.color.clone(), bag))
-        .collect::<HashMap<String, &Bag>>();
-    
-    let shiny_bag = bags_by_color.get("shiny gold").unwrap();
-    let num_bags_in_shiny_bag = shiny_bag.get_num_bags(&bags_by_color);
-    println!("Num bags in shiny bag: {}", num_bags_in_shiny_bag - 1);
+impl Solution for Day {
+    type Parsed = Vec<Bag>;
 
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(read_bags(input))
+    }
+
+    fn part_one(bags: &Self::Parsed) -> Answer {
+        let bags_by_contents = get_bags_by_contents(bags);
+        let transitive_bags_by_contents = get_transitive_map(bags, bags_by_contents);
+
+        transitive_bags_by_contents.get("shiny gold").unwrap().len().into()
+    }
+
+    fn part_two(bags: &Self::Parsed) -> Answer {
+        let bags_by_color = bags.iter()
+            .map(|bag| (bag.color.clone(), bag))
+            .collect::<HashMap<String, &Bag>>();
+
+        let shiny_bag = bags_by_color.get("shiny gold").unwrap();
+        let num_bags_in_shiny_bag = shiny_bag.get_num_bags(&bags_by_color);
+        (num_bags_in_shiny_bag - 1).into()
+    }
 }
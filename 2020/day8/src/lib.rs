@@ -1,7 +1,8 @@
-use std::path::Path;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+#![allow(clippy::if_same_then_else, clippy::new_ret_no_self, clippy::ptr_arg)]
+
 use std::collections::HashSet;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 struct State {
     next: usize,
@@ -142,13 +143,13 @@ fn run_program(instructions: &Vec<Box<dyn Instruction>>, state: State) -> State
     state
 }
 
-fn fix_instructions(instructions: Vec<Box<dyn Instruction>>, failed_state: State) -> State {
+fn fix_instructions(instructions: &Vec<Box<dyn Instruction>>, failed_state: State) -> State {
     let mut tried_jump_tos = HashSet::<usize>::new();
     loop {
         let jump_to = (0..instructions.len())
-            .filter(|index| !failed_state.executed.contains(&index))
+            .filter(|index| !failed_state.executed.contains(index))
             .filter(|index| !tried_jump_tos.contains(index))
-            .find(|index| run_program(&instructions, State::new(*index)).next == instructions.len())
+            .find(|index| run_program(instructions, State::new(*index)).next == instructions.len())
             .unwrap();
         
         tried_jump_tos.insert(jump_to);
@@ -176,24 +177,35 @@ fn fix_instructions(instructions: Vec<Box<dyn Instruction>>, failed_state: State
         }
 
         state.next = jump_to;
-        return run_program(&instructions, state);
+        return run_program(instructions, state);
     }
 }
 
-fn main() {
-    let path = Path::new("input.txt");
-    let file = File::open(path).unwrap();
+fn read_instructions(lines: &[String]) -> Vec<Box<dyn Instruction>> {
+    lines.iter()
+        .map(parse_instruction)
+        .collect::<Vec<Box<dyn Instruction>>>()
+}
+
+pub struct Day;
 
-    let instructions = BufReader::new(file).lines()
-        .flat_map(|line| line.ok())
-        .map(|line| parse_instruction(&line))
-        .collect::<Vec<Box<dyn Instruction>>>();
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-    println!("have {} instructions", instructions.len());
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
 
-    let state = run_program(&instructions, State::new(0));
-    println!("Part One: accumulator is {} before executing {}", state.acc, state.next);
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let instructions = read_instructions(lines);
+        let state = run_program(&instructions, State::new(0));
+        state.acc.into()
+    }
 
-    let state = fix_instructions(instructions, state);
-    println!("Part Two: accumulator is {} before executing {}", state.acc, state.next);
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let instructions = read_instructions(lines);
+        let state = run_program(&instructions, State::new(0));
+        let state = fix_instructions(&instructions, state);
+        state.acc.into()
+    }
 }
@@ -0,0 +1,85 @@
+use std::collections::LinkedList;
+use std::collections::BTreeSet;
+use aoc_common::input::numbers;
+use aoc_common::{Answer, Result, Solution};
+
+fn does_sum_exist(avail_numbers: &BTreeSet<u32>, num: u32) -> bool {
+    let mut front = avail_numbers.iter().peekable();
+    let mut back = avail_numbers.iter().rev().peekable();
+
+    while let (Some(smaller), Some(bigger)) = (front.peek(), back.peek()) {
+        if *smaller >= *bigger {
+            return false;
+        }
+        
+        match (*smaller + *bigger) as i32 - num as i32 {
+            x if x < 0 => front.next(),
+            x if x > 0 => back.next(),
+            _ => return true
+        };
+    }
+    false
+}
+
+const PREAMBLE_LEN: usize = 25;
+
+fn find_invalid_number(numbers: &[u32]) -> u32 {
+    let mut number_queue = numbers[..PREAMBLE_LEN].iter().copied()
+        .collect::<LinkedList<u32>>();
+    
+    let mut avail_numbers = number_queue.iter().copied()
+        .collect::<BTreeSet<u32>>();
+
+    numbers[PREAMBLE_LEN..].iter().copied()
+        .find(|num| {
+            let exists = does_sum_exist(&avail_numbers, *num);
+            let first_num = number_queue.pop_front().unwrap();
+            avail_numbers.remove(&first_num);
+            number_queue.push_back(*num);
+            avail_numbers.insert(*num);
+            !exists
+        })
+        .unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        numbers(input)
+    }
+
+    fn part_one(numbers: &Self::Parsed) -> Answer {
+        find_invalid_number(numbers).into()
+    }
+
+    fn part_two(numbers: &Self::Parsed) -> Answer {
+        let invalid_number = find_invalid_number(numbers);
+
+        let mut number_queue = LinkedList::<u32>::new();
+        let mut avail_numbers = BTreeSet::<u32>::new();
+        let mut current_sum = 0;
+
+        for &num in numbers {
+            current_sum += num;
+            number_queue.push_back(num);
+            avail_numbers.insert(num);
+            if current_sum > invalid_number {
+                while current_sum > invalid_number {
+                    let removed = number_queue.pop_front().unwrap();
+                    current_sum -= removed;
+                    avail_numbers.remove(&removed);
+                }
+            }
+            if current_sum == invalid_number {
+                break;
+            }
+        }
+
+        let smallest = avail_numbers.iter().next().unwrap();
+        let biggest = avail_numbers.iter().next_back().unwrap();
+        (smallest + biggest).into()
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::{BTreeSet, HashMap};
+use aoc_common::input::numbers;
+use aoc_common::{Answer, Result, Solution};
+
+struct Adapter {
+    higher: Vec<u32>,
+    paths_to_end: u64
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = BTreeSet<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(numbers(input)?.into_iter().collect())
+    }
+
+    fn part_one(joltages: &Self::Parsed) -> Answer {
+        let (diff_by_one, diff_by_three, _) = joltages.iter().fold((0, 0, 0), |result, joltage| {
+            let joltage = *joltage;
+            let (diff_by_one, diff_by_three, last_joltage) = result;
+            match joltage - last_joltage {
+                0 | 2 => (diff_by_one, diff_by_three, joltage),
+                1 => (diff_by_one + 1, diff_by_three, joltage),
+                3 => (diff_by_one, diff_by_three + 1, joltage),
+                _ => panic!("Invalid next adapter. Last: {} current: {}", last_joltage, joltage)
+            }
+        });
+        let diff_by_three = diff_by_three + 1; // last_adapter -> device
+
+        (diff_by_one * diff_by_three).into()
+    }
+
+    fn part_two(joltages: &Self::Parsed) -> Answer {
+        let mut joltages = joltages.clone();
+        joltages.insert(0);
+
+        let mut adapters = joltages.iter()
+            .map(|joltage| {
+                let joltage = *joltage;
+                let higher = joltages.range((joltage + 1)..(joltage + 4)).copied()
+                    .collect::<Vec<u32>>();
+
+                (joltage, Adapter { paths_to_end: 0, higher })
+            })
+            .collect::<HashMap<u32, Adapter>>();
+
+        let max_joltage = joltages.iter().last().unwrap();
+        let last_adapter = adapters.get_mut(max_joltage).unwrap();
+        last_adapter.paths_to_end = 1;
+
+        for joltage in joltages.iter().rev().skip(1) {
+            let adapter = adapters.get(joltage).unwrap();
+            let mut sum = 0;
+            for next in adapter.higher.iter() {
+                sum += adapters.get(next).unwrap().paths_to_end;
+            }
+            let adapter = adapters.get_mut(joltage).unwrap();
+            adapter.paths_to_end = sum;
+        }
+        adapters.get(&0).unwrap().paths_to_end.into()
+    }
+}
@@ -0,0 +1,112 @@
+use aoc_common::grid::{Grid, Point, ALL_DIRECTIONS};
+use aoc_common::{Answer, Result, Solution};
+
+type Layout = Grid<char>;
+
+fn occupied_count(seat: char) -> u32 {
+    match seat {
+        '#' => 1,
+        _ => 0
+    }
+}
+
+fn get_adjacent_occupied(layout: &Layout, point: Point) -> u32 {
+    layout.all_neighbours(point)
+        .map(|point| occupied_count(layout[point]))
+        .sum()
+}
+
+fn get_los_occupied_with_direction(layout: &Layout, mut point: Point, direction: (isize, isize)) -> u32 {
+    while let Some(next) = layout.step(point, direction) {
+        match layout[next] {
+            '#' => return 1,
+            'L' => return 0,
+            _ => ()
+        };
+        point = next;
+    }
+    0
+}
+
+fn get_los_occupied(layout: &Layout, point: Point) -> u32 {
+    ALL_DIRECTIONS.iter()
+        .map(|direction| get_los_occupied_with_direction(layout, point, *direction))
+        .sum()
+}
+
+fn change_seats<F>(layout: &Layout, tolerance: u32, get_occupied: F) -> (u32, Layout)
+    where F: Fn(&Layout, Point) -> u32
+{
+    let mut changes = 0;
+    let seats = layout.iter()
+        .map(|(point, seat)| match seat {
+            '#' if get_occupied(layout, point) >= tolerance => {
+                changes += 1;
+                'L'
+            },
+            'L' if get_occupied(layout, point) == 0 => {
+                changes += 1;
+                '#'
+            },
+            x => *x
+        })
+        .collect();
+
+    (changes, Layout::new(layout.width(), layout.height(), seats))
+}
+
+fn change_seats_part_one(layout: &Layout) -> (u32, Layout) {
+    change_seats(layout, 4, get_adjacent_occupied)
+}
+
+fn change_seats_part_two(layout: &Layout) -> (u32, Layout) {
+    change_seats(layout, 5, get_los_occupied)
+}
+
+fn get_occupied_count(layout: &Layout) -> u32 {
+    layout.values()
+        .map(|seat| occupied_count(*seat))
+        .sum()
+}
+
+fn stabilize_part_one(layout: Layout) -> Layout {
+    let mut layout = layout;
+    loop {
+        let (changes, new_layout) = change_seats_part_one(&layout);
+        layout = new_layout;
+        if changes == 0 {
+            return layout;
+        }
+    }
+}
+
+fn stabilize_part_two(layout: Layout) -> Layout {
+    let mut layout = layout;
+    loop {
+        let (changes, new_layout) = change_seats_part_two(&layout);
+        layout = new_layout;
+        if changes == 0 {
+            return layout;
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Layout;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Grid::chars(input)
+    }
+
+    fn part_one(layout: &Self::Parsed) -> Answer {
+        let stable_layout = stabilize_part_one(layout.clone());
+        get_occupied_count(&stable_layout).into()
+    }
+
+    fn part_two(layout: &Self::Parsed) -> Answer {
+        let stable_layout = stabilize_part_two(layout.clone());
+        get_occupied_count(&stable_layout).into()
+    }
+}
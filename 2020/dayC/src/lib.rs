@@ -1,12 +1,5 @@
-use std::path::Path;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
-
-fn get_file_lines(file_name: &str) -> Lines<BufReader<File>> {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 trait MovableObject {
     fn rotate(&mut self, amount: i32, neg_const: i32);
@@ -95,9 +88,8 @@ fn execute_instruction(object: &mut dyn MovableObject, instruction: char, amount
     }
 }
 
-fn get_instructions(file_name: &str) -> Vec<(char, i32)> {
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
+fn get_instructions(input: &str) -> Vec<(char, i32)> {
+    lines(input).into_iter()
         .map(|line| {
             let instruction = line.chars().next().unwrap();
             let amount = line[1..].parse::<i32>().unwrap();
@@ -106,41 +98,41 @@ fn get_instructions(file_name: &str) -> Vec<(char, i32)> {
         .collect::<Vec<(char, i32)>>()
 }
 
-fn part_one(file_name: &str) {
-    let mut ferry = Ferry::new();
-    
-    for (instruction, amount) in get_instructions(file_name) {
-        if instruction == 'F' {
-            ferry.change_pos(ferry.x_dir * amount, ferry.y_dir * amount);
-        } else {
-            execute_instruction(&mut ferry, instruction, amount);
-        }
-    }
+pub struct Day;
 
-    println!("For {}, Part 1: Manhattan distance is {}", file_name, ferry.get_manhattan_distance());
-}
+impl Solution for Day {
+    type Parsed = Vec<(char, i32)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(get_instructions(input))
+    }
 
-fn part_two(file_name: &str) {
-    let mut ferry = Ferry::new();
-    let mut waypoint = Waypoint::new(10, 1);
+    fn part_one(instructions: &Self::Parsed) -> Answer {
+        let mut ferry = Ferry::new();
 
-    for (instruction, amount) in get_instructions(file_name) {
-        if instruction == 'F' {
-            ferry.change_pos(waypoint.x * amount, waypoint.y * amount);
-        } else {
-            execute_instruction(&mut waypoint, instruction, amount);
+        for &(instruction, amount) in instructions {
+            if instruction == 'F' {
+                ferry.change_pos(ferry.x_dir * amount, ferry.y_dir * amount);
+            } else {
+                execute_instruction(&mut ferry, instruction, amount);
+            }
         }
+
+        ferry.get_manhattan_distance().into()
     }
-    
-    println!("For {}, Part 2: Manhattan distance is {}", file_name, ferry.get_manhattan_distance());
-}
 
-fn test_input(file_name: &str) {
-    part_one(file_name);
-    part_two(file_name);
-}
+    fn part_two(instructions: &Self::Parsed) -> Answer {
+        let mut ferry = Ferry::new();
+        let mut waypoint = Waypoint::new(10, 1);
 
-fn main() {
-    test_input("sample.txt");
-    test_input("input.txt");
+        for &(instruction, amount) in instructions {
+            if instruction == 'F' {
+                ferry.change_pos(waypoint.x * amount, waypoint.y * amount);
+            } else {
+                execute_instruction(&mut waypoint, instruction, amount);
+            }
+        }
+
+        ferry.get_manhattan_distance().into()
+    }
 }
@@ -1,14 +1,9 @@
-use std::path::Path;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
+#![allow(clippy::assign_op_pattern, clippy::needless_range_loop)]
+
 use std::collections::HashMap;
 use regex::Regex;
-
-fn get_file_lines(file_name: &str) -> Lines<BufReader<File>> {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 struct Mask {
     zeros: u64,
@@ -33,7 +28,7 @@ impl Mask {
         Mask {
             zeros,
             ones,
-            permuted: permuted,
+            permuted,
             permuted_zero_mask: !unstable_bits
         }
     }
@@ -42,14 +37,13 @@ impl Mask {
             return;
         }
 
-        let mut permuted = permuted;
         permuted.push(base_mask);
-        Mask::create_permutations(&mut permuted, base_mask, index + 1, digits);
+        Mask::create_permutations(permuted, base_mask, index + 1, digits);
 
         let digit = digits[index];
         let with_one = base_mask | (1 << digit);
         permuted.push(with_one);
-        Mask::create_permutations(&mut permuted, with_one, index + 1, digits);
+        Mask::create_permutations(permuted, with_one, index + 1, digits);
     }
     fn apply(&self, number: u64) -> u64 {
         (number & self.zeros) | self.ones
@@ -102,18 +96,17 @@ fn set_memory(
     }
 }
 
-fn test_input(file_name: &str, permute: bool) {
+fn run_program(lines: &[String], permute: bool) -> (u64, u64) {
     let mut memory = HashMap::<u64, u64>::new();
     let mut permuted_memory = HashMap::<u64, u64>::new();
     let mut mask = Mask::new(0, 0, false);
-    let mut lines = get_file_lines(file_name);
-
+    
     let parser = Regex::new(r"^(?:(mask)|mem\[([0-9]+)\]) = (.+)$").unwrap();
 
-    while let Some(Ok(line)) = lines.next() {
+    for line in lines {
         let tokens = parser.captures(line.as_str()).unwrap()
             .iter()
-            .flat_map(|capture| capture)
+            .flatten()
             .map(|capture| capture.as_str())
             .collect::<Vec<&str>>();
 
@@ -125,17 +118,25 @@ fn test_input(file_name: &str, permute: bool) {
         }
     }
 
-    let value_sum = memory.values().sum::<u64>();
-    println!("For {}, sum of values in memory: {}", file_name, value_sum);
-    
-    if permute {
-        let value_sum = permuted_memory.values().sum::<u64>();
-        println!("For {}, sum of values in permuted memory: {}", file_name, value_sum);
-    }
+    (memory.values().sum::<u64>(), permuted_memory.values().sum::<u64>())
 }
 
-fn main() {
-    test_input("normal_sample.txt", false);
-    test_input("permute_sample.txt", true);
-    test_input("input.txt", true);
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let (value_sum, _) = run_program(lines, false);
+        value_sum.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let (_, value_sum) = run_program(lines, true);
+        value_sum.into()
+    }
 }
@@ -0,0 +1,40 @@
+#![allow(clippy::needless_range_loop)]
+
+use std::collections::HashMap;
+use aoc_common::input::numbers;
+use aoc_common::{Answer, Result, Solution};
+
+fn play(initial_numbers: &[u32], turns: usize) -> u32 {
+    let mut last_by_number = HashMap::<u32, usize>::new();
+    for i in 0..(initial_numbers.len() - 1) {
+        last_by_number.insert(initial_numbers[i], i + 1);
+    }
+
+    let mut last = initial_numbers[initial_numbers.len() - 1];
+    for turn in (initial_numbers.len() + 1)..(turns + 1) {
+        let speak_now = last_by_number.insert(last, turn - 1)
+            .map(|last_turn| (turn - 1) - last_turn)
+            .unwrap_or(0) as u32;
+        last = speak_now;
+    }
+
+    last
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        numbers(input.lines().next().unwrap_or(""))
+    }
+
+    fn part_one(initial_numbers: &Self::Parsed) -> Answer {
+        play(initial_numbers, 2020).into()
+    }
+
+    fn part_two(initial_numbers: &Self::Parsed) -> Answer {
+        play(initial_numbers, 30000000).into()
+    }
+}
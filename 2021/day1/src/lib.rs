@@ -0,0 +1,54 @@
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let numbers = lines.iter()
+            .flat_map(|line| line.parse::<i32>())
+            .collect::<Vec<i32>>();
+
+        let mut last = numbers[0];
+        let mut increases = 0;
+        for number in numbers {
+            if number > last {
+                increases += 1;
+            }
+            last = number;
+        }
+
+        increases.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut numbers = lines.iter()
+            .flat_map(|line| line.parse::<i32>());
+
+        let mut queue = [numbers.next().unwrap(), 
+            numbers.next().unwrap(), 
+            numbers.next().unwrap()];
+
+        let mut increases = 0;
+        let mut last = queue.iter().sum::<i32>();
+        let mut insert = 0;
+
+        numbers.for_each(|num| {
+            let new_sum = last - queue[insert] + num;
+            queue[insert] = num;
+            insert = (insert + 1) % 3;
+            if new_sum > last {
+                increases += 1;
+            }
+            last = new_sum;
+        });
+
+        increases.into()
+    }
+}
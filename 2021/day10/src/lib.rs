@@ -1,15 +1,8 @@
-use std::collections::HashMap;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
+#![allow(clippy::ptr_arg, clippy::redundant_closure)]
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use std::collections::HashMap;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 fn find_syntax_error(line: &String) -> Option<char> {
     let mut expected_ends = vec![];
@@ -65,40 +58,37 @@ fn get_incomplete_score(line: &String) -> Option<u64> {
     Some(score)
 }
 
-fn part_one(file_name: &str) {
-    let mut score_by_expected = HashMap::<char, u32>::new();
-    score_by_expected.insert(')', 3);
-    score_by_expected.insert(']', 57);
-    score_by_expected.insert('}', 1197);
-    score_by_expected.insert('>', 25137);
-    let score_by_expected = score_by_expected;
+pub struct Day;
 
-    let score = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .flat_map(|line| find_syntax_error(&line))
-        .flat_map(|expected| score_by_expected.get(&expected))
-        .sum::<u32>();
-    
-    println!("Part 1: {}", score);
-}
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-fn part_two(file_name: &str) {
-    let mut scores = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .flat_map(|line| get_incomplete_score(&line))
-        .collect::<Vec<_>>();
-    
-    scores.sort();
-    let mid = scores.len() >> 1;
-    let score = scores[mid];
-    
-    println!("Part 2: {}", score);
-}
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let mut score_by_expected = HashMap::<char, u32>::new();
+        score_by_expected.insert(')', 3);
+        score_by_expected.insert(']', 57);
+        score_by_expected.insert('}', 1197);
+        score_by_expected.insert('>', 25137);
+        let score_by_expected = score_by_expected;
 
-fn main() {
-    part_one("input.txt");
-    part_two("sample.txt");
-    part_two("input.txt");
+        lines.iter()
+            .flat_map(|line| find_syntax_error(line))
+            .flat_map(|expected| score_by_expected.get(&expected))
+            .sum::<u32>()
+            .into()
+    }
 
-    println!("Done!");
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut scores = lines.iter()
+            .flat_map(|line| get_incomplete_score(line))
+            .collect::<Vec<_>>();
+
+        scores.sort();
+        let mid = scores.len() >> 1;
+        scores[mid].into()
+    }
 }
@@ -0,0 +1,95 @@
+use aoc_common::grid::{Grid, Point};
+use aoc_common::{Answer, Result, Solution};
+
+#[derive(Clone)]
+pub struct Octopuses {
+    energy: Grid<u32>,
+    cascaded: Grid<bool>
+}
+
+impl Octopuses {
+    fn parse(input: &str) -> Result<Octopuses> {
+        let energy = Grid::parse(input, |c| c.to_digit(10))?;
+        let cascaded = energy.map(|_| false);
+
+        Ok(Octopuses {
+            energy,
+            cascaded
+        })
+    }
+
+    fn cascade_energy(&mut self, point: Point) {
+        // don't cascade this octo if it has been already
+        if self.cascaded[point] || self.energy[point] <= 9 {
+            return;
+        }
+
+        self.cascaded[point] = true;
+
+        let neighbours = self.energy.all_neighbours(point).collect::<Vec<_>>();
+        neighbours.into_iter().for_each(|neighbour| {
+            self.energy[neighbour] += 1;
+            self.cascade_energy(neighbour);
+        });
+    }
+
+    fn execute_step(&mut self) -> u32 {
+        // mark all of the octos as not having cascaded
+        // their energy to their neighbors yet
+        self.cascaded.values_mut()
+            .for_each(|cascaded| *cascaded = false);
+        
+        // add one energy to each octo for this step
+        self.energy.values_mut()
+            .for_each(|energy| *energy += 1);
+
+        // cascade the energy of each octo 
+        // over 9 to it's neighbords
+        for point in self.energy.points() {
+            self.cascade_energy(point);
+        }
+
+        // reset entery to 0 if an octos  
+        // has energy greater than 9 and
+        // return the number of flashes we had
+        self.energy.values_mut()
+            .filter(|energy| **energy > 9)
+            .map(|energy| *energy = 0)
+            .count() as u32
+    }
+
+    fn _print(&self) {
+        print!("{}", self.energy);
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Octopuses;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Octopuses::parse(input)
+    }
+
+    fn part_one(octopuses: &Self::Parsed) -> Answer {
+        let mut octopuses = octopuses.clone();
+
+        (0..100)
+            .map(|_| octopuses.execute_step())
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_two(octopuses: &Self::Parsed) -> Answer {
+        let mut octopuses = octopuses.clone();
+
+        let octos = (octopuses.energy.width() * octopuses.energy.height()) as u32;
+        let mut steps = 1;
+        while octopuses.execute_step() < octos {
+            steps += 1;
+        }
+
+        steps.into()
+    }
+}
@@ -1,16 +1,7 @@
 use std::collections::HashSet;
 use std::collections::HashMap;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 struct Cave {
     label: String,
@@ -41,7 +32,7 @@ impl Cave {
     }
 }
 
-struct Network {
+pub struct Network {
     caves: Vec<Cave>,
     label_to_id: HashMap<String, usize>
 }
@@ -116,7 +107,7 @@ impl Network {
         cave.adjacent.push(*adj_id);
     }
 
-    fn from_file(file_name: &str) -> Network {
+    fn parse(input: &str) -> Network {
         let mut network = Network {
             caves: Vec::new(),
             label_to_id: HashMap::new()
@@ -126,8 +117,7 @@ impl Network {
         network.create("start");
 
         // populate the network
-        get_file_lines(file_name)
-            .flat_map(|line| line.ok())
+        lines(input).into_iter()
             .for_each(|line| {
                 let mut pieces = line.split("-");
                 let from = pieces.next().unwrap();
@@ -155,7 +145,7 @@ impl Network {
             } else {
                 self.find_end(to_visit, log);
             }
-            log.un_visit(&to_visit);
+            log.un_visit(to_visit);
         }
         log.path.pop();
     }
@@ -166,7 +156,7 @@ impl Network {
 
     fn _print(&self) {
         for cave in &self.caves {
-            self._print_cave(&cave);
+            self._print_cave(cave);
         }
     }
 
@@ -180,33 +170,28 @@ impl Network {
     }
 }
 
-fn part_one(file_name: &str) {
-    let network = Network::from_file(file_name);
+pub struct Day;
 
-    let start = network.start();
-    let mut log = TravelLog::new(false);
-    network.find_end(start, &mut log);
-    
-    println!("Part 1: {}", log.paths.len());
-}
+impl Solution for Day {
+    type Parsed = Network;
 
-fn part_two(file_name: &str) {
-    let network = Network::from_file(file_name);
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Network::parse(input))
+    }
 
-    let start = network.start();
-    let mut log = TravelLog::new(true);
-    network.find_end(start, &mut log);
-    
-    println!("Part 2: {}", log.paths.len());
-}
+    fn part_one(network: &Self::Parsed) -> Answer {
+        let start = network.start();
+        let mut log = TravelLog::new(false);
+        network.find_end(start, &mut log);
 
-fn main() {
-    part_one("input.txt");
+        log.paths.len().into()
+    }
 
-    part_two("sample.txt");
-    part_two("sample2.txt");
-    part_two("sample3.txt");
-    part_two("input.txt");
+    fn part_two(network: &Self::Parsed) -> Answer {
+        let start = network.start();
+        let mut log = TravelLog::new(true);
+        network.find_end(start, &mut log);
 
-    println!("Done!");
+        log.paths.len().into()
+    }
 }
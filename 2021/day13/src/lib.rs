@@ -1,15 +1,6 @@
 use std::collections::HashSet;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 #[derive(Copy, Clone)]
 enum Direction { X, Y }
@@ -29,16 +20,16 @@ impl Fold {
     }
 }
 
-struct ActivationCode {
+#[derive(Clone)]
+pub struct ActivationCode {
     points: HashSet<(i32, i32)>,
     folds: Vec<Fold>
 }
 
 impl ActivationCode {
 
-    fn from_file(file_name: &str) -> ActivationCode {
-        let mut lines = get_file_lines(file_name)
-            .flat_map(|line| line.ok());
+    fn parse(input: &str) -> ActivationCode {
+        let mut lines = lines(input).into_iter();
         
         let points = (&mut lines).take_while(|line| !line.is_empty())
             .map(|line| {
@@ -82,12 +73,12 @@ impl ActivationCode {
         G: Fn(&(i32, i32)) -> i32,
         C: Fn(&(i32, i32), i32) -> (i32, i32) {
         let mut new_points = self.points.iter()
-            .filter(|point| get_coord(*point) < offset)
+            .filter(|point| get_coord(point) < offset)
             .copied()
             .collect::<HashSet<_>>();
         
         self.points.iter()
-            .filter(|point| get_coord(*point) > offset)
+            .filter(|point| get_coord(point) > offset)
             .for_each(|point| {
                 let v = get_coord(point) - offset;
                 let v = offset - v;
@@ -97,7 +88,7 @@ impl ActivationCode {
         self.points = new_points;
     }
 
-    fn print(&self) {
+    fn render(&self) -> String {
         let x_max = self.points.iter()
             .map(|(x, _)| *x)
             .max()
@@ -107,42 +98,47 @@ impl ActivationCode {
             .max()
             .unwrap() + 1;
         
+        let mut output = String::new();
         for y in 0..y_max {
+            if y > 0 {
+                output.push('\n');
+            }
             for x in 0..x_max {
                 match self.points.contains(&(x, y)) {
-                    true => print!("#"),
-                    false => print!(".")
+                    true => output.push('#'),
+                    false => output.push('.')
                 }
             }
-            println!("");
         }
+        output
     }
     
 }
 
-fn part_one(file_name: &str) {
-    let mut activation = ActivationCode::from_file(file_name);
+pub struct Day;
 
-    let fold = activation.folds[0];
-    activation.fold(fold);
+impl Solution for Day {
+    type Parsed = ActivationCode;
 
-    println!("Part 1: {}", activation.points.len());
-}
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(ActivationCode::parse(input))
+    }
 
-fn part_two(file_name: &str) {
-    let mut activation = ActivationCode::from_file(file_name);
-    
-    let folds = activation.folds.to_vec();
-    folds.into_iter().for_each(|fold| activation.fold(fold));
+    fn part_one(activation: &Self::Parsed) -> Answer {
+        let mut activation = activation.clone();
 
-    println!("Part 2:");
-    activation.print();
-}
+        let fold = activation.folds[0];
+        activation.fold(fold);
+
+        activation.points.len().into()
+    }
+
+    fn part_two(activation: &Self::Parsed) -> Answer {
+        let mut activation = activation.clone();
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
-    part_two("sample.txt");
+        let folds = activation.folds.to_vec();
+        folds.into_iter().for_each(|fold| activation.fold(fold));
 
-    println!("Done!");
+        activation.render().into()
+    }
 }
@@ -1,26 +1,17 @@
 use std::collections::HashMap;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
-
-struct PolymerManual {
+#[derive(Clone)]
+pub struct PolymerManual {
     insertions: HashMap<(char, char), char>,
     template: Vec<char>,
 }
 
 impl PolymerManual {
 
-    fn from_file(file_name: &str) -> PolymerManual {
-        let mut lines = get_file_lines(file_name)
-            .flat_map(|line| line.ok());
+    fn parse(input: &str) -> PolymerManual {
+        let mut lines = lines(input).into_iter();
         
         let template = lines.next()
             .unwrap()
@@ -64,7 +55,7 @@ impl PolymerManual {
                 let (a, b) = pair;
                 let a = *a;
                 let b = *b;
-                if let Some(insert) = self.insertions.get(&pair) {
+                if let Some(insert) = self.insertions.get(pair) {
                     let insert = *insert;
                     if let Some(new_count) = new_pairs.get_mut(&(a, insert)) {
                         *new_count += *count; 
@@ -132,27 +123,28 @@ impl PolymerManual {
     }
 }
 
+pub struct Day;
 
-fn part_one(file_name: &str) {
-    let mut manual = PolymerManual::from_file(file_name);
-    
-    let counts = manual.apply(10);
+impl Solution for Day {
+    type Parsed = PolymerManual;
 
-    println!("Part 1: {}", manual.determine_answer(counts));
-}
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(PolymerManual::parse(input))
+    }
 
-fn part_two(file_name: &str) {
-    let mut manual = PolymerManual::from_file(file_name);
+    fn part_one(manual: &Self::Parsed) -> Answer {
+        let mut manual = manual.clone();
 
-    let counts = manual.apply(40);
-    
-    println!("Part 2: {}", manual.determine_answer(counts));
-}
+        let counts = manual.apply(10);
+
+        manual.determine_answer(counts).into()
+    }
 
-fn main() {
-    part_one("input.txt");
-    // part_two("sample.txt");
-    part_two("input.txt");
+    fn part_two(manual: &Self::Parsed) -> Answer {
+        let mut manual = manual.clone();
 
-    println!("Done!");
+        let counts = manual.apply(40);
+
+        manual.determine_answer(counts).into()
+    }
 }
@@ -0,0 +1,66 @@
+use aoc_common::grid::{Grid, Point, ORTHOGONAL};
+use aoc_common::search::{dijkstra, Neighbours};
+use aoc_common::{Answer, Result, Solution};
+
+struct Cave<'a> {
+    // one tile of the cave, which repeats size_mult times each way
+    rows: &'a Grid<u32>,
+    width: usize,
+    height: usize
+}
+
+impl<'a> Cave<'a> {
+    fn new(rows: &'a Grid<u32>, size_mult: usize) -> Cave<'a> {
+        Cave {
+            rows,
+            width: rows.width() * size_mult,
+            height: rows.height() * size_mult
+        }
+    }
+
+    fn get_risk(&self, (x, y): Point) -> u32 {
+        let add = ((y / self.rows.height()) + (x / self.rows.width())) as u32;
+        let risk = self.rows[(x % self.rows.width(), y % self.rows.height())];
+        let risk = risk + add;
+        risk % 10 + (risk / 10)
+    }
+
+    fn find_least_risky_path(&self) -> u64 {
+        let end = (self.width - 1, self.height - 1);
+        dijkstra(self, [(0, 0)], |point| *point == end)
+            .unwrap()
+            .cost
+    }
+}
+
+impl Neighbours for Cave<'_> {
+    type State = Point;
+
+    fn neighbours(&self, &(x, y): &Point) -> Vec<(Point, u64)> {
+        ORTHOGONAL.iter()
+            .flat_map(|(dx, dy)| Some((x.checked_add_signed(*dx)?, y.checked_add_signed(*dy)?)))
+            .filter(|(x, y)| *x < self.width && *y < self.height)
+            .map(|point| (point, self.get_risk(point) as u64))
+            .collect()
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Grid::parse(input, |c| c.to_digit(10))
+    }
+
+    fn part_one(rows: &Self::Parsed) -> Answer {
+        let cave = Cave::new(rows, 1);
+        cave.find_least_risky_path().into()
+    }
+
+    fn part_two(rows: &Self::Parsed) -> Answer {
+        let cave = Cave::new(rows, 5);
+        cave.find_least_risky_path().into()
+    }
+}
@@ -1,15 +1,8 @@
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
+#![allow(clippy::needless_return)]
 
-type Input = Lines<BufReader<File>>;
-type Packets = Vec<Box<dyn Packet>>;
+use aoc_common::{Answer, Result, Solution};
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+type Packets = Vec<Box<dyn Packet>>;
 
 struct PacketString {
     nibbles: Vec<u8>,
@@ -63,14 +56,9 @@ impl PacketString {
     }
 }
 
-fn parse(file_name: &str) -> Box<dyn Packet> {
-    let line = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .next()
-        .unwrap();
-    let mut packet_str = PacketString::parse(line);
-    let packet = parse_nibbles(&mut packet_str);
-    packet
+fn parse_packet(line: &str) -> Box<dyn Packet> {
+    let mut packet_str = PacketString::parse(line.to_owned());
+    parse_nibbles(&mut packet_str)
 }
 
 fn parse_nibbles(packet_str: &mut PacketString) -> Box<dyn Packet> {
@@ -85,7 +73,7 @@ fn parse_nibbles(packet_str: &mut PacketString) -> Box<dyn Packet> {
                 number = (number << 4) | packet_str.read_number(4);
             }
             number = (number << 4) | packet_str.read_number(4);
-            let literal = Literal { version, number, sub_packets: Vec::with_capacity(0) };
+            let literal = Literal { version, number };
             return Box::new(literal);
         }
         _ => {
@@ -111,18 +99,6 @@ fn parse_nibbles(packet_str: &mut PacketString) -> Box<dyn Packet> {
                     }
                 }
             }
-            let label = match type_id {
-                0 => "sum",
-                1 => "prod",
-                2 => "min",
-                3 => "max",
-                5 => "greater",
-                6 => "less",
-                7 => "equal",
-                _ => panic!("Invalid operator type: {}", type_id)
-            };
-            let label = label.to_owned();
-
             let op: Box<dyn Fn(Vec<u64>) -> u64> = match type_id {
                 0 => Box::new(|results| results.iter().sum::<u64>()),
                 1 => Box::new(|results| results.iter().fold(1, |product,num| product * *num)),
@@ -148,31 +124,24 @@ fn parse_nibbles(packet_str: &mut PacketString) -> Box<dyn Packet> {
                 }),
                 _ => panic!("Invalid operator type: {}", type_id)
             };
-            let operator = Operator { version, op, label, sub_packets };
+            let operator = Operator { version, op, sub_packets };
             return Box::new(operator);
         }
     }
 }
 
 trait Packet {
-    fn get_version(&self) -> u32;
-    fn get_sub_packets(&self) -> &Packets;
     fn execute(&self) -> u64;
     fn sum_versions(&self) -> u32;
-    fn print(&self, indent: String);
 }
 
 struct Operator {
     version: u32,
     op: Box<dyn Fn(Vec<u64>) -> u64>,
-    sub_packets: Packets,
-    label: String
+    sub_packets: Packets
 }
 
 impl Packet for Operator {
-    fn get_version(&self) -> u32 {
-        self.version
-    }
     fn execute(&self) -> u64 { 
         let results = self.sub_packets.iter()
             .map(|packet| packet.execute())
@@ -180,83 +149,46 @@ impl Packet for Operator {
         (*self.op)(results)
     }
 
-    fn get_sub_packets(&self) -> &Packets { 
-        &self.sub_packets
-    }
-    
     fn sum_versions(&self) -> u32 {
         self.version + self.sub_packets.iter()
             .map(|packet| packet.sum_versions())
             .sum::<u32>()
     }
-    fn print(&self, indent: String) {
-        println!("{}{} of = {}", indent, self.label, self.execute());
-        println!("{}[", indent);
-        self.sub_packets.iter()
-            .for_each(|packet| packet.print(indent.clone() + "  "));
-        println!("{}]", indent);
-    }
 }
 
 struct Literal {
     version: u32,
-    number: u64,
-    sub_packets: Vec<Box<dyn Packet>>
+    number: u64
 }
 
 impl Packet for Literal {
-    fn get_version(&self) -> u32 {
-        self.version
-    }
     fn execute(&self) -> u64 { 
         self.number
     }
 
-    fn get_sub_packets(&self) -> &Packets { 
-        &self.sub_packets 
-    }
-    
     fn sum_versions(&self) -> u32 {
         self.version
     }
-    fn print(&self, indent: String) {
-        println!("{}{}", indent, self.number);
-    }
 }
 
-fn part_one(file_name: &str) {
-    let packet = parse(file_name);
+pub struct Day;
 
-    let sum = packet.sum_versions();
-    
-    println!("Part 1: {}", sum);
-}
+impl Solution for Day {
+    type Parsed = String;
 
-fn part_two(file_name: &str) {
-    let packet = parse(file_name);
-    
-    // packet.print("".to_owned());
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(input.trim().to_owned())
+    }
 
-    let result = packet.execute();
-    
-    println!("Part 2: {}", result);
-}
+    fn part_one(line: &Self::Parsed) -> Answer {
+        let packet = parse_packet(line);
 
-fn samples(file_name: &str) {
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| (line.clone(), PacketString::parse(line.clone())))
-        .for_each(|(line, mut packet_str)| {
-            let packet = parse_nibbles(&mut packet_str);
-            let sum = packet.execute();
-            println!("Sample {} = {}", line, sum);
-        });
-}
+        packet.sum_versions().into()
+    }
 
-fn main() {
-    samples("sample.txt");
-    part_one("input.txt");
-    part_two("input.txt");
+    fn part_two(line: &Self::Parsed) -> Answer {
+        let packet = parse_packet(line);
 
-    println!("Done!");
+        packet.execute().into()
+    }
 }
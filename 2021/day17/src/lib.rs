@@ -0,0 +1,126 @@
+use std::ops::Range;
+use aoc_common::{Answer, Error, Result, Solution};
+
+/// The target area, inclusive at both ends. It's always right of and below
+/// the launcher.
+pub struct Target {
+    x: (i32, i32),
+    y: (i32, i32)
+}
+
+impl Target {
+    /// `target area: x=20..30, y=-10..-5`
+    fn parse(line: &str) -> Option<Target> {
+        let (x, y) = line.trim().strip_prefix("target area: x=")?.split_once(", y=")?;
+        let range = |range: &str| {
+            let (start, end) = range.split_once("..")?;
+            Some((start.parse().ok()?, end.parse().ok()?))
+        };
+        let target = Target { x: range(x)?, y: range(y)? };
+        match target.x.0 > 0 && target.y.1 < 0 {
+            true => Some(target),
+            false => None
+        }
+    }
+
+    /// Every initial velocity that puts the probe in the target after some step.
+    fn hitting_velocities(&self) -> Vec<(i32, i32)> {
+        // any slower and we'll stop short of the x range
+        let mut x_min = 0;
+        while x_min * (x_min + 1) / 2 < self.x.0 {
+            x_min += 1;
+        }
+        let x_max = self.x.1 + 1; // any faster and the first step will over-shoot
+        let y_max = -self.y.0; // any faster and coming back down through 0 will over-shoot
+        let y_min = self.y.0; // any lower and we over-shoot on first step
+
+        let mut velocities = Vec::new();
+
+        let y_start_check = |pos: i32| {
+            pos > self.y.1
+        };
+        let y_end_check = |pos: i32| {
+            pos >= self.y.0
+        };
+
+        let x_start_check = |pos: i32| {
+            pos < self.x.0
+        };
+        let x_end_check = |pos: i32| {
+            pos <= self.x.1
+        };
+
+        for y in y_min..y_max {
+            let y_range = find_within_range(y, i32::MIN, usize::MAX, y_start_check, y_end_check);
+            if y_range.start == y_range.end {
+                continue;
+            }
+            for x in x_min..x_max {
+                let x_range = find_within_range(x, 0, y_range.end, x_start_check, x_end_check);
+                if x_range.end > y_range.start && x_range.start < y_range.end {
+                    velocities.push((x, y));
+                }
+            }
+        }
+
+        velocities
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Target;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Target::parse(input)
+            .ok_or_else(|| Error::parse(1, "expected `target area: x=<a>..<b>, y=<c>..<d>` right of and below the launcher"))
+    }
+
+    fn part_one(target: &Self::Parsed) -> Answer {
+        // the highest shot tops out after vy steps at vy + (vy - 1) + ... + 1
+        target.hitting_velocities()
+            .into_iter()
+            .map(|(_, y)| y.max(0) * (y.max(0) + 1) / 2)
+            .max()
+            .unwrap_or(0)
+            .into()
+    }
+
+    fn part_two(target: &Self::Parsed) -> Answer {
+        target.hitting_velocities().len().into()
+    }
+}
+
+fn find_within_range<S,E>(
+    init_vel: i32, 
+    decrease_to: i32, 
+    all_max_step: usize, 
+    start_check: S, 
+    end_check: E) -> Range<usize> 
+    where
+    S: Fn(i32) -> bool,
+    E: Fn(i32) -> bool
+{
+    let mut min_step = 1;
+    let mut max_step = 1;
+    let mut vel = init_vel;
+    let mut pos = init_vel;
+    while start_check(pos) && vel > decrease_to && max_step <= all_max_step {
+        vel -= 1;
+        pos += vel;
+        min_step += 1;
+        max_step += 1;
+    }
+    while end_check(pos) && max_step <= all_max_step {
+        if vel > decrease_to {
+            vel -= 1;
+            pos += vel;
+        } else {
+            max_step = all_max_step;
+            break;
+        }
+        max_step += 1;
+    }
+    min_step..max_step
+}
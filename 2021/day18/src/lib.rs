@@ -1,12 +1,10 @@
-use std::iter::Sum;
+#![allow(clippy::let_and_return)]
+
 use std::ops::Add;
 use std::iter::Peekable;
 use std::str::Chars;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 enum Element {
     Number(u32),
@@ -23,7 +21,7 @@ impl Element {
     fn parse(line: &mut Peekable<Chars<'_>>) -> Element {
         match *line.peek().unwrap() {
             '[' => Element::pair(Pair::parse(line)),
-            _ => Element::Number(line.next().unwrap().to_digit(10).unwrap() as u32)
+            _ => Element::Number(line.next().unwrap().to_digit(10).unwrap())
         }
     }
     fn pair(pair: Pair) -> Element {
@@ -84,12 +82,6 @@ impl Element {
             Element::Pair(pair) => pair.get_magnitude()
         }
     }
-    fn print(&self) {
-        match self {
-            Element::Number(number) => print!("{}", number),
-            Element::Pair(pair) => pair.print_rec()
-        }
-    }
     fn copy(&self) -> Element {
         match self {
             Element::Number(number) => Element::Number(*number),
@@ -98,7 +90,7 @@ impl Element {
     }
 }
 
-struct Pair {
+pub struct Pair {
     left: Element,
     right: Element
 }
@@ -199,17 +191,6 @@ impl Pair {
     fn get_magnitude(&self) -> u64 {
         (self.left.get_magnitude() * 3) + (self.right.get_magnitude() * 2)
     }
-    fn print(&self) {
-        self.print_rec();
-        println!("");
-    }
-    fn print_rec(&self) {
-        print!("[");
-        self.left.print();
-        print!(",");
-        self.right.print();
-        print!("]");
-    }
     fn copy(&self) -> Self {
         Pair {
             left: self.left.copy(),
@@ -230,58 +211,50 @@ impl Add for Pair {
     }
 }
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
-
-fn get_homework(file_name: &str) -> Vec<Pair> {
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
+fn get_homework(input: &str) -> Vec<Pair> {
+    lines(input).into_iter()
         .map(|line| Pair::parse(&mut line.chars().peekable()))
         .collect::<Vec<_>>()
 }
 
-fn part_one(file_name: &str) {
-    let sum = get_homework(file_name)
-        .into_iter()
-        .reduce(|result, pair| {
-            let result = result + pair;
-            result
-        })
-        .unwrap();
-    
-    println!("Part 1: {}", sum.get_magnitude());
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Pair>;
 
-fn part_two(file_name: &str) {
-    let homework_one = get_homework(file_name);
-    let homework_two = get_homework(file_name);
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(get_homework(input))
+    }
 
-    let mut max_magnitude = 0;
+    fn part_one(homework: &Self::Parsed) -> Answer {
+        let sum = homework.iter()
+            .map(Pair::copy)
+            .reduce(|result, pair| {
+                let result = result + pair;
+                result
+            })
+            .unwrap();
 
-    for i in 0..homework_one.len() {
-        for j in 0..homework_two.len() {
-            if i == j {
-                continue;
-            }
+        sum.get_magnitude().into()
+    }
+
+    fn part_two(homework: &Self::Parsed) -> Answer {
+        let mut max_magnitude = 0;
+
+        for i in 0..homework.len() {
+            for j in 0..homework.len() {
+                if i == j {
+                    continue;
+                }
 
-            let sum = homework_one[i].copy() + homework_two[j].copy();
-            let magnitude = sum.get_magnitude();
-            if magnitude > max_magnitude {
-                max_magnitude = magnitude;
+                let sum = homework[i].copy() + homework[j].copy();
+                let magnitude = sum.get_magnitude();
+                if magnitude > max_magnitude {
+                    max_magnitude = magnitude;
+                }
             }
         }
-    }
-    
-    println!("Part 2: {}", max_magnitude);
-}
 
-fn main() {
-    part_one("input.txt");
-    part_two("sample.txt");
-    part_two("input.txt");
-
-    println!("Done!");
+        max_magnitude.into()
+    }
 }
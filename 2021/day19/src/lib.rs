@@ -1,23 +1,19 @@
-use std::mem::swap;
+#![allow(
+    clippy::needless_range_loop,
+    clippy::ptr_arg,
+    clippy::redundant_closure,
+    clippy::vec_init_then_push,
+)]
+
 use std::cmp::max;
 use std::f64::consts::PI;
 use std::fmt::Display;
 use std::fmt::Formatter;
-use std::collections::BTreeMap;
 use std::collections::VecDeque;
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 struct Point {
@@ -81,7 +77,7 @@ impl Point {
     }
     fn taxicab_distance(&self, other: &Point) -> u64 {
         self.diff(other).dims.iter()
-            .map(|d| d.abs() as u64)
+            .map(|d| d.unsigned_abs() as u64)
             .sum::<u64>()
     }
 }
@@ -147,15 +143,14 @@ impl Permutation {
     }
 }
 
-struct Scanner {
-    label: String,
+pub struct Scanner {
     points: Vec<Point>,
     distances: HashMap<(u32, u16), Vec<(Point, Point)>>,
     translation: Option<Point>
 }
 
 impl Scanner {
-    fn new(label: String, points: Vec<Point>) -> Scanner {
+    fn new(points: Vec<Point>) -> Scanner {
         let mut distances = HashMap::<(u32, u16), Vec<(Point, Point)>>::new();
         for i in 0..points.len() {
             let from = &points[i];
@@ -171,7 +166,6 @@ impl Scanner {
             }
         }
         Scanner {
-            label,
             points,
             distances,
             translation: None
@@ -183,39 +177,13 @@ impl Scanner {
         self.points.iter_mut()
             .for_each(|point| transform(point));
         self.distances.values_mut()
-            .flat_map(|points| points)
+            .flatten()
             .for_each(|(a, b)| {
                 transform(a);
                 transform(b);
             });
     }
 
-    fn print_points(points: &Vec<(Point, Point)>) -> String {
-        let mut formatted = String::new();
-
-        formatted.push('[');
-        points.iter().for_each(|(a,b)| {
-            if formatted.len() != 1 {
-                formatted.push(',');
-                formatted.push(' ');
-            }
-            formatted += format!("{}->{}", a, b).as_str();
-        });
-        formatted.push(']');
-
-        formatted
-    }
-
-    fn _print(&self) {
-        println!("{}", self.label);
-        let distances = self.distances.iter()
-            .collect::<BTreeMap<_,_>>();
-        distances.iter().for_each(|((diff, angle), points)|  {
-            println!("({}, {}) -> {}", diff, angle, Scanner::print_points(points))
-        });
-        println!("");
-    }
-
     fn determine_orientation(
         my_from: Point, my_to: Point, 
         other_from: Point, other_to: Point) -> Option<(Permutation, Point)>
@@ -320,8 +288,7 @@ impl Scanner {
                 true => {
                     let start_angle = max(0, (angle as i32) - 1) as u16;
                     for angle in start_angle..(angle + 2) {
-                        other.distances.get(&(distance, angle)).map(|points| 
-                            points.iter().for_each(|point| other_points.push(point)));
+                        if let Some(points) = other.distances.get(&(distance, angle)) { points.iter().for_each(|point| other_points.push(point)) }
                     }
                 }
                 false => {
@@ -357,25 +324,25 @@ impl Scanner {
     }
 }
 
-fn read_scanners(file_name: &str) -> Vec<Scanner> {
-    let mut lines = get_file_lines(file_name)
-        .flat_map(|line| line.ok());
+fn read_scanners(input: &str) -> Vec<Scanner> {
+    let mut lines = lines(input).into_iter();
 
     let lines = &mut lines;
 
     let mut scanners = Vec::new();
-    while let Some(label) = lines.next() {
+    // each scanner starts with its `--- scanner N ---` header
+    while lines.next().is_some() {
         let points = lines.take_while(|line| !line.is_empty())
             .map(|line| Point::new(&line))
             .collect::<Vec<_>>();
-        scanners.push(Scanner::new(label, points));
+        scanners.push(Scanner::new(points));
     }
 
     scanners
 }
 
-fn order_scanners(file_name: &str) -> Vec<Scanner> {
-    let mut unordered = read_scanners(file_name)
+fn order_scanners(input: &str) -> Vec<Scanner> {
+    let mut unordered = read_scanners(input)
         .into_iter()
         .collect::<VecDeque<_>>();
     
@@ -414,43 +381,42 @@ fn order_scanners(file_name: &str) -> Vec<Scanner> {
     ordered
 }
 
-fn part_one(file_name: &str) {
-    let ordered = order_scanners(file_name);
+pub struct Day;
 
-    let count = ordered.iter()
-        .flat_map(|scanner| scanner.points.iter())
-        .collect::<HashSet<_>>()
-        .len();
-    
-    println!("Part 1: {}", count);
-}
-
-fn part_two(file_name: &str) {
-    let ordered = order_scanners(file_name);
+impl Solution for Day {
+    type Parsed = Vec<Scanner>;
 
-    let mut furthest = 0;
-    for i in 0..ordered.len() {
-        let one = &ordered[i];
-        let one_offset = one.translation.as_ref().unwrap();
-        for j in 0..ordered.len() {
-            if i == j {
-                continue;
-            }
-            let two = &ordered[j];
-            let two_offset = two.translation.as_ref().unwrap();
-            let distance = two_offset.taxicab_distance(one_offset);
-            if distance > furthest {
-                furthest = distance;
-            }
-        }
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(order_scanners(input))
     }
 
-    println!("Part 2: {}", furthest);
-}
+    fn part_one(ordered: &Self::Parsed) -> Answer {
+        let count = ordered.iter()
+            .flat_map(|scanner| scanner.points.iter())
+            .collect::<HashSet<_>>()
+            .len();
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+        count.into()
+    }
 
-    println!("Done!");
+    fn part_two(ordered: &Self::Parsed) -> Answer {
+        let mut furthest = 0;
+        for i in 0..ordered.len() {
+            let one = &ordered[i];
+            let one_offset = one.translation.as_ref().unwrap();
+            for j in 0..ordered.len() {
+                if i == j {
+                    continue;
+                }
+                let two = &ordered[j];
+                let two_offset = two.translation.as_ref().unwrap();
+                let distance = two_offset.taxicab_distance(one_offset);
+                if distance > furthest {
+                    furthest = distance;
+                }
+            }
+        }
+
+        furthest.into()
+    }
 }
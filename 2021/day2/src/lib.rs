@@ -0,0 +1,62 @@
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let mut horizontal = 0;
+        let mut depth = 0;
+
+        lines.iter()
+            .map(|line| {
+                let pieces = line.split(" ").collect::<Vec<&str>>();
+                let command = pieces[0].to_owned();
+                let amount = pieces[1].parse::<i32>().unwrap();
+                (command, amount)
+            })
+            .for_each(|(command, amount)| {
+                match command.as_str() {
+                    "up" => depth -= amount,
+                    "down" => depth += amount,
+                    "forward" => horizontal += amount,
+                    _ => panic!("Invalid command: {}", command)
+                };
+            });
+
+        (horizontal * depth).into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut horizontal = 0;
+        let mut depth = 0;
+        let mut aim = 0;
+
+        lines.iter()
+            .map(|line| {
+                let pieces = line.split(" ").collect::<Vec<&str>>();
+                let command = pieces[0].to_owned();
+                let amount = pieces[1].parse::<i32>().unwrap();
+                (command, amount)
+            })
+            .for_each(|(command, amount)| {
+                match command.as_str() {
+                    "up" => aim -= amount,
+                    "down" => aim += amount,
+                    "forward" => { 
+                        horizontal += amount;
+                        depth += amount * aim;
+                    },
+                    _ => panic!("Invalid command: {}", command)
+                };
+            });
+
+        (horizontal * depth).into()
+    }
+}
@@ -1,16 +1,10 @@
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
+#![allow(clippy::assign_op_pattern)]
 
-type Input = Lines<BufReader<File>>;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
-
-struct Enhancer {
+#[derive(Clone)]
+pub struct Enhancer {
     algorithm: Vec<bool>,
     index: usize,
     r: i32,
@@ -59,7 +53,8 @@ impl Enhancer {
     }
 }
 
-struct Image {
+#[derive(Clone)]
+pub struct Image {
     rows: Vec<Vec<bool>>,
     width: i32,
     height: i32,
@@ -68,12 +63,11 @@ struct Image {
 
 impl Image {
     fn get_bit(&self, r: i32, c: i32) -> usize {
-        if r >= 0 && r < self.height {
-            if c >= 0 && c < self.width {
+        if r >= 0 && r < self.height
+            && c >= 0 && c < self.width {
                 let row = &self.rows[r as usize];
                 return row[c as usize] as usize;
             }
-        }
         self.infinite_bit
     }
     fn new(rows: Vec<Vec<bool>>, infinite_bit: usize) -> Image {
@@ -86,24 +80,10 @@ impl Image {
             infinite_bit
         }
     }
-    fn print(&self) {
-        for r in 0..self.height as usize {
-            let row = &self.rows[r];
-            for c in 0..self.width as usize {
-                if row[c] {
-                    print!("#");
-                } else {
-                    print!(".");
-                }
-            }
-            println!("");
-        }
-    }
 }
 
-fn read_input(file_name: &str) -> (Enhancer, Image) {
-    let mut lines = get_file_lines(file_name)
-        .flat_map(|line| line.ok());
+fn read_input(input: &str) -> (Enhancer, Image) {
+    let mut lines = lines(input).into_iter();
     
     let algorithm = lines.next().unwrap()
         .chars()
@@ -143,36 +123,34 @@ fn enhance(mut image: Image, mut enhancer: Enhancer, times: usize) -> Image {
     image
 }
 
-fn part_one(file_name: &str) {
-    let (enhancer, image) = read_input(file_name);
+pub struct Day;
 
-    let image = enhance(image, enhancer, 2);
+impl Solution for Day {
+    type Parsed = (Enhancer, Image);
 
-    let lit = image.rows.iter()
-        .flat_map(|row| row)
-        .filter(|lit| **lit)
-        .count();
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(read_input(input))
+    }
 
-    println!("Part 1: {}", lit);
-}
+    fn part_one((enhancer, image): &Self::Parsed) -> Answer {
+        let image = enhance(image.clone(), enhancer.clone(), 2);
 
-fn part_two(file_name: &str) {
-    let (enhancer, image) = read_input(file_name);
+        let lit = image.rows.iter()
+            .flatten()
+            .filter(|lit| **lit)
+            .count();
 
-    let image = enhance(image, enhancer, 50);
-    
-    let lit = image.rows.iter()
-        .flat_map(|row| row)
-        .filter(|lit| **lit)
-        .count();
+        lit.into()
+    }
 
-    println!("Part 2: {}", lit);
-}
+    fn part_two((enhancer, image): &Self::Parsed) -> Answer {
+        let image = enhance(image.clone(), enhancer.clone(), 50);
 
-fn main() {
-    part_one("input.txt");
-    part_two("sample.txt");
-    part_two("input.txt");
+        let lit = image.rows.iter()
+            .flatten()
+            .filter(|lit| **lit)
+            .count();
 
-    println!("Done!");
+        lit.into()
+    }
 }
@@ -1,54 +1,87 @@
+#![allow(clippy::type_complexity)]
+
+use aoc_common::{Answer, Error, Result, Solution};
 
 use std::cmp::max;
 use std::collections::HashMap;
 use std::cmp::min;
 
-const PLAYER_1_INIT: u32 = 2;
-const PLAYER_2_INIT: u32 = 8;
 const END_SCORE_PT1: u32 = 1000;
 const END_SCORE_PT2: usize = 21;
 
-fn part_one() {
-    
-    let player1_step_series = vec![
-        6, 4, 2, 0, 8 // steps
-    ];
-    let player2_step_series = vec![
-        5, 3, 1, 9, 7 // steps
-    ];
-
-    let mut step = 0;
-    let mut player1_total = 0;
-    let mut player2_total = 0;
-    let mut player1_pos = PLAYER_1_INIT;
-    let mut player2_pos = PLAYER_2_INIT;
-    loop {
-        let player1_new_pos = player1_pos + player1_step_series[step % 5];
-        player1_pos = (player1_new_pos % 11) + player1_new_pos / 11;
-        player1_total += player1_pos;
-        if player1_total >= END_SCORE_PT1 {
-            break;
+pub struct Day;
+
+impl Solution for Day {
+    // starting positions of player 1 and player 2
+    type Parsed = (u32, u32);
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let mut positions = Vec::new();
+        for (index, line) in input.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let expected = format!("Player {} starting position: ", positions.len() + 1);
+            let position = line.trim()
+                .strip_prefix(&expected)
+                .and_then(|position| position.parse().ok())
+                .filter(|position| (1..=10).contains(position))
+                .ok_or_else(|| Error::parse(index + 1, format!("expected `{}<1-10>`, found `{}`", expected, line)))?;
+            positions.push(position);
         }
-        
-        let player2_new_pos = player2_pos + player2_step_series[step % 5];
-        player2_pos = (player2_new_pos % 11) + player2_new_pos / 11;
-        player2_total += player2_pos;
-        if player2_total >= END_SCORE_PT1 {
-            break;
+
+        match positions[..] {
+            [player1, player2] => Ok((player1, player2)),
+            _ => Err(Error::parse(input.lines().count(), "expected a starting position for each of the two players"))
         }
-        step += 1;
     }
-    let step = (step + 1) as u32;
 
-    let min_score = min(player1_total, player2_total);
-    
-    let rolls = match player1_total >= END_SCORE_PT1 {
-        true => ((step * 2) - 1) * 3,
-        false => step * 2 * 3
-    };
+    fn part_one(&(player1_init, player2_init): &Self::Parsed) -> Answer {
+
+        let player1_step_series = [
+            6, 4, 2, 0, 8 // steps
+        ];
+        let player2_step_series = [
+            5, 3, 1, 9, 7 // steps
+        ];
+
+        let mut step = 0;
+        let mut player1_total = 0;
+        let mut player2_total = 0;
+        let mut player1_pos = player1_init;
+        let mut player2_pos = player2_init;
+        loop {
+            let player1_new_pos = player1_pos + player1_step_series[step % 5];
+            player1_pos = (player1_new_pos % 11) + player1_new_pos / 11;
+            player1_total += player1_pos;
+            if player1_total >= END_SCORE_PT1 {
+                break;
+            }
+
+            let player2_new_pos = player2_pos + player2_step_series[step % 5];
+            player2_pos = (player2_new_pos % 11) + player2_new_pos / 11;
+            player2_total += player2_pos;
+            if player2_total >= END_SCORE_PT1 {
+                break;
+            }
+            step += 1;
+        }
+        let step = (step + 1) as u32;
+
+        let min_score = min(player1_total, player2_total);
 
+        let rolls = match player1_total >= END_SCORE_PT1 {
+            true => ((step * 2) - 1) * 3,
+            false => step * 2 * 3
+        };
 
-    println!("Part 1: {} * {} = {}", min_score, rolls, (min_score * rolls));
+        (min_score * rolls).into()
+    }
+
+    fn part_two(&(player1_init, player2_init): &Self::Parsed) -> Answer {
+        let mut quantum = Quantum::new();
+
+        let (player1, player2) = quantum.get_win_count(player1_init as usize, 0, player2_init as usize, 0, true);
+
+        max(player1, player2).into()
+    }
 }
 
 struct Quantum {
@@ -124,18 +157,3 @@ impl Quantum {
         count
     }
 }
-
-fn part_two() {
-    let mut quantum = Quantum::new();
-
-    let (player1, player2) = quantum.get_win_count(PLAYER_1_INIT as usize, 0, PLAYER_2_INIT as usize, 0, true);
-
-    println!("Part 2: max({}, {}) = {}", player1, player2, max(player1, player2));
-}
-
-fn main() {
-    part_one();
-    part_two();
-
-    println!("Done!");
-}
@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use aoc_common::interval::{Interval, Region};
+use aoc_common::{Answer, Error, Result, Solution};
+
+type Cuboid = Region<i32, 3>;
+
+/// Turn every cube in `cuboid` on or off.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step {
+    on: bool,
+    cuboid: Cuboid
+}
+
+fn parse_range(range: &str, axis: char) -> Option<Interval<i32>> {
+    let range = range.strip_prefix(axis)?.strip_prefix('=')?;
+    let (from, to) = range.split_once("..")?;
+    let from = from.parse::<i32>().ok()?;
+    let to = to.parse::<i32>().ok()?;
+    if from > to {
+        return None;
+    }
+    Some(Interval::new(from, to))
+}
+
+fn parse_step(line: &str) -> Option<Step> {
+    let (state, ranges) = line.split_once(' ')?;
+    let on = match state {
+        "on" => true,
+        "off" => false,
+        _ => return None
+    };
+    let mut ranges = ranges.split(',');
+    let mut next_range = |axis| ranges.next().and_then(|range| parse_range(range, axis));
+    let cuboid = Region::new([next_range('x')?, next_range('y')?, next_range('z')?]);
+    Some(Step { on, cuboid })
+}
+
+/// The reboot steps in `input`, read one line at a time.
+fn steps(input: &str) -> impl Iterator<Item = Result<Step>> + '_ {
+    input.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| {
+            parse_step(line)
+                .ok_or_else(|| Error::parse(index + 1, format!("expected `on|off x=<a>..<b>,y=<c>..<d>,z=<e>..<f>`, found `{}`", line)))
+        })
+}
+
+/// The cubes that are on, as a count of how many times each cuboid is added
+/// or taken away. Every cube that is on is counted exactly once overall, so
+/// cutting a step's cuboid out only means cancelling whatever already counts
+/// inside it.
+#[derive(Default)]
+pub struct Reactor {
+    cuboids: HashMap<Cuboid, i64>
+}
+
+impl Reactor {
+    pub fn new() -> Reactor {
+        Reactor { cuboids: HashMap::new() }
+    }
+
+    /// The reactor after each of `steps` in turn.
+    pub fn run<'a, I>(steps: I) -> Reactor where I: IntoIterator<Item = &'a Step> {
+        let mut reactor = Reactor::new();
+        steps.into_iter().for_each(|step| reactor.apply(step));
+        reactor
+    }
+
+    pub fn apply(&mut self, step: &Step) {
+        let overlaps = self.cuboids.iter()
+            .filter_map(|(cuboid, count)| cuboid.intersection(&step.cuboid).map(|overlap| (overlap, -count)))
+            .collect::<Vec<_>>();
+
+        let added = overlaps.into_iter()
+            .chain(if step.on { Some((step.cuboid, 1)) } else { None });
+        for (cuboid, count) in added {
+            let total = self.cuboids.entry(cuboid).or_insert(0);
+            *total += count;
+            if *total == 0 {
+                self.cuboids.remove(&cuboid);
+            }
+        }
+    }
+
+    /// How many cubes are on.
+    pub fn lit_volume(&self) -> u64 {
+        self.cuboids.iter()
+            .map(|(cuboid, count)| cuboid.volume() as i64 * count)
+            .sum::<i64>() as u64
+    }
+
+    /// How many cubes inside `region` are on.
+    pub fn lit_volume_in(&self, region: &Cuboid) -> u64 {
+        self.cuboids.iter()
+            .filter_map(|(cuboid, count)| cuboid.intersection(region).map(|overlap| overlap.volume() as i64 * count))
+            .sum::<i64>() as u64
+    }
+
+    pub fn is_lit(&self, point: [i32; 3]) -> bool {
+        let count = self.cuboids.iter()
+            .filter(|(cuboid, _)| cuboid.contains(point))
+            .map(|(_, count)| count)
+            .sum::<i64>();
+        count > 0
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        steps(input).collect()
+    }
+
+    fn part_one(steps: &Self::Parsed) -> Answer {
+        let initialization = Region::new([Interval::new(-50, 50); 3]);
+        Reactor::run(steps).lit_volume_in(&initialization).into()
+    }
+
+    fn part_two(steps: &Self::Parsed) -> Answer {
+        Reactor::run(steps).lit_volume().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "\
+on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10";
+
+    #[test]
+    fn answers_queries_after_each_step() {
+        let steps = Day::parse(SMALL).unwrap();
+        let mut reactor = Reactor::new();
+        let mut lit = Vec::new();
+        for step in steps.iter() {
+            reactor.apply(step);
+            lit.push((reactor.lit_volume(), reactor.is_lit([10, 10, 10]), reactor.is_lit([13, 13, 13])));
+        }
+        assert_eq!(vec![(27, true, false), (46, true, true), (38, false, true), (39, true, true)], lit);
+
+        // the off step turned a 2x2x2 corner of the first cuboid back off
+        let first = steps[0].cuboid;
+        assert_eq!(19, Reactor::run(&steps[..3]).lit_volume_in(&first));
+        assert_eq!(0, reactor.lit_volume_in(&Region::new([Interval::new(-5, 5); 3])));
+    }
+
+    #[test]
+    fn reports_bad_lines() {
+        let error = Day::parse("on x=1..2,y=3..4,z=5..6\ntoggle x=1..2,y=3..4,z=5..6").err().unwrap();
+        assert!(error.to_string().contains("line 2"), "{}", error);
+        assert!(Day::parse("on x=2..1,y=3..4,z=5..6").is_err());
+    }
+}
@@ -1,15 +1,12 @@
-use std::ops::Range;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
+#![allow(
+    clippy::len_zero,
+    clippy::needless_range_loop,
+    clippy::ptr_arg,
+)]
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use std::ops::Range;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 const HALL_SIZE: usize = 7;
 const ROOMS: usize = 4;
@@ -57,18 +54,11 @@ impl Amphipod {
             Desert => 1000
         }
     }
-    fn to_char(&self) -> char {
-        match self {
-            Amber => 'A',
-            Bronze => 'B',
-            Copper => 'C',
-            Desert => 'D'
-        }
-    }
 }
 
 use Amphipod::*;
 
+#[derive(Clone)]
 struct Room {
     owner: Amphipod,
     wrong: Vec<Amphipod>,
@@ -106,11 +96,12 @@ impl Room {
         self.wrong.pop().unwrap();
     }
     fn get_next(&self) -> Amphipod {
-        self.wrong.last().unwrap().clone()
+        *self.wrong.last().unwrap()
     }
 }
 
-struct Burrow {
+#[derive(Clone)]
+pub struct Burrow {
     rooms: Vec<Room>,
     hallway: Vec<Option<Amphipod>>,
     in_hallway: Vec<usize>,
@@ -120,9 +111,8 @@ struct Burrow {
 }
 
 impl Burrow {
-    fn parse(file_name: &str) -> Burrow {
-        let mut lines = get_file_lines(file_name)
-            .flat_map(|line| line.ok())
+    fn parse(input: &str) -> Burrow {
+        let mut lines = lines(input).into_iter()
             .skip(2)
             .take(2)
             .collect::<Vec<_>>();
@@ -186,7 +176,7 @@ impl Burrow {
             had_move = false;
             for i in 0..ROOMS {
                 if self.rooms[i].is_empty() {
-                    let owner = self.rooms[i].owner.clone();
+                    let owner = self.rooms[i].owner;
                     let per_move_cost = owner.cost();
                     if let Some(from_index) = (0..4).filter(|j| i != *j)
                         .find(|j| {
@@ -217,7 +207,7 @@ impl Burrow {
                         self.cost += cost;
                         self.rooms[from_index].remove_next();
                         self.rooms[i].add();
-                        moved.push((-(from_index as i32) - 1, owner.clone(), cost));
+                        moved.push((-(from_index as i32) - 1, owner, cost));
                         had_move = true;
                         break;
                     }
@@ -240,7 +230,6 @@ impl Burrow {
                             }
                         }
 
-
                         let right_hall_index = left_hall_index + 1;
                         if let Some(index) = self.find_next_in_hall(right_hall_index, true) {
                             if *self.hallway[index].as_ref().unwrap() == owner {
@@ -266,7 +255,7 @@ impl Burrow {
             let amp_index = amphipod.index();
             self.rooms[amp_index].remove();
             if *index < 0 {
-                let to_room = -1 * (index + 1);
+                let to_room = -(index + 1);
                 self.rooms[to_room as usize].wrong.push(*amphipod);
             } else {
                 let index = *index as usize;
@@ -293,7 +282,7 @@ impl Burrow {
 fn real_hall_pos(pos: usize) -> usize {
     match pos {
         x if x < 2 => x,
-        x if x >= 2 && x < 5 => ((x - 2) * 2) + 3,
+        x if (2..5).contains(&x) => ((x - 2) * 2) + 3,
         x if x >= 5 => x + 4,
         _ => panic!("Invalid hall pos: {}", pos)
     }
@@ -306,71 +295,13 @@ fn calc_cost(from: usize, to: usize, per_move_cost: usize) -> usize {
     }
 }
 
-fn print_hall_char(amphipod: &Option<Amphipod>) {
-    let c = match amphipod {
-        Some(amphipod) => amphipod.to_char(),
-        None => '.'
-    };
-    print!("{}", c);
-}
-
-fn _print(burrow: &Burrow) {
-    println!("#############");
-    print!("#");
-    for i in 0..2 {
-        print_hall_char(&burrow.hallway[i]);
-    }
-    for i in 2..5 {
-        print!("x");
-        print_hall_char(&burrow.hallway[i]);
-    }
-    print!("x");
-    for i in 5..7 {
-        print_hall_char(&burrow.hallway[i]);
-    }
-    println!("#");
-    for r in 0..burrow.rows {
-        if r == 0 {
-            print!("##");
-        } else {
-            print!("  ");
-        }
-        let index = (burrow.rows - r) - 1;
-        for i in 0..ROOMS {
-            print!("#");
-            let room = &burrow.rooms[i];
-            let top = room.correct + room.wrong.len();
-            if top > index {
-                if index >= room.correct {
-                    print!("{}", room.wrong[index - room.correct].to_char());
-                } else {
-                    print!("{}", room.owner.to_char());
-                }
-            } else {
-                print!(".");
-            }
-        }
-        if r == 0 {
-            println!("###");
-        } else {
-            println!("#");
-        }
-    }
-    println!("  #########");
-    println!("");
-}
-
-fn next_move(burrow: &mut Burrow, level: usize) {
+fn next_move(burrow: &mut Burrow, _level: usize) {
     let orig_cost = burrow.cost;
     if orig_cost > burrow.lowest_cost {
         // println!("[{}] Not attempting any further moves, cost to high: {} > {}", level, orig_cost, burrow.lowest_cost);
         return;
     }
     let to_room_hist = burrow.move_to_room();
-    // if to_room_hist.len() > 0 {
-    //     println!("---- [{}] after move to room, cost: {} from: {:?} ----", level, burrow.cost, to_room_hist);
-    //     _print(&burrow);
-    // }
 
     // move from closed rooms to the hallway
     let mut closed_count = 0;
@@ -391,9 +322,7 @@ fn next_move(burrow: &mut Burrow, level: usize) {
                 let cost = base_cost + calc_cost(cur_pos, real_hall_pos(to_pos), per_pos_cost);
                 burrow.cost += cost;
                 burrow.hallway[to_pos] = Some(*next);
-                // println!("---- [{}] after move to hallway from {} to {} - cost: {} ----", level, cur_pos, to_pos, burrow.cost);
-                // _print(&burrow);
-                next_move(burrow, level + 1);
+                next_move(burrow, _level + 1);
                 burrow.hallway[to_pos] = None;
                 burrow.cost -= cost;
             }
@@ -417,44 +346,44 @@ fn next_move(burrow: &mut Burrow, level: usize) {
     // println!("[{}] End of tries, cost is now: {} - was {}", level, burrow.cost, orig_cost);
 }
 
-fn part_one(file_name: &str) {
-    let mut burrow = Burrow::parse(file_name);
-    burrow.move_bottom_to_correct();
+pub struct Day;
 
-    // println!("---- initial ----");
-    // _print(&burrow);
+impl Solution for Day {
+    type Parsed = Burrow;
 
-    next_move(&mut burrow, 0);
-
-    println!("Part 1: {}", burrow.lowest_cost);
-}
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Burrow::parse(input))
+    }
 
-fn part_two(file_name: &str) {
-    let mut burrow = Burrow::parse(file_name);
+    fn part_one(burrow: &Self::Parsed) -> Answer {
+        let mut burrow = burrow.clone();
+        burrow.move_bottom_to_correct();
 
-    burrow.rows = 4;
-    let new_rows = vec![
-        vec![Desert, Copper, Bronze, Amber],
-        vec![Desert, Bronze, Amber, Copper],
-    ];
+        next_move(&mut burrow, 0);
 
-    for row in new_rows {
-        for i in 0..ROOMS {
-            let amphipod = row[i];
-            burrow.rooms[i].wrong.insert(1, amphipod);
-        }
+        burrow.lowest_cost.into()
     }
 
-    burrow.move_bottom_to_correct();
+    fn part_two(burrow: &Self::Parsed) -> Answer {
+        let mut burrow = burrow.clone();
 
-    next_move(&mut burrow, 0);
+        burrow.rows = 4;
+        let new_rows = vec![
+            vec![Desert, Copper, Bronze, Amber],
+            vec![Desert, Bronze, Amber, Copper],
+        ];
 
-    println!("Part 2: {}", burrow.lowest_cost);
-}
+        for row in new_rows {
+            for i in 0..ROOMS {
+                let amphipod = row[i];
+                burrow.rooms[i].wrong.insert(1, amphipod);
+            }
+        }
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+        burrow.move_bottom_to_correct();
 
-    println!("Done!");
+        next_move(&mut burrow, 0);
+
+        burrow.lowest_cost.into()
+    }
 }
@@ -0,0 +1,335 @@
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    Inp,
+    Add,
+    Mul,
+    Div,
+    Mod,
+    Eql
+}
+
+impl Op {
+    fn parse(name: &str) -> Op {
+        match name {
+            "inp" => Op::Inp,
+            "add" => Op::Add,
+            "mul" => Op::Mul,
+            "div" => Op::Div,
+            "mod" => Op::Mod,
+            "eql" => Op::Eql,
+            _ => panic!("Invalid operator: {}", name)
+        }
+    }
+    /// None where the ALU would crash.
+    fn apply(&self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Op::Inp => panic!("inp has no second operand"),
+            Op::Add => Some(a + b),
+            Op::Mul => Some(a * b),
+            Op::Div if b == 0 => None,
+            Op::Div => Some(a / b),
+            Op::Mod if a < 0 || b <= 0 => None,
+            Op::Mod => Some(a % b),
+            Op::Eql => Some((a == b) as i64)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Operand {
+    Register(usize),
+    Const(i64)
+}
+
+fn register(name: &str) -> usize {
+    match name {
+        "w" => 0,
+        "x" => 1,
+        "y" => 2,
+        "z" => 3,
+        _ => panic!("Invalid register: {}", name)
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    op: Op,
+    a: usize,
+    b: Operand
+}
+
+impl Instruction {
+    fn parse(line: &str) -> Instruction {
+        let pieces = line.split_whitespace().collect::<Vec<_>>();
+        let b = match pieces.get(2) {
+            None => Operand::Const(0),
+            Some(b) => match b.parse::<i64>() {
+                Ok(value) => Operand::Const(value),
+                Err(_) => Operand::Register(register(b))
+            }
+        };
+        Instruction { op: Op::parse(pieces[0]), a: register(pieces[1]), b }
+    }
+}
+
+/// Runs the program on the given input digits, returning the registers
+/// w, x, y and z, or None if it crashes or runs out of input.
+fn run(program: &[Instruction], input: &[i64]) -> Option<[i64; 4]> {
+    let mut registers = [0; 4];
+    let mut input = input.iter();
+
+    for instruction in program {
+        registers[instruction.a] = match instruction.op {
+            Op::Inp => *input.next()?,
+            op => {
+                let b = match instruction.b {
+                    Operand::Register(index) => registers[index],
+                    Operand::Const(value) => value
+                };
+                op.apply(registers[instruction.a], b)?
+            }
+        };
+    }
+
+    Some(registers)
+}
+
+/// A register's value in terms of the input digits.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Term {
+    Const(i64),
+    // the input digit at this index plus an offset
+    Digit(usize, i64)
+}
+
+impl Term {
+    fn range(&self) -> (i64, i64) {
+        match self {
+            Term::Const(value) => (*value, *value),
+            Term::Digit(_, offset) => (1 + offset, 9 + offset)
+        }
+    }
+    fn is_base_26_place(&self) -> bool {
+        let (low, high) = self.range();
+        low >= 0 && high < 26
+    }
+    fn add(&self, other: &Term) -> Option<Term> {
+        match (self, other) {
+            (Term::Const(a), Term::Const(b)) => Some(Term::Const(a + b)),
+            (Term::Digit(input, offset), Term::Const(value)) | (Term::Const(value), Term::Digit(input, offset)) =>
+                Some(Term::Digit(*input, offset + value)),
+            _ => None
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Term(Term),
+    // a number written out in base 26, most significant place first, which
+    // is how MONAD uses z as a stack
+    Places(Vec<Term>)
+}
+
+impl Value {
+    fn places(&self) -> Option<Vec<Term>> {
+        match self {
+            Value::Term(Term::Const(0)) => Some(Vec::new()),
+            Value::Term(term) if term.is_base_26_place() => Some(vec![*term]),
+            Value::Term(_) => None,
+            Value::Places(places) => Some(places.clone())
+        }
+    }
+    fn from_places(places: Vec<Term>) -> Option<Value> {
+        if !places.iter().all(|place| place.is_base_26_place()) {
+            return None;
+        }
+        Some(match places.len() {
+            0 => Value::Term(Term::Const(0)),
+            1 => Value::Term(places[0]),
+            _ => Value::Places(places)
+        })
+    }
+}
+
+/// Two input digits MONAD needs to be equal once offset: the digit at
+/// `later` has to be `difference` more than the one at `earlier`.
+#[derive(Debug, PartialEq)]
+struct Pair {
+    earlier: usize,
+    later: usize,
+    difference: i64
+}
+
+/// Runs the program with every input digit only known to be 1 to 9. Each
+/// `eql` that could go either way is assumed to be true, as that's the only
+/// way the stack in z gets emptied again, and the digits it compares are
+/// recorded as a pair. None if the program does something this can't follow.
+fn analyse(program: &[Instruction]) -> Option<(usize, Vec<Pair>)> {
+    let mut registers = vec![Value::Term(Term::Const(0)); 4];
+    let mut inputs = 0;
+    let mut pairs = Vec::new();
+
+    for instruction in program {
+        if instruction.op == Op::Inp {
+            registers[instruction.a] = Value::Term(Term::Digit(inputs, 0));
+            inputs += 1;
+            continue;
+        }
+        let b = match instruction.b {
+            Operand::Register(index) => registers[index].clone(),
+            Operand::Const(value) => Value::Term(Term::Const(value))
+        };
+        registers[instruction.a] = apply_symbolic(instruction.op, &registers[instruction.a], &b, &mut pairs)?;
+    }
+
+    match registers[3] == Value::Term(Term::Const(0)) {
+        true => Some((inputs, pairs)),
+        false => None
+    }
+}
+
+fn apply_symbolic(op: Op, a: &Value, b: &Value, pairs: &mut Vec<Pair>) -> Option<Value> {
+    use Term::*;
+    use Value::Term as T;
+
+    match (op, a, b) {
+        (op, T(Const(a)), T(Const(b))) => Some(T(Const(op.apply(*a, *b)?))),
+        (Op::Mul, _, T(Const(0))) | (Op::Mul, T(Const(0)), _) => Some(T(Const(0))),
+        (Op::Mul, value, T(Const(1))) | (Op::Mul, T(Const(1)), value)
+            | (Op::Add, value, T(Const(0))) | (Op::Add, T(Const(0)), value)
+            | (Op::Div, value, T(Const(1))) => Some(value.clone()),
+        (Op::Add, T(a), T(b)) if a.add(b).is_some() => Some(T(a.add(b)?)),
+        (Op::Add, value, T(term)) => {
+            let mut places = value.places()?;
+            let lowest = places.pop().unwrap_or(Const(0));
+            places.push(lowest.add(term)?);
+            Value::from_places(places)
+        },
+        (Op::Mul, value, T(Const(26))) => {
+            let mut places = value.places()?;
+            places.push(Const(0));
+            Value::from_places(places)
+        },
+        (Op::Div, value, T(Const(26))) => {
+            let mut places = value.places()?;
+            places.pop();
+            Value::from_places(places)
+        },
+        (Op::Mod, value, T(Const(26))) => Some(T(value.places()?.pop().unwrap_or(Const(0)))),
+        (Op::Eql, T(a), T(b)) => {
+            let ((a_low, a_high), (b_low, b_high)) = (a.range(), b.range());
+            if a_high < b_low || b_high < a_low {
+                return Some(T(Const(0)));
+            }
+            match (*a, *b) {
+                (Digit(a, a_offset), Digit(b, b_offset)) if a != b => {
+                    // a + a_offset == b + b_offset
+                    pairs.push(match a < b {
+                        true => Pair { earlier: a, later: b, difference: a_offset - b_offset },
+                        false => Pair { earlier: b, later: a, difference: b_offset - a_offset }
+                    });
+                    Some(T(Const(1)))
+                },
+                _ => None
+            }
+        },
+        _ => None
+    }
+}
+
+fn model_number(digits: &[i64]) -> i64 {
+    digits.iter().fold(0, |number, digit| number * 10 + digit)
+}
+
+/// The largest or smallest model number MONAD accepts, checked by actually
+/// running it.
+fn find_model_number(program: &[Instruction], largest: bool) -> Option<i64> {
+    let (inputs, pairs) = analyse(program)?;
+
+    let mut digits = vec![if largest { 9 } else { 1 }; inputs];
+    for Pair { earlier, later, difference } in pairs {
+        let digit = match largest {
+            true => 9.min(9 - difference),
+            false => 1.max(1 - difference)
+        };
+        if !(1..=9).contains(&digit) || !(1..=9).contains(&(digit + difference)) {
+            return None;
+        }
+        digits[earlier] = digit;
+        digits[later] = digit + difference;
+    }
+
+    match run(program, &digits)?[3] == 0 {
+        true => Some(model_number(&digits)),
+        false => None
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input).iter()
+            .map(|line| Instruction::parse(line))
+            .collect())
+    }
+
+    fn part_one(program: &Self::Parsed) -> Answer {
+        match find_model_number(program, true) {
+            Some(number) => number.into(),
+            None => Answer::Unsolved
+        }
+    }
+
+    fn part_two(program: &Self::Parsed) -> Answer {
+        match find_model_number(program, false) {
+            Some(number) => number.into(),
+            None => Answer::Unsolved
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(program: &str) -> Vec<Instruction> {
+        program.lines().map(Instruction::parse).collect()
+    }
+
+    // the shape of every block in MONAD, pushing when divisor is 1 and
+    // popping when it's 26
+    fn monad(blocks: &[(i64, i64, i64)]) -> Vec<Instruction> {
+        let text = blocks.iter()
+            .map(|(divisor, check, offset)| format!(
+                "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\nmul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\nmul y x\nadd z y\n",
+                divisor, check, offset))
+            .collect::<String>();
+        parse(&text)
+    }
+
+    #[test]
+    fn runs_binary_example() {
+        let program = parse("inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\nmod x 2\ndiv w 2\nmod w 2");
+        assert_eq!(Some([1, 1, 0, 1]), run(&program, &[13]));
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let program = monad(&[(1, 12, 4), (1, 11, 10), (26, -8, 2), (1, 14, 1), (26, -3, 7), (26, -11, 5)]);
+        let valid = (0..9i64.pow(6))
+            .map(|n| (0..6).rev().map(|place| n / 9i64.pow(place) % 9 + 1).collect::<Vec<_>>())
+            .filter(|digits| run(&program, digits).unwrap()[3] == 0)
+            .map(|digits| model_number(&digits))
+            .collect::<Vec<_>>();
+
+        assert_eq!(valid.iter().max().copied(), find_model_number(&program, true));
+        assert_eq!(valid.iter().min().copied(), find_model_number(&program, false));
+    }
+}
@@ -1,17 +1,10 @@
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
+#![allow(clippy::needless_range_loop, clippy::println_empty_string)]
 
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 #[derive(Clone, Copy, Eq, PartialEq)]
-enum Cucumber {
+pub enum Cucumber {
     NONE,
     EAST,
     SOUTH
@@ -19,16 +12,16 @@ enum Cucumber {
 
 use Cucumber::*;
 
-struct Trench {
+#[derive(Clone)]
+pub struct Trench {
     width: usize,
     height: usize,
     grid: Vec<Vec<Cucumber>>,
 }
 
 impl Trench {
-    fn from_file(file_name: &str) -> Trench {
-        let grid = get_file_lines(file_name)
-            .flat_map(|line| line.ok())
+    fn parse(input: &str) -> Trench {
+        let grid = lines(input).into_iter()
             .map(|line| line.chars()
                 .map(|c| match c {
                     '>' => EAST,
@@ -42,8 +35,6 @@ impl Trench {
         let height = grid.len();
         let width = grid[0].len();
 
-        println!("Grid is {} x {}", height, width);
-
         Trench {
             height,
             width,
@@ -116,19 +107,28 @@ impl Trench {
 
 }
 
-fn part_one(file_name: &str) {
-    let mut trench = Trench::from_file(file_name);
+pub struct Day;
 
-    let mut steps = 1;
-    while trench.execute_step() {
-        steps += 1;
+impl Solution for Day {
+    type Parsed = Trench;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Trench::parse(input))
     }
-    
-    println!("Part 1: {}", steps);
-}
 
-fn main() {
-    part_one("input.txt");
+    fn part_one(trench: &Self::Parsed) -> Answer {
+        let mut trench = trench.clone();
 
-    println!("Done!");
+        let mut steps = 1;
+        while trench.execute_step() {
+            steps += 1;
+        }
+
+        steps.into()
+    }
+
+    fn part_two(_: &Self::Parsed) -> Answer {
+        // the last day only has one puzzle
+        Answer::Unsolved
+    }
 }
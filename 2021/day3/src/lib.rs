@@ -1,51 +1,68 @@
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
+#![allow(
+    clippy::assign_op_pattern,
+    clippy::needless_range_loop,
+    clippy::needless_return,
+    clippy::ptr_arg,
+)]
 
-type Input = Lines<BufReader<File>>;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-fn part_one(file_name: &str) {
-    let pattern_len = 12;
-    let mut counts = Vec::<i32>::with_capacity(pattern_len);
-    for _ in 0..pattern_len {
-        counts.push(0);
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
     }
 
-    let mut total = 0;
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let pattern_len = 12;
+        let mut counts = vec![0; pattern_len];
 
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .flat_map(|line| line.char_indices().collect::<Vec<_>>())
-        .for_each(|(i, c)| {
-            if c == '1' {
-                counts[i] += 1;
-            }
-            if i == 0 {
-                total += 1;
+        let mut total = 0;
+
+        lines.iter()
+            .flat_map(|line| line.char_indices().collect::<Vec<_>>())
+            .for_each(|(i, c)| {
+                if c == '1' {
+                    counts[i] += 1;
+                }
+                if i == 0 {
+                    total += 1;
+                }
+            });
+
+        let mut gamma: u64 = 0;
+        let half = total / 2;
+        let mut mask = 0;
+        for i in 0..pattern_len {
+            let num = counts[i];
+            gamma = gamma << 1;
+            if num > half {
+                gamma = gamma | 1;
             }
-        });
-    
-    let mut gamma: u64 = 0;
-    let half = total / 2;
-    let mut mask = 0;
-    for i in 0..pattern_len {
-        let num = counts[i];
-        gamma = gamma << 1;
-        if num > half {
-            gamma = gamma | 1;
+            mask = (mask << 1) | 0x1;
         }
-        mask = (mask << 1) | 0x1;
+
+        let delta = (gamma ^ mask) & mask;
+
+        (gamma * delta).into()
     }
 
-    let delta = (gamma ^ mask) & mask;
-    
-    println!("Part 1: {} * {} = {}", gamma, delta, gamma * delta);
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut root = Node::new();
+
+        lines.iter()
+            .map(|line| line.chars().collect::<Vec<char>>())
+            .for_each(|line| root.add(line, 0));
+
+        let oxygen = root.get_oxygen(0);
+        let co2 = root.get_co2(0);
+
+        (oxygen * co2).into()
+    }
 }
 
 struct Node {
@@ -68,14 +85,14 @@ impl Node {
             match line[pos] {
                 '0' => {
                     if self.zero.is_none() {
-                        self.zero = Box::new(Some(Node::new()))
+                        *self.zero = Some(Node::new())
                     }
                     let zero = &mut *self.zero;
                     zero.as_mut().unwrap().add(line, pos + 1);
                 },
                 '1' => {
                     if self.one.is_none() {
-                        self.one = Box::new(Some(Node::new()))
+                        *self.one = Some(Node::new())
                     }
                     let one = &mut *self.one;
                     one.as_mut().unwrap().add(line, pos + 1);
@@ -153,20 +170,6 @@ impl Node {
     }
 }
 
-fn part_two(file_name: &str) {
-    let mut root = Node::new();
-
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .for_each(|line| root.add(line, 0));
-    
-    let oxygen = root.get_oxygen(0);
-    let co2 = root.get_co2(0);
-
-    println!("Part 2: {} * {} = {}", oxygen, co2, oxygen * co2);
-}
-
 fn split<'a>(remaining: &Vec<&'a Vec<char>>, i: usize) -> (Vec<&'a Vec<char>>, Vec<&'a Vec<char>>) {
     let mut zeros = Vec::<&'a Vec<char>>::new();
     let mut ones = Vec::<&'a Vec<char>>::new();
@@ -190,14 +193,13 @@ fn to_number(line: &Vec<char>) -> u64 {
     })
 }
 
-fn part_two_no_structs(file_name: &str) {
-    let orig = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
+pub fn part_two_no_structs(lines: &[String]) -> u64 {
+    let orig = lines.iter()
         .map(|line| line.chars().collect::<Vec<char>>())
         .collect::<Vec<Vec<char>>>();
 
     let mut remaining = Vec::<&Vec<char>>::new();
-    orig.iter().for_each(|line| remaining.push(&line));
+    orig.iter().for_each(|line| remaining.push(line));
     
     for i in 0..12 {
         let (zeros, ones) = split(&remaining, i);
@@ -215,7 +217,7 @@ fn part_two_no_structs(file_name: &str) {
     let oxygen = to_number(remaining[0]);
     
     let mut remaining = Vec::<&Vec<char>>::new();
-    orig.iter().for_each(|line| remaining.push(&line));
+    orig.iter().for_each(|line| remaining.push(line));
 
     for i in 0..12 {
         let (zeros, ones) = split(&remaining, i);
@@ -232,13 +234,5 @@ fn part_two_no_structs(file_name: &str) {
 
     let co2 = to_number(remaining[0]);
 
-    println!("Part 2 ez: {} * {} = {}", oxygen, co2, oxygen * co2);
-}
-
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
-    part_two_no_structs("input.txt");
-
-    println!("Done!");
+    oxygen * co2
 }
@@ -1,19 +1,12 @@
+#![allow(clippy::needless_range_loop, clippy::ptr_arg, clippy::vec_init_then_push)]
+
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::ops::Range;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
-struct Line {
+pub struct Line {
     start_x: i32,
     end_x: i32,
     start_y: i32,
@@ -59,14 +52,10 @@ impl Line {
 
     fn sort_points<'a>(a_start: &'a mut  i32, b_start: &'a mut i32, a_end: &'a mut i32, b_end: &'a mut i32) {
         if a_start > a_end {
-            let swap = *a_end;
-            *a_end = *a_start;
-            *a_start = swap;
+            std::mem::swap(&mut *a_end, &mut *a_start);
         }
         if b_start > b_end {
-            let swap = *b_end;
-            *b_end = *b_start;
-            *b_start = swap;
+            std::mem::swap(&mut *b_end, &mut *b_start);
         }
     }
 
@@ -85,7 +74,7 @@ impl Line {
     fn get_parallel_diff(mut a_start: i32, mut b_start: i32, mut a_end: i32, mut b_end: i32) -> Range<i32> {
         if Line::intersects_parallel(a_start, b_start, a_end, b_end) {
             Line::sort_points(&mut a_start, &mut b_start, &mut a_end, &mut b_end);
-            let mut points = vec![a_start, b_start, a_end, b_end];
+            let mut points = [a_start, b_start, a_end, b_end];
             points.sort();
             points[1]..(points[2] + 1)
         } else {
@@ -140,32 +129,91 @@ impl Line {
 
 }
 
-fn read_lines(file_name: &str) -> Vec<Line> {
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
+fn read_vent_lines(input: &str) -> Vec<Line> {
+    lines(input).into_iter()
         .map(|line| { Line::parse(&line) })
         .collect::<Vec<Line>>()
 }
 
-fn part_one(file_name: &str) {
-    let lines = read_lines(file_name)
-        .into_iter()
-        .filter(|line| line.is_straight())
-        .collect::<Vec<Line>>();
-
-    let mut points = HashSet::new();
-
-    for i in 0..lines.len() {
-        let one = &lines[i];
-        for j in (i + 1)..lines.len() {
-            let two = &lines[j];
-            one.get_intersections(two)
-                .into_iter()
-                .for_each(|point| { points.insert(point); });
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(read_vent_lines(input))
+    }
+
+    fn part_one(vent_lines: &Self::Parsed) -> Answer {
+        let lines = vent_lines.iter()
+            .filter(|line| line.is_straight())
+            .collect::<Vec<&Line>>();
+
+        let mut points = HashSet::new();
+
+        for i in 0..lines.len() {
+            let one = &lines[i];
+            for j in (i + 1)..lines.len() {
+                let two = &lines[j];
+                one.get_intersections(two)
+                    .into_iter()
+                    .for_each(|point| { points.insert(point); });
+            }
         }
+
+        points.len().into()
     }
 
-    println!("Part 1: {}", points.len());
+    fn part_two(vent_lines: &Self::Parsed) -> Answer {
+
+        let mut point_counts = HashMap::<(i32,i32), i32>::new();
+
+        // #EZmode
+        for line in vent_lines {
+            let mut start_x = line.start_x;
+            let mut end_x = line.end_x;
+            let mut start_y = line.start_y;
+            let mut end_y = line.end_y;
+
+            if line.is_horizontal() {
+                Line::sort_points(&mut start_x, &mut start_y, &mut end_x, &mut end_y);
+                let points = (start_x..(end_x + 1))
+                    .map(|x| (x, start_y))
+                    .collect::<Vec<_>>();
+                add_points(points, &mut point_counts);
+            } else if line.is_vertical() {
+                Line::sort_points(&mut start_x, &mut start_y, &mut end_x, &mut end_y);
+                let points = (start_y..(end_y + 1))
+                    .map(|y| (start_x, y))
+                    .collect::<Vec<_>>();
+                add_points(points, &mut point_counts);
+            } else {
+                let mut x_increment = 1;
+                let mut y_increment = 1;
+                let mut count = end_x - start_x;
+
+                if start_x > end_x {
+                    x_increment = -1;
+                    count = start_x - end_x;
+                }
+                if start_y > end_y {
+                    y_increment = -1;
+                }
+
+                let count = count + 1;
+
+                let points = (0..count)
+                    .map(|i| (start_x + (i * x_increment), start_y + (i * y_increment)))
+                    .collect::<Vec<_>>();
+                add_points(points, &mut point_counts);
+            }
+        }
+
+        point_counts.iter()
+            .filter(|(_, count)| **count > 1)
+            .count()
+            .into()
+    }
 }
 
 fn add_points(points: Vec<(i32, i32)>, point_counts: &mut HashMap<(i32, i32), i32>) {
@@ -177,64 +225,3 @@ fn add_points(points: Vec<(i32, i32)>, point_counts: &mut HashMap<(i32, i32), i3
         }
     });
 }
-
-fn part_two(file_name: &str) {
-    let lines = read_lines(file_name);
-
-    let mut point_counts = HashMap::<(i32,i32), i32>::new();
-
-    // #EZmode
-    for line in lines {
-        let mut start_x = line.start_x;
-        let mut end_x = line.end_x;
-        let mut start_y = line.start_y;
-        let mut end_y = line.end_y;
-
-        if line.is_horizontal() {
-            Line::sort_points(&mut start_x, &mut start_y, &mut end_x, &mut end_y);
-            let points = (start_x..(end_x + 1))
-                .map(|x| (x, start_y))
-                .collect::<Vec<_>>();
-            add_points(points, &mut point_counts);
-        } else if line.is_vertical() {
-            Line::sort_points(&mut start_x, &mut start_y, &mut end_x, &mut end_y);
-            let points = (start_y..(end_y + 1))
-                .map(|y| (start_x, y))
-                .collect::<Vec<_>>();
-            add_points(points, &mut point_counts);
-        } else {
-            let mut x_increment = 1;
-            let mut y_increment = 1;
-            let mut count = end_x - start_x;
-
-            if start_x > end_x {
-                x_increment = -1;
-                count = start_x - end_x;
-            }
-            if start_y > end_y {
-                y_increment = -1;
-            }
-
-            let count = count + 1;
-
-            let points = (0..count)
-                .map(|i| (start_x + (i * x_increment), start_y + (i * y_increment)))
-                .collect::<Vec<_>>();
-            add_points(points, &mut point_counts);
-        }
-    }
-
-    let count = point_counts.iter()
-        .filter(|(_, count)| **count > 1)
-        .count();
-    
-    println!("Part 2: {}", count);
-}
-
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
-    // part_two("sample.txt");
-
-    println!("Done!");
-}
@@ -0,0 +1,50 @@
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+fn read_initial_fishes(input: &str) -> Vec<u64> {
+    let line = lines(input).into_iter()
+        .next()
+        .unwrap();
+    
+    let mut fishes = vec![0; 9];
+    
+    line.split(",")
+        .flat_map(|number_str| number_str.parse::<usize>())
+        .for_each(|number| fishes[number] += 1);
+
+    fishes
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(read_initial_fishes(input))
+    }
+
+    fn part_one(fishes: &Self::Parsed) -> Answer {
+        let mut fishes = fishes.clone();
+
+        for _ in 0..80 {
+            let new_fishes = fishes[0];
+            fishes.rotate_left(1);
+            fishes[6] += new_fishes;
+        }
+
+        fishes.iter().sum::<u64>().into()
+    }
+
+    fn part_two(fishes: &Self::Parsed) -> Answer {
+        let mut fishes = fishes.clone();
+
+        for _ in 0..256 {
+            let new_fishes = fishes[0];
+            fishes.rotate_left(1);
+            fishes[6] += new_fishes;
+        }
+
+        fishes.iter().sum::<u64>().into()
+    }
+}
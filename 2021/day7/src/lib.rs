@@ -0,0 +1,77 @@
+#![allow(clippy::needless_range_loop)]
+
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+fn read_positions(input: &str) -> Vec<i64> {
+    let numbers = lines(input).into_iter()
+        .next()
+        .unwrap()
+        .split(",")
+        .flat_map(|number| number.parse::<i64>())
+        .collect::<Vec<_>>();
+    
+    let mut positions = vec![0; (*numbers.iter().max().unwrap() + 1) as usize];
+
+    for number in numbers {
+        positions[number as usize] += 1;
+    }
+        
+    positions
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(read_positions(input))
+    }
+
+    fn part_one(positions: &Self::Parsed) -> Answer {
+        let max = positions.len();
+
+        let mut min_cost = 1000000;
+        for i in 0..max {
+            let mut cost: i64 = 0;
+            let target = i as i64;
+            for l in 0..i {
+                cost += (target - (l as i64)) * positions[l];
+            }
+            for u in (i+1)..max {
+                cost += ((u as i64) - target) * positions[u];
+            }
+            if cost < min_cost {
+                min_cost = cost;
+            }
+        }
+
+        min_cost.into()
+    }
+
+    fn part_two(positions: &Self::Parsed) -> Answer {
+        let max = positions.len();
+
+        let mut min_cost = 1000000000;
+        for i in 0..max {
+            let mut cost: i64 = 0;
+            let target = i as i64;
+            for l in 0..i {
+                let distance = target - (l as i64);
+                let single_cost = (distance * (distance + 1)) / 2;
+                cost += single_cost * positions[l];
+            }
+            for u in (i+1)..max {
+                let distance = (u as i64) - target;
+                let single_cost = (distance * (distance + 1)) / 2;
+                cost += single_cost * positions[u];
+            }
+            if cost < min_cost {
+                min_cost = cost;
+            }
+        }
+
+        min_cost.into()
+    }
+}
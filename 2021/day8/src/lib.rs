@@ -1,16 +1,9 @@
+#![allow(clippy::needless_range_loop, clippy::ptr_arg, clippy::redundant_closure)]
+
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 #[derive(Debug)]
 struct Pattern {
@@ -143,11 +136,10 @@ impl Test {
         let u2 = five_uniques[1].1;
 
         let three = len_five.iter()
-            .filter(|pattern| {
+            .find(|pattern| {
                 !pattern.chars.contains(&u1) && 
                 !pattern.chars.contains(&u2)
             })
-            .next()
             .unwrap();
 
         let mut len_five = len_five.clone();
@@ -160,8 +152,7 @@ impl Test {
         };
 
         let d = four.chars.iter()
-            .filter(|c| !one.chars.contains(c) && **c != b)
-            .map(|c| *c)
+            .filter(|c| !one.chars.contains(c) && **c != b).copied()
             .next().unwrap();
         
         let len_six = by_length.get(&6).unwrap();
@@ -202,39 +193,38 @@ impl Test {
     }
 }
 
-fn part_one(file_name: &str) {
-    let mut counts = vec![0; 9];
+pub struct Day;
 
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .flat_map(|line| Test::parse(line).output)
-        .map(|pattern| pattern.len())
-        .for_each(|len| {
-            counts[len] += 1;
-        });
-    
-    let total = counts[2] + 
-        counts[3] + 
-        counts[4] + 
-        counts[7];
-    
-    println!("Part 1: {} + {} + {} + {} = {}", counts[2], counts[3], counts[4], counts[7], total);
-}
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-fn part_two(file_name: &str) {
-    let total = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| Test::parse(line))
-        .map(|test| test.determine_output())
-        .sum::<i32>();
-    
-    println!("Part 2: {}", total);
-}
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let mut counts = [0; 9];
+
+        lines.iter()
+            .flat_map(|line| Test::parse(line.clone()).output)
+            .map(|pattern| pattern.len())
+            .for_each(|len| {
+                counts[len] += 1;
+            });
+
+        let total = counts[2] + 
+            counts[3] + 
+            counts[4] + 
+            counts[7];
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
-    part_two("sample.txt");
+        total.into()
+    }
 
-    println!("Done!");
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        lines.iter()
+            .map(|line| Test::parse(line.clone()))
+            .map(|test| test.determine_output())
+            .sum::<i32>()
+            .into()
+    }
 }
@@ -0,0 +1,73 @@
+use std::collections::HashSet;
+use aoc_common::grid::{Grid, Point};
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Heightmap {
+    heights: Grid<u32>
+}
+
+impl Heightmap {
+    fn parse(input: &str) -> Result<Heightmap> {
+        Ok(Heightmap {
+            heights: Grid::parse(input, |c| c.to_digit(10))?
+        })
+    }
+
+    fn is_low_point(&self, point: Point) -> bool {
+        let current = self.heights[point];
+        self.heights.neighbours(point)
+            .all(|neighbour| self.heights[neighbour] > current)
+    }
+
+    fn low_points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.heights.points()
+            .filter(move |point| self.is_low_point(*point))
+    }
+
+    fn calc_basin_size(&self, point: Point) -> u32 {
+        let mut points = HashSet::new();
+        self.calc_basin_size_recursive(point, &mut points);
+        points.len() as u32
+    }
+
+    fn calc_basin_size_recursive(&self, point: Point, points: &mut HashSet<Point>) {
+        let current = self.heights[point];
+        if current != 9 && points.insert(point) {
+            for neighbour in self.heights.neighbours(point) {
+                if self.heights[neighbour] > current {
+                    self.calc_basin_size_recursive(neighbour, points);
+                }
+            }
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Heightmap;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Heightmap::parse(input)
+    }
+
+    fn part_one(heightmap: &Self::Parsed) -> Answer {
+        heightmap.low_points()
+            .map(|point| 1 + heightmap.heights[point])
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_two(heightmap: &Self::Parsed) -> Answer {
+        let mut basins = heightmap.low_points()
+            .map(|point| heightmap.calc_basin_size(point))
+            .collect::<Vec<_>>();
+
+        basins.sort();
+
+        basins.iter()
+            .skip(basins.len() - 3)
+            .product::<u32>()
+            .into()
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::BTreeSet;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let lines = lines.iter();
+
+        let mut total = 0;
+        let mut max = 0;
+
+        for line in lines {
+            if line.is_empty() {
+                max = total.max(max);
+                total = 0;
+            } else {
+                total += line.parse::<u32>().unwrap();
+            }
+        }
+
+        if total > 0 {
+            max = total.max(max);
+        }
+
+        max.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let lines = lines.iter();
+
+        let mut all = BTreeSet::<u32>::new();
+        let mut total: u32 = 0;
+
+        for line in lines {
+            if line.is_empty() {
+                all.insert(total);
+                total = 0;
+            } else {
+                total += line.parse::<u32>().unwrap();
+            }
+        }
+
+        if total > 0 {
+            all.insert(total);
+        }
+
+        let mut end = all.iter().rev();
+        let result = end.next().unwrap() + end.next().unwrap() + end.next().unwrap();
+
+        result.into()
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::LinkedList;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+struct Display {
+    cycle: i64,
+    register: i64,
+    add_pipeline: LinkedList<i64>
+}
+
+impl Display {
+    fn new() -> Display {
+        Display {
+            cycle: 1,
+            register: 1,
+            add_pipeline: LinkedList::new()
+        }
+    }
+
+    fn cycle(&mut self) -> bool {
+        if let Some(amount) = self.add_pipeline.pop_front() {
+            self.register += amount;
+            self.cycle += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn process(&mut self, command: &str) {
+        self.add_pipeline.push_back(0);
+        match &command[0..4] {
+            "addx" => {
+                let amount = command[5..].parse::<i64>().unwrap();
+                self.add_pipeline.push_back(amount);
+            },
+            "noop" => (),
+            _ => panic!("Invalid operation: {}", command)
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let lines = lines.iter();
+
+        let mut display = Display::new();
+        let mut strength_sum = 0;
+        let mut next_report = 20;
+
+        for command in lines {
+            display.process(command);
+
+            while display.cycle() {
+                if display.cycle == next_report {
+                    strength_sum += display.cycle * display.register;
+                    next_report += 40;
+                    if next_report > 220 {
+                        break;
+                    }
+                }
+            }
+        }
+
+        strength_sum.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let lines = lines.iter();
+
+        let mut display = Display::new();
+
+        let mut screen = String::from("#");
+
+        for command in lines {
+            display.process(command);
+
+            while display.cycle() {
+                let sprite = display.register;
+                let position = (display.cycle - 1) % 40;
+                if position >= sprite - 1 && position <= sprite + 1 {
+                    screen.push('#');
+                } else {
+                    screen.push('.');
+                }
+                if position == 39 {
+                    screen.push('\n');
+                }
+            }
+        }
+
+        screen.trim_end().into()
+    }
+}
@@ -1,15 +1,8 @@
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-use std::slice::Iter;
-
-type Input = Lines<BufReader<File>>;
+#![allow(clippy::ptr_arg)]
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use std::slice::Iter;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 struct Monkey {
     items: Vec<u64>,
@@ -96,10 +89,10 @@ struct Monkeys {
 }
 
 impl Monkeys {
-    fn from_lines(file_name: &str, div_amount: u64) -> Monkeys {
-        let lines = get_file_lines(file_name)
-            .flat_map(|line| line.ok())
+    fn from_lines(lines: &[String], div_amount: u64) -> Monkeys {
+        let lines = lines.iter()
             .filter(|line| !line.is_empty())
+            .cloned()
             .collect::<Vec<_>>();
         let mut lines = lines.iter();
 
@@ -132,47 +125,50 @@ impl Monkeys {
     }
 }
 
-fn part_one(file_name: &str) {
-    let mut monkeys = Monkeys::from_lines(file_name, 3);
+pub struct Day;
 
-    for _ in 0..20 {
-        monkeys.play_round();
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
     }
 
-    let mut inspections = monkeys.monkeys.iter()
-        .map(|monkey| monkey.inspections)
-        .collect::<Vec<_>>();
-    inspections.sort();
-    inspections.reverse();
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let mut monkeys = Monkeys::from_lines(lines, 3);
 
-    let top_product = inspections[0] * inspections[1];
-    
-    println!("Part 1: {}", top_product);
-}
+        for _ in 0..20 {
+            monkeys.play_round();
+        }
 
-fn part_two(file_name: &str) {
-    let mut monkeys = Monkeys::from_lines(file_name, 1);
+        let mut inspections = monkeys.monkeys.iter()
+            .map(|monkey| monkey.inspections)
+            .collect::<Vec<_>>();
+        inspections.sort();
+        inspections.reverse();
 
-    monkeys.compute_mod_amount();
+        let top_product = inspections[0] * inspections[1];
 
-    for _ in 0..10000 {
-        monkeys.play_round();
+        top_product.into()
     }
 
-    let mut inspections = monkeys.monkeys.iter()
-        .map(|monkey| monkey.inspections)
-        .collect::<Vec<_>>();
-    inspections.sort();
-    inspections.reverse();
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut monkeys = Monkeys::from_lines(lines, 1);
 
-    let top_product = inspections[0] * inspections[1];
-    
-    println!("Part 2: {}", top_product);
-}
+        monkeys.compute_mod_amount();
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+        for _ in 0..10000 {
+            monkeys.play_round();
+        }
 
-    println!("Done!");
+        let mut inspections = monkeys.monkeys.iter()
+            .map(|monkey| monkey.inspections)
+            .collect::<Vec<_>>();
+        inspections.sort();
+        inspections.reverse();
+
+        let top_product = inspections[0] * inspections[1];
+
+        top_product.into()
+    }
 }
@@ -0,0 +1,79 @@
+use aoc_common::grid::{Grid, Point};
+use aoc_common::search::{bfs, Neighbours};
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Map {
+    elevations: Grid<u32>,
+    start: Point,
+    end: Point
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Map> {
+        let mut start = (0, 0);
+        let mut end = (0, 0);
+
+        let letters = Grid::chars(input)?;
+        let elevations = letters.map(|c| match c {
+            'S' => 0,
+            'E' => ('z' as u32 - 'a' as u32) + 1,
+            _ => *c as u32 - 'a' as u32
+        });
+
+        for (point, c) in letters.iter() {
+            match c {
+                'S' => start = point,
+                'E' => end = point,
+                _ => ()
+            }
+        }
+
+        Ok(Map {
+            elevations,
+            start,
+            end
+        })
+    }
+
+    fn find_steps_to_end<I>(&self, starts: I) -> Option<u64> where I: IntoIterator<Item = Point> {
+        bfs(self, starts, |point| *point == self.end)
+            .map(|path| path.cost)
+    }
+}
+
+impl Neighbours for Map {
+    type State = Point;
+
+    fn neighbours(&self, from: &Point) -> Vec<(Point, u64)> {
+        let from_elevation = self.elevations[*from];
+        self.elevations.neighbours(*from)
+            .filter(|point| self.elevations[*point] <= from_elevation + 1)
+            .map(|point| (point, 1))
+            .collect()
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Map::parse(input)
+    }
+
+    fn part_one(map: &Self::Parsed) -> Answer {
+        let steps = map.find_steps_to_end([map.start]).unwrap();
+
+        steps.into()
+    }
+
+    fn part_two(map: &Self::Parsed) -> Answer {
+        // searching from every lowest point at once finds the closest
+        let starts = map.elevations.iter()
+            .filter(|(_, elevation)| **elevation == 0)
+            .map(|(point, _)| point);
+
+        map.find_steps_to_end(starts).unwrap().into()
+    }
+}
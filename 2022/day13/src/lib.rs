@@ -1,16 +1,16 @@
-use std::cmp::Ordering;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
+#![allow(
+    clippy::len_zero,
+    clippy::needless_range_loop,
+    clippy::needless_return,
+    clippy::ptr_arg,
+    clippy::upper_case_acronyms,
+)]
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use std::cmp::Ordering;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
+#[derive(Clone)]
 enum Packet {
     NUMBER(u32),
     LIST(Vec<Packet>)
@@ -34,7 +34,7 @@ impl Packet {
         let mut current_list = Vec::new();
         let mut current_number = String::new();
 
-        while let Some(c) = line.next() {
+        for c in line {
             match c {
                 ']' => {
                     if current_number.len() > 0 {
@@ -129,7 +129,8 @@ impl Packet {
 
 }
 
-struct Pair {
+#[derive(Clone)]
+pub struct Pair {
     left: Packet,
     right: Packet,
     index: usize
@@ -152,9 +153,8 @@ impl Pair {
     }
 }
 
-fn read_pairs(file_name: &str) -> Vec<Pair> {
-    let lines = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
+fn read_pairs(input: &str) -> Vec<Pair> {
+    let lines = lines(input).into_iter()
         .filter(|line| !line.is_empty())
         .collect::<Vec<_>>();
     let mut lines = lines.iter();
@@ -167,52 +167,52 @@ fn read_pairs(file_name: &str) -> Vec<Pair> {
     pairs
 }
 
-fn part_one(file_name: &str) {
-    let pairs = read_pairs(file_name);
-
-    let total: usize = pairs.iter()
-        .filter(|pair| pair.are_packets_in_order())
-        .map(|pair| pair.index)
-        .sum();
+pub struct Day;
 
-    println!("Part 1: {}", total);
-}
+impl Solution for Day {
+    type Parsed = Vec<Pair>;
 
-fn part_two(file_name: &str) {
-
-    let mut all_packets = Vec::new();
-    all_packets.push(Packet::LIST(vec![Packet::LIST(vec![Packet::NUMBER(2)])]));
-    all_packets.push(Packet::LIST(vec![Packet::LIST(vec![Packet::NUMBER(6)])]));
-
-    let pairs = read_pairs(file_name);
-    pairs.into_iter().for_each(|pair| {
-        all_packets.push(pair.left);
-        all_packets.push(pair.right);
-    });
-
-    all_packets.sort_by(|a, b| match a.get_status(b) {
-        Status::Ordered => Ordering::Less,
-        Status::Unknown => Ordering::Equal,
-        Status::Unordered => Ordering::Greater
-    });
-
-    let mut key = 1;
-    let mut check = String::new();
-    for i in 0..all_packets.len() {
-        all_packets[i].to_string(&mut check);
-        match check.as_str() {
-            "[[2]]" | "[[6]]" => key *= i + 1,
-            _ => ()
-        };
-        check.clear();
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(read_pairs(input))
     }
 
-    println!("Part 2: {}", key);
-}
+    fn part_one(pairs: &Self::Parsed) -> Answer {
+        let total: usize = pairs.iter()
+            .filter(|pair| pair.are_packets_in_order())
+            .map(|pair| pair.index)
+            .sum();
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+        total.into()
+    }
 
-    println!("Done!");
+    fn part_two(pairs: &Self::Parsed) -> Answer {
+
+        let mut all_packets = Vec::new();
+        all_packets.push(Packet::LIST(vec![Packet::LIST(vec![Packet::NUMBER(2)])]));
+        all_packets.push(Packet::LIST(vec![Packet::LIST(vec![Packet::NUMBER(6)])]));
+
+        pairs.iter().cloned().for_each(|pair| {
+            all_packets.push(pair.left);
+            all_packets.push(pair.right);
+        });
+
+        all_packets.sort_by(|a, b| match a.get_status(b) {
+            Status::Ordered => Ordering::Less,
+            Status::Unknown => Ordering::Equal,
+            Status::Unordered => Ordering::Greater
+        });
+
+        let mut key = 1;
+        let mut check = String::new();
+        for i in 0..all_packets.len() {
+            all_packets[i].to_string(&mut check);
+            match check.as_str() {
+                "[[2]]" | "[[6]]" => key *= i + 1,
+                _ => ()
+            };
+            check.clear();
+        }
+
+        key.into()
+    }
 }
@@ -1,15 +1,8 @@
-use std::collections::{BTreeMap, BTreeSet};
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
+#![allow(clippy::useless_conversion)]
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use std::collections::{BTreeMap, BTreeSet};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 #[derive(Debug)]
 struct Point {
@@ -55,16 +48,16 @@ impl Point {
     }
 }
 
-struct Rocks {
+#[derive(Clone)]
+pub struct Rocks {
     x_map: BTreeMap<i32, BTreeSet<i32>>,
     y_floor: i32
 }
 
 impl Rocks {
-    fn from_file(file_name: &str) -> Rocks {
+    fn parse(input: &str) -> Rocks {
         let mut x_map = BTreeMap::new();
-        get_file_lines(file_name)
-            .flat_map(|line| line.ok())
+        lines(input).into_iter()
             .for_each(|line| {
                 line.split(" -> ")
                     .map(Point::parse)
@@ -75,7 +68,7 @@ impl Rocks {
             });
         
         let y_floor = *x_map.values()
-            .flat_map(|y_set| y_set)
+            .flatten()
             .max()
             .unwrap() + 2;
 
@@ -187,25 +180,28 @@ impl Rocks {
     }
 }
 
-fn part_one(file_name: &str) {
-    let mut rocks = Rocks::from_file(file_name);
+pub struct Day;
 
-    let added = rocks.fill_with_sand();
-    
-    println!("Part 1: {}", added);
-}
+impl Solution for Day {
+    type Parsed = Rocks;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Rocks::parse(input))
+    }
 
-fn part_two(file_name: &str) {
-    let mut rocks = Rocks::from_file(file_name);
+    fn part_one(rocks: &Self::Parsed) -> Answer {
+        let mut rocks = rocks.clone();
 
-    let added = rocks.fill_with_sand_with_floor();
-    
-    println!("Part 2: {}", added);
-}
+        let added = rocks.fill_with_sand();
+
+        added.into()
+    }
+
+    fn part_two(rocks: &Self::Parsed) -> Answer {
+        let mut rocks = rocks.clone();
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+        let added = rocks.fill_with_sand_with_floor();
 
-    println!("Done!");
+        added.into()
+    }
 }
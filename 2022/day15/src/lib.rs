@@ -0,0 +1,177 @@
+use std::collections::{BTreeSet, BTreeMap};
+use aoc_common::input::lines;
+use aoc_common::interval::{Interval, IntervalSet};
+use aoc_common::{Answer, Result, Solution};
+
+#[derive(Debug, Clone)]
+struct Point {
+    x: i32,
+    y: i32
+}
+
+impl Point {
+    fn coords(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+    fn distance(&self, other: &Self) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+pub struct ObjectMap {
+    objects_by_y: BTreeMap<i32, BTreeSet<i32>>,
+    measurements: Vec<Measurement>
+}
+
+#[derive(Clone)]
+struct Measurement {
+    sensor: Point,
+    distance: i32
+}
+
+impl Measurement {
+    fn new(sensor: Point, beacon: Point) -> Measurement {
+        let distance = sensor.distance(&beacon);
+        Measurement { sensor, distance }
+    }
+
+    /// The stretch of row `line_y` that's no further from the sensor than
+    /// its beacon, and so can't have another beacon in it.
+    fn empty_space_at(&self, line_y: i32) -> Option<Interval<i32>> {
+        let reach = self.distance - (self.sensor.y - line_y).abs();
+        match reach >= 0 {
+            true => Some(Interval::new(self.sensor.x - reach, self.sensor.x + reach)),
+            false => None
+        }
+    }
+}
+
+fn parse_coord(str: &str) -> i32 {
+    str[2..str.len() - 1].parse().unwrap()
+}
+
+impl ObjectMap {
+    fn parse(input: &str) -> ObjectMap {
+        let mut map = ObjectMap::new();
+
+        lines(input).into_iter()
+            .for_each(|line| map.parse_and_add(line));
+
+        map
+    }
+
+    fn new() -> ObjectMap {
+        ObjectMap {
+            objects_by_y: BTreeMap::new(),
+            measurements: Vec::new()
+        }
+    }
+
+    fn add_object(first: i32, second: i32, objects: &mut BTreeMap<i32, BTreeSet<i32>>) {
+        objects.entry(first)
+            .or_default()
+            .insert(second);
+    }
+
+    fn parse_and_add(&mut self, line: String) {
+        let pieces = line.split(" ").collect::<Vec<_>>();
+
+        let sensor_x = parse_coord(pieces[2]);
+        let sensor_y = parse_coord(pieces[3]);
+        let sensor = Point::coords(sensor_x, sensor_y);
+
+        let beacon_x = parse_coord(pieces[8]);
+        let beacon_y: i32 = pieces[9][2..].parse().unwrap();
+        let beacon = Point::coords(beacon_x, beacon_y);
+
+        Self::add_object(sensor_y, sensor_x, &mut self.objects_by_y);
+        Self::add_object(beacon_y, beacon_x, &mut self.objects_by_y);
+
+        self.measurements.push(Measurement::new(sensor, beacon));
+    }
+
+    fn determine_shadow_lines(&self, line_y: i32) -> IntervalSet<i32> {
+        self.measurements.iter()
+            .flat_map(|measurement| measurement.empty_space_at(line_y))
+            .collect()
+    }
+
+    fn sum_empty_space_at(&self, line_y: i32) -> usize {
+        let shadow_lines = self.determine_shadow_lines(line_y);
+
+        // the sensors and beacons themselves aren't empty
+        let objects = self.objects_by_y.get(&line_y)
+            .map_or(0, |set| set.iter().filter(|x| shadow_lines.contains(**x)).count());
+
+        shadow_lines.size() as usize - objects
+    }
+
+    /// The tuning frequency of the only spot within `0..=limit` on both
+    /// axes that no sensor rules out.
+    fn find_empty_space_frequency(&self, limit: i32) -> u64 {
+        let area = IntervalSet::from(Interval::new(0, limit));
+
+        for y in 0..limit + 1 {
+            let empty = area.difference(&self.determine_shadow_lines(y));
+            let gap = empty.iter().next().copied();
+            if let Some(gap) = gap {
+                let x = gap.start() as u64;
+                return (x * 4000000_u64) + y as u64;
+            }
+        }
+
+        panic!("No empty space within 0..={}", limit);
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = ObjectMap;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(ObjectMap::parse(input))
+    }
+
+    fn part_one(map: &Self::Parsed) -> Answer {
+        let empty = map.sum_empty_space_at(2000000);
+
+        empty.into()
+    }
+
+    fn part_two(map: &Self::Parsed) -> Answer {
+        let freq = map.find_empty_space_frequency(4000000);
+
+        freq.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+";
+
+    #[test]
+    fn sample_asks_about_a_smaller_area() {
+        // the sample's row is 10 and its search area 0..=20
+        let map = ObjectMap::parse(SAMPLE);
+        assert_eq!(26, map.sum_empty_space_at(10));
+        assert_eq!(56000011, map.find_empty_space_frequency(20));
+    }
+}
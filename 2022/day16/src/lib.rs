@@ -1,15 +1,13 @@
-use std::collections::{HashMap, HashSet, BTreeMap};
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
+#![allow(
+    clippy::len_zero,
+    clippy::needless_range_loop,
+    clippy::redundant_closure,
+    clippy::too_many_arguments,
+)]
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use std::collections::{HashMap, HashSet, BTreeMap};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 struct IdSource {
     ids: HashMap<String, u64>,
@@ -42,7 +40,7 @@ struct Valve {
 }
 
 impl Valve {
-    fn parse(line: String, ids: &mut IdSource) -> Valve {
+    fn parse(line: &str, ids: &mut IdSource) -> Valve {
         let pieces = line.split(" ").collect::<Vec<_>>();
 
         let id = ids.get(pieces[1]);
@@ -84,7 +82,7 @@ impl Network {
         }
     }
 
-    fn add_valve(&mut self, line: String) {
+    fn add_valve(&mut self, line: &str) {
         let valve = Valve::parse(line, &mut self.ids);
         self.valves.insert(valve.id, valve);
     }
@@ -92,9 +90,7 @@ impl Network {
     fn add_valve_distance(&mut self, from: u64, to: u64, distance: u32) {
         self.distances.insert((from, to), distance);
 
-        if !self.valve_by_distance.contains_key(&from) {
-            self.valve_by_distance.insert(from, BTreeMap::new());
-        }
+        self.valve_by_distance.entry(from).or_insert_with(|| BTreeMap::new());
         
         let by_distance = self.valve_by_distance.get_mut(&from).unwrap();
 
@@ -179,7 +175,7 @@ impl Network {
             if distance + 1 >= 30 {
                 continue;
             }
-            let rate = self.valves.get(&to).unwrap().rate;
+            let rate = self.valves.get(to).unwrap().rate;
             let this_max = self.max_pressure_from(*to, start, distance, rate, minutes, other_minutes, 0, max, 0);
             max = max.max(this_max);
         }
@@ -246,37 +242,38 @@ impl Network {
 
 }
 
-fn part_one(file_name: &str) {
-    let mut network = Network::new();
+pub struct Day;
 
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .for_each(|line| network.add_valve(line));
-    
-    network.compute_distances();
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-    let max = network.determine_max_pressure(30, 0);
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
 
-    println!("Part 1: {}", max);
-}
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let mut network = Network::new();
 
-fn part_two(file_name: &str) {
-    let mut network = Network::new();
-
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .for_each(|line| network.add_valve(line));
-    
-    network.compute_distances();
-    
-    let max = network.determine_max_pressure(26, 26);
-    
-    println!("Part 2: {}", max);
-}
+        lines.iter()
+            .for_each(|line| network.add_valve(line));
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+        network.compute_distances();
 
-    println!("Done!");
+        let max = network.determine_max_pressure(30, 0);
+
+        max.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut network = Network::new();
+
+        lines.iter()
+            .for_each(|line| network.add_valve(line));
+
+        network.compute_distances();
+
+        let max = network.determine_max_pressure(26, 26);
+
+        max.into()
+    }
 }
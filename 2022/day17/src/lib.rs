@@ -0,0 +1,320 @@
+#![allow(clippy::assign_op_pattern, clippy::let_and_return, clippy::needless_range_loop)]
+
+use aoc_common::cycle::{find_cycle, Cycle};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+#[derive(Debug, Clone)]
+pub enum Jet {
+    Left,
+    Right
+}
+
+struct Shape {
+    states: Vec<Vec<u8>>,
+    height: usize
+}
+
+impl Shape {
+    fn new(left_most: Vec<u8>) -> Shape {
+        let height = left_most.len();
+        let mut states = Vec::new();
+        states.push(left_most.to_owned());
+
+        let mut current = left_most;
+        while current.iter()
+            .find(|row| *row & 1 != 0)
+            .is_none()
+        {
+            current.iter_mut().for_each(|row| *row = *row >> 1);
+            states.push(current.to_owned());
+        }
+
+        let shape = Shape {
+            states,
+            height
+        };
+        
+        shape
+    }
+
+    fn next_state(&self, jet: &Jet, current_state: usize) -> usize {
+        match jet {
+            Jet::Left => match current_state == 0 {
+                true => 0,
+                false => current_state - 1
+            },
+            Jet::Right => match current_state + 1 < self.states.len() {
+                true => current_state + 1,
+                false => current_state
+            }
+        }
+    }
+
+    fn overlaps(&self, current_state: usize, rocks: &[u8]) -> bool {
+        let state = &self.states[current_state];
+        for r in 0..rocks.len() {
+            let shape_row = state[r];
+            let rock_row = rocks[r];
+            if shape_row & rock_row > 0 {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+struct Shapes {
+    shapes: Vec<Shape>
+}
+
+impl Shapes {
+    fn new() -> Shapes {
+        // all shapes are upside down
+        let shapes = vec![
+            // horizontal line
+            Shape::new(vec![
+                0b1111000
+            ]),
+            // plus
+            Shape::new(vec![
+                0b0100000,
+                0b1110000,
+                0b0100000
+            ]),
+            // backward L
+            Shape::new(vec![
+                0b1110000,
+                0b0010000,
+                0b0010000
+            ]),
+            // vertical line
+            Shape::new(vec![
+                0b1000000,
+                0b1000000,
+                0b1000000,
+                0b1000000
+            ]),
+            // square
+            Shape::new(vec![
+                0b1100000,
+                0b1100000
+            ])
+        ];
+
+        Shapes {
+            shapes
+        }
+    }
+
+    fn get(&self, index: usize) -> &Shape {
+        &self.shapes[index % self.shapes.len()]
+    }
+}
+
+struct Rocks {
+    jets: Vec<Jet>,
+    rows: Vec<u8>,
+    jet_index: usize,
+    dropped: usize
+}
+
+/// Every column of the chamber, one bit each.
+const CHAMBER: u8 = 0b1111111;
+
+impl Rocks {
+    fn new(jets: Vec<Jet>) -> Rocks {
+        Rocks {
+            jets,
+            rows: Vec::new(),
+            jet_index: 0,
+            dropped: 0
+        }
+    }
+
+    /// Drops the next rock, returning how many rows down from the top of
+    /// the tower it had to look to find where it stopped, counting the floor
+    /// as a row.
+    fn drop_rock(&mut self, shapes: &Shapes) -> usize {
+        let shape = shapes.get(self.dropped);
+
+        // move with jet first
+        let mut y = self.rows.len() + 3;
+        let mut state = 2;
+        loop {
+            let jet = &self.jets[self.jet_index];
+            let next_state = shape.next_state(jet, state);
+            if next_state != state {
+                state = match self.overlaps(shape, y, next_state) {
+                    true => state,
+                    false => next_state
+                };
+            }
+            self.jet_index = (self.jet_index + 1) % self.jets.len();
+
+            if y == 0 || self.overlaps(shape, y - 1, state) {
+                break;
+            }
+            y -= 1;
+        }
+
+        let depth = self.rows.len() + 1 - y;
+        self.add_shape(shape, y, state);
+        self.dropped += 1;
+        depth
+    }
+
+    /// The spaces a falling rock could get into, row by row down from the top
+    /// of the tower, stopping at the first row with none or after `depth`
+    /// rows. Any other space could only be reached through solid rock, so a
+    /// rock falls the same way whether it's empty or not.
+    fn surface(&self, depth: usize) -> Vec<u8> {
+        let mut surface = Vec::new();
+        let mut open = CHAMBER;
+        for row in self.rows.iter().rev().take(depth) {
+            let empty = !row & CHAMBER;
+            open &= empty;
+            // spread sideways through any gaps
+            loop {
+                let spread = open | ((open << 1) & empty) | ((open >> 1) & empty);
+                if spread == open {
+                    break;
+                }
+                open = spread;
+            }
+            if open == 0 {
+                break;
+            }
+            surface.push(open);
+        }
+        surface
+    }
+
+    /// The next shape, the next jet and the tower's surface down to `depth`,
+    /// which decide how the rocks fall from here on for as long as none of
+    /// them needs to look any further down.
+    fn key(&self, shapes: &Shapes, depth: usize) -> (usize, usize, Vec<u8>) {
+        (self.dropped % shapes.shapes.len(), self.jet_index, self.surface(depth))
+    }
+
+    fn add_shape(&mut self, shape: &Shape, y: usize, current_state: usize) {
+        let top = y + shape.height;
+        let y_end = top.min(self.rows.len());
+        let copy_count = y_end - y;
+
+        let state = &shape.states[current_state];
+
+        for i in 0..copy_count {
+            self.rows[y + i] = self.rows[y + i] | state[i];
+        }
+        for i in copy_count..state.len() {
+            self.rows.push(state[i]);
+        }
+    }
+
+    fn overlaps(&self, shape: &Shape, y: usize, state: usize) -> bool {
+        if y >= self.rows.len() {
+            return false;
+        }
+        let top = y + shape.height;
+        let y_end = top.min(self.rows.len());
+        let rocks = &self.rows[y..y_end];
+        shape.overlaps(state, rocks)
+    }
+}
+
+fn parse_jets(line: &str) -> Vec<Jet> {
+    line.chars()
+        .map(|c| match c {
+            '<' => Jet::Left,
+            '>' => Jet::Right,
+            _ => panic!("Invalid direction: {}", c)
+        })
+        .collect::<Vec<_>>()
+}
+
+/// The height of the tower after each number of rocks up to `count`, and
+/// how far down each rock had to look.
+fn drop_rocks(jets: &[Jet], count: usize) -> (Vec<usize>, Vec<usize>) {
+    let shapes = Shapes::new();
+    let mut rocks = Rocks::new(jets.to_vec());
+
+    let mut heights = vec![0];
+    let mut depths = Vec::new();
+    for _ in 0..count {
+        depths.push(rocks.drop_rock(&shapes));
+        heights.push(rocks.rows.len());
+    }
+    (heights, depths)
+}
+
+/// Where the tower starts growing the same way over and over, with its
+/// height after each rock until it's been round once.
+fn find_repeat(jets: &[Jet]) -> (Cycle, Vec<usize>) {
+    let shapes = Shapes::new();
+    let mut depth = 16;
+    loop {
+        let cycle = find_cycle(Rocks::new(jets.to_vec()), |rocks| { rocks.drop_rock(&shapes); }, |rocks| rocks.key(&shapes, depth));
+
+        // if no rock in the cycle looked below the surface compared, the
+        // surface comes round again with every rock falling just the same,
+        // so the cycle really repeats forever
+        let (heights, depths) = drop_rocks(jets, cycle.start + cycle.length);
+        if depths[cycle.start..].iter().all(|looked| *looked <= depth) {
+            return (cycle, heights);
+        }
+        depth *= 2;
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Jet>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let pattern = lines(input).into_iter()
+            .next()
+            .unwrap();
+
+        Ok(parse_jets(&pattern))
+    }
+
+    fn part_one(jets: &Self::Parsed) -> Answer {
+        let (heights, _) = drop_rocks(jets, 2022);
+        heights[2022].into()
+    }
+
+    fn part_two(jets: &Self::Parsed) -> Answer {
+        let (cycle, heights) = find_repeat(jets);
+        cycle.extrapolate(1000000000000, |dropped| heights[dropped] as u64).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn surface_stops_at_sealed_rows() {
+        let mut rocks = Rocks::new(parse_jets("<"));
+        rocks.rows = vec![0b1111111, 0b0000001, 0b1110111, 0b0000000];
+        // the gap in the middle opens onto the whole row below, but there's
+        // no getting past the full row under that
+        assert_eq!(vec![0b1111111, 0b0001000, 0b1111110], rocks.surface(10));
+        assert_eq!(vec![0b1111111, 0b0001000], rocks.surface(2));
+    }
+
+    #[test]
+    fn extrapolates_exactly() {
+        // the ones with jets only one way never seal off the far side
+        for pattern in [">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>", "<", ">", "<<>><>"] {
+            let jets = parse_jets(pattern);
+            let (cycle, heights) = find_repeat(&jets);
+            let (expected, _) = drop_rocks(&jets, 5000);
+            for dropped in [0, 1234, 5000] {
+                assert_eq!(expected[dropped] as u64, cycle.extrapolate(dropped, |dropped| heights[dropped] as u64));
+            }
+        }
+    }
+}
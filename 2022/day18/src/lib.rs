@@ -1,19 +1,10 @@
-use std::collections::{HashSet, HashMap, BTreeSet};
-use std::fs::File;
-use std::ops::Range;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+#![allow(clippy::too_many_arguments)]
 
+use std::collections::{HashSet, HashMap};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
-fn parse_point(line: String) -> (i32, i32, i32) {
+fn parse_point(line: &str) -> (i32, i32, i32) {
     let parts = line.split(",")
         .map(|part| part.parse::<i32>().unwrap())
         .collect::<Vec<_>>();
@@ -57,19 +48,38 @@ fn get_non_touching_sides(point: &(i32, i32, i32), points: &HashSet<(i32, i32, i
     add_non_touching_side(z(point, -1), points, sides);
 }
 
-fn part_one(file_name: &str) {
-    let points = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| parse_point(line))
-        .collect::<HashSet<_>>();
-    
-    let mut sides = HashMap::new();
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = HashSet<(i32, i32, i32)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input).iter()
+            .map(|line| parse_point(line))
+            .collect())
+    }
 
-    points.iter().for_each(|point| get_non_touching_sides(point, &points, &mut sides));
-    
-    let total = sides.values().sum::<u32>();
+    fn part_one(points: &Self::Parsed) -> Answer {
+        let mut sides = HashMap::new();
 
-    println!("Part 1: {}", total);
+        points.iter().for_each(|point| get_non_touching_sides(point, points, &mut sides));
+
+        let total = sides.values().sum::<u32>();
+
+        total.into()
+    }
+
+    fn part_two(points: &Self::Parsed) -> Answer {
+        let mut sides = HashMap::new();
+
+        points.iter().for_each(|point| get_non_touching_sides(point, points, &mut sides));
+
+        let mut droplet = Droplet::create(points);
+
+        let total = droplet.count_exterior_points(&sides);
+
+        total.into()
+    }
 }
 
 #[derive(Debug)]
@@ -137,17 +147,13 @@ impl Droplet {
         for a in a_min..a_max + 1 {
             for b in b_min..b_max + 1 {
                 let point = point_maker(a, b, plane_min);
-                if self.types.get(&point).is_none() {
-                    self.types.insert(point, Type::Exterior);
-                }
+                self.types.entry(point).or_insert(Type::Exterior);
                 
                 let point = point_maker(a, b, plane_min - 1);
                 self.types.insert(point, Type::Exterior);
                 
                 let point = point_maker(a, b, plane_max);
-                if self.types.get(&point).is_none() {
-                    self.types.insert(point, Type::Exterior);
-                }
+                self.types.entry(point).or_insert(Type::Exterior);
                 
                 let point = point_maker(a, b, plane_max + 1);
                 self.types.insert(point, Type::Exterior);
@@ -156,10 +162,7 @@ impl Droplet {
     }
 
     fn is_exterior(&self, point: (i32, i32, i32)) -> bool {
-        match self.types.get(&point) {
-            Some(Type::Exterior) => true,
-            _ => false
-        }
+        matches!(self.types.get(&point), Some(Type::Exterior))
     }
 
     fn connect_exteriors<F>(
@@ -183,9 +186,7 @@ impl Droplet {
                         
                     if has_adjacent_exterior {
                         let point = point_maker(a, b, c);
-                        if !self.types.contains_key(&point) {
-                            self.types.insert(point, Type::Exterior);
-                        }
+                        self.types.entry(point).or_insert(Type::Exterior);
                     }                    
                 }
             }
@@ -207,9 +208,7 @@ impl Droplet {
                         
                     if has_adjacent_exterior {
                         let point = point_maker(a, b, c);
-                        if !self.types.contains_key(&point) {
-                            self.types.insert(point, Type::Exterior);
-                        }
+                        self.types.entry(point).or_insert(Type::Exterior);
                     }                    
                 }
             }
@@ -287,45 +286,3 @@ impl Droplet {
             .sum()
     }
 }
-
-fn part_two(file_name: &str) {
-    let points = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| parse_point(line))
-        .collect::<HashSet<_>>();
-        
-    let mut sides = HashMap::new();
-
-    points.iter().for_each(|point| get_non_touching_sides(point, &points, &mut sides));
-
-    let mut droplet = Droplet::create(&points);
-
-    let total = droplet.count_exterior_points(&sides);
-
-    for z in droplet.z_min - 1..droplet.z_max + 2 {
-        println!("----- Z {} -----", z);
-        for y in droplet.y_min - 1..droplet.y_max + 2 {
-            for x in droplet.x_min - 1..droplet.x_max + 2 {
-                if let Some(t) = droplet.types.get(&(x, y, z)) {
-                    let symbol = match t {
-                        Type::Rock => '#',
-                        Type::Exterior => '~'
-                    };
-                    print!("{}", symbol);
-                } else {
-                    print!(".");
-                }
-            }
-            println!("");
-        }
-    }
-    
-    println!("Part 2: {}", total);
-}
-
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
-
-    println!("Done!");
-}
@@ -1,21 +1,14 @@
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
+#![allow(clippy::len_zero)]
 
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 const ORE_BOT: usize = 0;
 const CLAY_BOT: usize = 1;
 const OBSIDIAN_BOT: usize = 2;
 const GEODE_BOT: usize = 3;
 
-struct Blueprint {
+pub struct Blueprint {
     id: i32,
     ore: i32,
     clay: i32,
@@ -99,7 +92,7 @@ enum State {
 }
 
 impl Blueprint {
-    fn parse(line: String, id: i32) -> Blueprint {
+    fn parse(line: &str, id: i32) -> Blueprint {
         let pieces = line.split(" ").collect::<Vec<_>>();
         
         let ore = pieces[6].parse::<i32>().unwrap();
@@ -214,12 +207,12 @@ impl Blueprint {
                 State::Middle => {
                     minutes -= added_minutes;
                     self.sell(&mut resources, current + 1);
-                    resources.mine(-1 * added_minutes);
+                    resources.mine(-added_minutes);
                 },
                 State::End => {
                     minutes -= added_minutes;
                     self.sell(&mut resources, 0);
-                    resources.mine(-1 * added_minutes);
+                    resources.mine(-added_minutes);
                     continue;
                 }
             }
@@ -294,40 +287,32 @@ impl Blueprint {
     }
 }
 
-fn part_one(file_name: &str) {
-    let mut id = 1;
-    let quality_total = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| {
-            let this_id = id;
-            id += 1;
-            Blueprint::parse(line, this_id)
-        })
-        .map(|blueprint| blueprint.compute_quality(24))
-        .sum::<i32>();
-    
-    println!("Part 1: {}", quality_total);
-}
+pub struct Day;
 
-fn part_two(file_name: &str) {
-    let mut id = 1;
-    let product = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| {
-            let this_id = id;
-            id += 1;
-            Blueprint::parse(line, this_id)
-        })
-        .take(3)
-        .map(|blueprint| blueprint.compute_quality(32) / blueprint.id)
-        .fold(1, |product, current| product * current);
-    
-    println!("Part 2: {}", product);
-}
+impl Solution for Day {
+    type Parsed = Vec<Blueprint>;
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input).iter()
+            .enumerate()
+            .map(|(index, line)| Blueprint::parse(line, index as i32 + 1))
+            .collect())
+    }
+
+    fn part_one(blueprints: &Self::Parsed) -> Answer {
+        let quality_total = blueprints.iter()
+            .map(|blueprint| blueprint.compute_quality(24))
+            .sum::<i32>();
+
+        quality_total.into()
+    }
+
+    fn part_two(blueprints: &Self::Parsed) -> Answer {
+        let product = blueprints.iter()
+            .take(3)
+            .map(|blueprint| blueprint.compute_quality(32) / blueprint.id)
+            .product::<i32>();
 
-    println!("Done!");
+        product.into()
+    }
 }
@@ -1,14 +1,5 @@
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 fn determine_score_pt1(left: char, right: char) -> i32 {
     // normalize the two symbols
@@ -42,7 +33,6 @@ fn determine_score_pt1(left: char, right: char) -> i32 {
     right + outcome_score
 }
 
-
 fn determine_score_pt2(left: char, right: char) -> i32 {
     // map left to index
     // A => Rock     => 0
@@ -69,29 +59,30 @@ fn determine_score_pt2(left: char, right: char) -> i32 {
     outcome_score + play_score
 }
 
-fn part_one(file_name: &str) {
-    let total = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .map(|chars| determine_score_pt1(chars[0], chars[2]))
-        .sum::<i32>();
-    
-    println!("Part 1: {}", total);
-}
+pub struct Day;
 
-fn part_two(file_name: &str) {
-    let total = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .map(|chars| determine_score_pt2(chars[0], chars[2]))
-        .sum::<i32>();
-    
-    println!("Part 2: {}", total);
-}
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let total = lines.iter()
+            .map(|line| line.chars().collect::<Vec<char>>())
+            .map(|chars| determine_score_pt1(chars[0], chars[2]))
+            .sum::<i32>();
+
+        total.into()
+    }
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let total = lines.iter()
+            .map(|line| line.chars().collect::<Vec<char>>())
+            .map(|chars| determine_score_pt2(chars[0], chars[2]))
+            .sum::<i32>();
 
-    println!("Done!");
+        total.into()
+    }
 }
@@ -1,15 +1,9 @@
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
+#![allow(clippy::assign_op_pattern)]
 
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
+#[derive(Clone)]
 struct Number {
     orig_pos: usize,
     value: i64
@@ -24,22 +18,23 @@ impl Number {
     }
 }
 
-struct Numbers {
+#[derive(Clone)]
+pub struct Numbers {
     numbers: Vec<Number>
 }
 
 impl Numbers {
-    fn from_file(file_name: &str) -> Numbers {
+    fn parse(input: &str) -> Numbers {
         let mut numbers = Vec::new();
 
-        get_file_lines(file_name)
-            .flat_map(|line| line.ok())
+        lines(input).into_iter()
             .map(|line| line.parse::<i64>().unwrap())
             .for_each(|number| numbers.push(Number::new(numbers.len(), number)));
 
         Numbers { numbers }
     }
 
+    #[cfg(test)]
     fn from_vec(values: Vec::<i64>) -> Numbers {
         let mut numbers = Vec::new();
         
@@ -66,7 +61,7 @@ impl Numbers {
         if new_pos == 0 {
             new_pos = count as i64 - 1;
         } else if new_pos < 0 {
-            new_pos = -1 * new_pos;
+            new_pos = -new_pos;
             new_pos = new_pos % (count as i64 - 1);
             new_pos = (count as i64 - 1) - new_pos;
             if new_pos == 0 {
@@ -117,6 +112,7 @@ impl Numbers {
         self.numbers[(zero_pos + 3000) % self.numbers.len()].value
     }
 
+    #[cfg(test)]
     fn get_values(&self) -> Vec<i64> {
         self.numbers.iter()
             .map(|number| number.value)
@@ -125,39 +121,41 @@ impl Numbers {
 
 }
 
-fn part_one(file_name: &str) {
-    let mut numbers = Numbers::from_file(file_name);
-    
-    numbers.decrypt();
+pub struct Day;
 
-    let sum = numbers.sum_grove_coordinates();
-    
-    println!("Part 1: {}", sum);
-}
+impl Solution for Day {
+    type Parsed = Numbers;
 
-fn part_two(file_name: &str) {
-    let mut numbers = Numbers::from_file(file_name);
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Numbers::parse(input))
+    }
+
+    fn part_one(numbers: &Self::Parsed) -> Answer {
+        let mut numbers = numbers.clone();
 
-    numbers.numbers.iter_mut().for_each(|number| {
-        number.value *= 811589153;
-    });
-    
-    for _ in 0..10 {
         numbers.decrypt();
+
+        let sum = numbers.sum_grove_coordinates();
+
+        sum.into()
     }
-    
-    let sum = numbers.sum_grove_coordinates();
-    
-    println!("Part 2: {}", sum);
-}
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn part_two(numbers: &Self::Parsed) -> Answer {
+        let mut numbers = numbers.clone();
 
-    println!("Done!");
-}
+        numbers.numbers.iter_mut().for_each(|number| {
+            number.value *= 811589153;
+        });
+
+        for _ in 0..10 {
+            numbers.decrypt();
+        }
 
+        let sum = numbers.sum_grove_coordinates();
+
+        sum.into()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -314,4 +312,4 @@ mod tests {
         numbers.decrypt_orig_index(2);
         assert_eq!(vec![1, 2, -13, 4, -5], numbers.get_values());
     }
-}
\ No newline at end of file
+}
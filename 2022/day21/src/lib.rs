@@ -1,15 +1,8 @@
-use std::collections::{HashMap, BTreeSet};
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
+#![allow(clippy::let_and_return)]
 
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use std::collections::HashMap;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 #[derive(Debug)]
 enum Operation {
@@ -130,8 +123,8 @@ impl Riddle {
             } else {
                 let left_name = monkey.left.as_ref().unwrap();
                 let right_name = monkey.right.as_ref().unwrap();
-                let left = self.eval_monkey(&left_name, answers);
-                let right = self.eval_monkey(&right_name, answers);
+                let left = self.eval_monkey(left_name, answers);
+                let right = self.eval_monkey(right_name, answers);
                 let value = match monkey.operation {
                     Operation::Add => left + right,
                     Operation::Subtract => left - right,
@@ -146,7 +139,7 @@ impl Riddle {
         }
     }
     
-    fn add(&mut self, line: String) {
+    fn add(&mut self, line: &str) {
         let name = line[0..4].to_owned();
         let operation_str = &line[6..];
 
@@ -172,35 +165,36 @@ impl Riddle {
     }
 }
 
-fn part_one(file_name: &str) {
-    let mut riddle = Riddle::new();
+pub struct Day;
 
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .for_each(|line| riddle.add(line));
-    
-    let riddle = riddle;
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-    let answer = riddle.solve();
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
 
-    println!("Part 1: {}", answer);
-}
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let mut riddle = Riddle::new();
 
-fn part_two(file_name: &str) {
-    let mut riddle = Riddle::new();
+        lines.iter()
+            .for_each(|line| riddle.add(line));
 
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .for_each(|line| riddle.add(line));
-    
-    let answer = riddle.solve_human();
-    
-    println!("Part 2: {}", answer);
-}
+        let riddle = riddle;
+
+        let answer = riddle.solve();
+
+        answer.into()
+    }
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut riddle = Riddle::new();
 
-    println!("Done!");
+        lines.iter()
+            .for_each(|line| riddle.add(line));
+
+        let answer = riddle.solve_human();
+
+        answer.into()
+    }
 }
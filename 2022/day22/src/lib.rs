@@ -0,0 +1,498 @@
+#![allow(clippy::needless_range_loop)]
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+struct Map {
+    y_to_x: Vec<(i32, i32)>,
+    x_to_y: Vec<(i32, i32)>,
+    walls: HashSet<(i32, i32)>,
+    width: i32
+}
+
+struct Vector {
+    x: i32,
+    y: i32
+}
+
+impl Vector {
+    fn new() -> Vector {
+        Vector {
+            x: 1,
+            y: 0
+        }
+    }
+
+    fn do_move(&self, x: i32, y: i32) -> (i32, i32) {
+        (self.x + x, self.y + y)
+    }
+    fn turn_left(&mut self) {
+        let swap = -self.x;
+        self.x = self.y;
+        self.y = swap;
+    }
+    
+    fn turn_right(&mut self) {
+        let swap = -self.y;
+        self.y = self.x;
+        self.x = swap;
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.x == 0
+    }
+
+    fn facing(&self) -> i32 {
+        match self.x == 0 {
+            true => match self.y == -1 {
+                true => 3,
+                false => 1
+            },
+            false => match self.x == -1 {
+                true => 2,
+                false => 0
+            }
+        }
+    }
+}
+
+impl Map {
+    fn new(first: &str) -> Map {
+        let width = first.len() as i32;
+
+        let mut map = Map {
+            width,
+            y_to_x: Vec::new(),
+            x_to_y: vec![(-1, -1); width as usize],
+            walls: HashSet::new()
+        };
+
+        map.add_line(first);
+
+        map
+    }
+
+    fn add_line(&mut self, line: &str) {
+        let line = line.chars().collect::<Vec<_>>();
+        
+        let mut left = -1;
+        for i in 0..line.len() {
+            if line[i] != ' ' {
+                left = i as i32;
+                break;
+            }
+        }
+
+        let mut right = -1;
+        for i in 0..line.len() {
+            let i = (line.len() - i) - 1;
+            if line[i] != ' ' {
+                right = i as i32;
+                break;
+            }
+        }
+
+        let y = self.y_to_x.len() as i32;
+        
+        for x in left..right + 1 {
+            if x as usize >= self.x_to_y.len() {
+                self.x_to_y.push((-1, -1));
+                self.width += 1;
+            }
+            let (min, max) = &mut self.x_to_y[x as usize];
+            if *min == -1 {
+                *min = y;
+            }
+            *max = y;
+            match line[x as usize]{
+                '#' => {
+                    self.walls.insert((x, y));
+                },
+                '.' => (),
+                _ => panic!("Unexpected character at ({}, {})", x, y)
+            }
+        }
+        
+        self.y_to_x.push((left, right));
+    }
+
+    fn follow_directions(&self, movements: Vec<Movement>) -> (i32, i32, i32) {
+        let mut x = self.y_to_x[0].0;
+        let mut y = 0;
+
+        let mut vector = Vector::new();
+
+        for movement in movements {
+            if let Movement::Walk(amount) = movement {
+                if vector.is_vertical() {
+                    let min = self.y_min(x);
+                    let max = self.y_max(x);
+                    for _ in 0..amount {
+                        let mut dest = y + vector.y;
+                        if dest > max {
+                            dest = min;
+                        } else if  dest < min {
+                            dest = max;
+                        }
+                        if self.walls.contains(&(x, dest)) {
+                            break;
+                        }
+                        y = dest;
+                    }
+                } else {
+                    let min = self.x_min(y);
+                    let max = self.x_max(y);
+                    for _ in 0..amount {
+                        let mut dest = x + vector.x;
+                        if dest > max {
+                            dest = min;
+                        } else if  dest < min {
+                            dest = max;
+                        }
+                        if self.walls.contains(&(dest, y)) {
+                            break;
+                        }
+                        x = dest;
+                    }
+                }
+            } else {
+                match movement {
+                    Movement::Left => vector.turn_left(),
+                    Movement::Right => vector.turn_right(),
+                    _ => panic!("impossible")
+                };
+            }
+        }
+
+        let facing = vector.facing();
+        (x, y, facing)
+    }
+
+    fn y_min(&self, x: i32) -> i32 {
+        self.x_to_y[x as usize].0
+    }
+    fn y_max(&self, x: i32) -> i32 {
+        self.x_to_y[x as usize].1
+    }
+    fn x_min(&self, y: i32) -> i32 {
+        self.y_to_x[y as usize].0
+    }
+    fn x_max(&self, y: i32) -> i32 {
+        self.y_to_x[y as usize].1
+    }
+
+    fn is_on_map(&self, x: i32, y: i32) -> bool {
+        y >= 0 && y < self.y_to_x.len() as i32 && x >= self.x_min(y) && x <= self.x_max(y)
+    }
+
+    fn follow_directions_cube(&self, movements: Vec<Movement>) -> (i32, i32, i32) {
+        let cube = Cube::fold(self);
+        let mut x = self.y_to_x[0].0;
+        let mut y = 0;
+
+        let mut vector = Vector::new();
+
+        for movement in movements {
+            if let Movement::Walk(amount) = movement {
+                for _ in 0..amount {
+                    let (dest_x, dest_y) = vector.do_move(x, y);
+                    let (dest_x, dest_y, dest_vector) = match self.is_on_map(dest_x, dest_y) {
+                        true => (dest_x, dest_y, Vector { x: vector.x, y: vector.y }),
+                        false => cube.wrap(x, y, &vector)
+                    };
+                    if self.walls.contains(&(dest_x, dest_y)) {
+                        break;
+                    }
+                    x = dest_x;
+                    y = dest_y;
+                    vector = dest_vector;
+                }
+            } else {
+                match movement {
+                    Movement::Left => vector.turn_left(),
+                    Movement::Right => vector.turn_right(),
+                    _ => panic!("impossible")
+                };
+            }
+        }
+
+        let facing = vector.facing();
+        (x, y, facing)
+    }
+}
+
+type Vec3 = [i32; 3];
+
+fn scale(v: Vec3, by: i32) -> Vec3 {
+    [v[0] * by, v[1] * by, v[2] * by]
+}
+
+fn add(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn dot(a: Vec3, b: Vec3) -> i32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Where a face of the net ends up once folded: the directions its map
+/// right and down point in, and the way it faces out of the cube.
+#[derive(Clone, Copy, Debug)]
+struct Frame {
+    right: Vec3,
+    down: Vec3,
+    normal: Vec3
+}
+
+impl Frame {
+    /// The frame of the face next to this one in the net, `dx` or `dy` faces
+    /// away, after folding it over the shared edge.
+    fn fold(&self, dx: i32, dy: i32) -> Frame {
+        let Frame { right, down, normal } = *self;
+        match (dx, dy) {
+            (1, 0) => Frame { right: scale(normal, -1), down, normal: right },
+            (-1, 0) => Frame { right: normal, down, normal: scale(right, -1) },
+            (0, 1) => Frame { right, down: scale(normal, -1), normal: down },
+            (0, -1) => Frame { right, down: normal, normal: scale(down, -1) },
+            _ => panic!("Not a neighbouring face: ({}, {})", dx, dy)
+        }
+    }
+}
+
+/// The map folded up into a cube, found from the net itself so it works for
+/// any layout of the faces and any face size.
+struct Cube {
+    size: i32,
+    // keyed by the face's position in the net, in faces rather than tiles
+    faces: HashMap<(i32, i32), Frame>
+}
+
+impl Cube {
+    fn fold(map: &Map) -> Cube {
+        let tiles = map.y_to_x.iter().map(|(left, right)| right - left + 1).sum::<i32>();
+        let size = (1..).find(|size| size * size * 6 >= tiles).unwrap();
+        let is_face = |(x, y): (i32, i32)| map.is_on_map(x * size, y * size);
+
+        let start = (map.y_to_x[0].0 / size, 0);
+        let mut faces = HashMap::new();
+        faces.insert(start, Frame { right: [1, 0, 0], down: [0, 1, 0], normal: [0, 0, -1] });
+        let mut queue = VecDeque::from([start]);
+
+        while let Some((x, y)) = queue.pop_front() {
+            let frame = faces[&(x, y)];
+            for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+                let next = (x + dx, y + dy);
+                if x + dx >= 0 && is_face(next) && !faces.contains_key(&next) {
+                    faces.insert(next, frame.fold(dx, dy));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        Cube { size, faces }
+    }
+
+    /// Steps off the edge of the face at (x, y) in the direction of `vector`,
+    /// returning where that lands on the neighbouring face of the cube and
+    /// which way it's then facing.
+    ///
+    /// Tiles are placed in 3D at twice their size, centred on the cube's
+    /// centre, so a tile next to an edge is one unit from it. Stepping over
+    /// the edge is then one unit out along the way it's heading and one unit
+    /// in along the face's normal.
+    fn wrap(&self, x: i32, y: i32, vector: &Vector) -> (i32, i32, Vector) {
+        let size = self.size;
+        let face = (x / size, y / size);
+        let frame = self.faces[&face];
+        let (x, y) = (x - face.0 * size, y - face.1 * size);
+
+        let heading = add(scale(frame.right, vector.x), scale(frame.down, vector.y));
+        let point = add(add(scale(frame.right, 2 * x + 1 - size), scale(frame.down, 2 * y + 1 - size)), scale(frame.normal, size));
+        let point = add(add(point, heading), scale(frame.normal, -1));
+
+        let (&(face_x, face_y), next) = self.faces.iter()
+            .find(|(_, next)| next.normal == heading)
+            .unwrap();
+        let heading = scale(frame.normal, -1);
+
+        (
+            face_x * size + (dot(point, next.right) + size - 1) / 2,
+            face_y * size + (dot(point, next.down) + size - 1) / 2,
+            Vector { x: dot(heading, next.right), y: dot(heading, next.down) }
+        )
+    }
+}
+
+#[derive(Debug)]
+enum Movement {
+    Walk(i32),
+    Left,
+    Right
+}
+
+fn parse_movements(line: &str) -> Vec<Movement> {
+    let line_str = line;
+    let line = line_str.chars().collect::<Vec<_>>();
+    let mut movements = Vec::new();
+    let mut last_start = 0;
+    for i in 0..line.len() {
+        match line[i] {
+            'L' => {
+                let amount = line_str[last_start..i].parse::<i32>().unwrap();
+                movements.push(Movement::Walk(amount));
+                movements.push(Movement::Left);
+                last_start = i + 1;
+            },
+            'R' => {
+                let amount = line_str[last_start..i].parse::<i32>().unwrap();
+                movements.push(Movement::Walk(amount));
+                movements.push(Movement::Right);
+                last_start = i + 1;
+            },
+            _ => ()
+        }
+    }
+    let last_amount = line_str[last_start..].parse::<i32>().unwrap();
+    movements.push(Movement::Walk(last_amount));
+    movements
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let mut lines = lines.iter();
+
+        let first = lines.next().unwrap();
+        let mut map = Map::new(first);
+
+        (&mut lines).take_while(|line| !line.is_empty())
+            .for_each(|line| map.add_line(line));
+
+        let map = map;
+        let movements = parse_movements(lines.next().unwrap());
+
+        let (x, y, facing) = map.follow_directions(movements);
+
+        let password = (4 * (x + 1)) + (1000 * (y + 1)) + facing;
+
+        password.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut lines = lines.iter();
+
+        let first = lines.next().unwrap();
+        let mut map = Map::new(first);
+
+        (&mut lines).take_while(|line| !line.is_empty())
+            .for_each(|line| map.add_line(line));
+
+        let map = map;
+        let movements = parse_movements(lines.next().unwrap());
+
+        let (x, y, facing) = map.follow_directions_cube(movements);
+
+        let password = (4 * (x + 1)) + (1000 * (y + 1)) + facing;
+
+        password.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // every net of a cube, one character per face
+    const NETS: [&str; 11] = [
+        "#...\n####\n#...",
+        "#...\n####\n.#..",
+        "#...\n####\n..#.",
+        "#...\n####\n...#",
+        ".#..\n####\n.#..",
+        ".#..\n####\n..#.",
+        "##..\n.###\n.#..",
+        "##..\n.###\n..#.",
+        "##..\n.###\n...#",
+        "##..\n.##.\n..##",
+        "###..\n..###"
+    ];
+
+    fn unfold(net: &str, size: usize) -> Map {
+        let lines = net.lines()
+            .flat_map(|row| {
+                let line = row.chars()
+                    .map(|c| match c {
+                        '#' => ".".repeat(size),
+                        _ => " ".repeat(size)
+                    })
+                    .collect::<String>();
+                vec![line.trim_end().to_owned(); size]
+            })
+            .collect::<Vec<_>>();
+
+        let mut map = Map::new(&lines[0]);
+        lines[1..].iter().for_each(|line| map.add_line(line));
+        map
+    }
+
+    fn edge_steps(map: &Map) -> Vec<(i32, i32, Vector)> {
+        let directions = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+        (0..map.y_to_x.len() as i32)
+            .flat_map(|y| (map.x_min(y)..=map.x_max(y)).map(move |x| (x, y)))
+            .flat_map(|(x, y)| directions.iter().map(move |(dx, dy)| (x, y, Vector { x: *dx, y: *dy })))
+            .filter(|(x, y, vector)| {
+                let (x, y) = vector.do_move(*x, *y);
+                !map.is_on_map(x, y)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn walks_off_every_edge_and_back() {
+        for net in NETS {
+            for size in [1, 3, 4] {
+                let map = unfold(net, size);
+                let cube = Cube::fold(&map);
+                assert_eq!(6, cube.faces.len(), "net {:?}", net);
+
+                let steps = edge_steps(&map);
+                // seven edges of the cube are cut open, each leaving two sides in the net
+                assert_eq!(14 * size, steps.len());
+                for (x, y, vector) in steps {
+                    let (to_x, to_y, arrived) = cube.wrap(x, y, &vector);
+                    assert!(map.is_on_map(to_x, to_y), "net {:?} size {} from ({}, {})", net, size, x, y);
+
+                    // arriving over an edge, so turning round has to go back over it
+                    let back = Vector { x: -arrived.x, y: -arrived.y };
+                    let (step_x, step_y) = back.do_move(to_x, to_y);
+                    assert!(!map.is_on_map(step_x, step_y));
+                    let (back_x, back_y, returned) = cube.wrap(to_x, to_y, &back);
+                    assert_eq!((x, y, -vector.x, -vector.y), (back_x, back_y, returned.x, returned.y),
+                        "net {:?} size {} from ({}, {})", net, size, x, y);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn folds_input_layout() {
+        let map = unfold(".##\n.#.\n##.\n#..", 50);
+        let cube = Cube::fold(&map);
+        assert_eq!(50, cube.size);
+
+        // left off the top face comes in on the left of the third row, upside down
+        let (x, y, vector) = cube.wrap(50, 10, &Vector { x: -1, y: 0 });
+        assert_eq!((0, 139, 1, 0), (x, y, vector.x, vector.y));
+        // up off the right face comes in at the bottom of the last row
+        let (x, y, vector) = cube.wrap(120, 0, &Vector { x: 0, y: -1 });
+        assert_eq!((20, 199, 0, -1), (x, y, vector.x, vector.y));
+    }
+}
@@ -1,19 +1,13 @@
-use std::collections::{HashSet, HashMap};
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
+#![allow(clippy::explicit_counter_loop, clippy::type_complexity)]
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use std::collections::{HashSet, HashMap};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
-struct Map {
+#[derive(Clone)]
+pub struct Map {
     elves: HashSet<(i32, i32)>,
-    moves: Vec<Box<dyn Fn(&Self, i32, i32) -> Option<(i32, i32)>>>,
+    moves: Vec<fn(&Self, i32, i32) -> Option<(i32, i32)>>,
     index: usize
 }
 
@@ -44,12 +38,11 @@ impl Proposals {
 }
 
 impl Map {
-    fn from_file(file_name: &str) -> Map {
+    fn parse(input: &str) -> Map {
         let mut elves = HashSet::new();
 
         let mut y = 0;
-        get_file_lines(file_name)
-            .flat_map(|line| line.ok())
+        lines(input).into_iter()
             .for_each(|line| {
                 let mut x = 0;
                 for c in line.chars() {
@@ -64,10 +57,10 @@ impl Map {
         Map {
             elves,
             moves: vec![
-                Box::new(Map::open_up),
-                Box::new(Map::open_down),
-                Box::new(Map::open_left),
-                Box::new(Map::open_right)
+                Map::open_up,
+                Map::open_down,
+                Map::open_left,
+                Map::open_right
             ],
             index: 0
         }
@@ -141,7 +134,7 @@ impl Map {
             }
             
             for i in self.index..self.index + 4 {
-                if let Some(dest) = self.moves[i % 4](&self, x, y) {
+                if let Some(dest) = self.moves[i % 4](self, x, y) {
                     proposals.add(dest, (x, y));
                     break;
                 }
@@ -193,32 +186,35 @@ impl Map {
     }
 }
 
-fn part_one(file_name: &str) {
-    let mut map = Map::from_file(file_name);
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Map;
 
-    for _ in 0..10 {
-        map.next_move();
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Map::parse(input))
     }
 
-    let empty = map.calc_empty_tiles();
+    fn part_one(map: &Self::Parsed) -> Answer {
+        let mut map = map.clone();
 
-    println!("Part 1: {}", empty);
-}
+        for _ in 0..10 {
+            map.next_move();
+        }
 
-fn part_two(file_name: &str) {
-    let mut map = Map::from_file(file_name);
+        let empty = map.calc_empty_tiles();
 
-    let mut moves = 1;
-    while map.next_move() > 0 {
-        moves += 1;
+        empty.into()
     }
-    
-    println!("Part 2: {}", moves);
-}
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn part_two(map: &Self::Parsed) -> Answer {
+        let mut map = map.clone();
 
-    println!("Done!");
+        let mut moves = 1;
+        while map.next_move() > 0 {
+            moves += 1;
+        }
+
+        moves.into()
+    }
 }
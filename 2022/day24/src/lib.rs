@@ -0,0 +1,208 @@
+#![allow(clippy::assign_op_pattern, clippy::needless_range_loop)]
+
+use aoc_common::input::lines;
+use aoc_common::search::{bfs, Neighbours};
+use aoc_common::{Answer, Result, Solution};
+
+#[derive(Clone)]
+struct Horizontal {
+    grid: Vec<Vec<u128>>,
+    width: usize,
+}
+
+impl Horizontal {
+    fn new(grid: Vec<Vec<u128>>, width: usize) -> Horizontal {
+        Horizontal { grid, width }
+    }
+    fn fill_left(&mut self, x: usize, y: usize) {
+        let row = &mut self.grid[y];
+        let mut indicator = 1 << (self.width - 1);
+
+        row[x] = row[x] | 1;
+        for x in x + 1..x + self.width {
+            let x = x % self.width;
+            row[x] = row[x] | indicator;
+            indicator = indicator >> 1;
+        }
+    }
+    fn fill_right(&mut self, x: usize, y: usize) {
+        let row = &mut self.grid[y];
+        let mut indicator = 1;
+
+        for x in x..x + self.width {
+            let x = x % self.width;
+            row[x] = row[x] | indicator;
+            indicator = indicator << 1;
+        }
+    }
+    fn is_open(&self, x: usize, y: usize, minute: usize) -> bool {
+        let point = self.grid[y][x];
+        point & (1 << (minute % self.width)) == 0
+    }
+}
+
+#[derive(Clone)]
+struct Vertical {
+    grid: Vec<Vec<u32>>,
+    height: usize,
+}
+
+impl Vertical {
+    fn new(grid: Vec<Vec<u32>>, height: usize) -> Vertical {
+        Vertical { grid, height }
+    }
+    fn fill_up(&mut self, x: usize, y: usize) {
+        let mut indicator = 1 << (self.height - 1);
+
+        self.grid[y][x] = self.grid[y][x] | 1;
+
+        for y in y + 1..y + self.height {
+            let y = y % self.height;
+            self.grid[y][x] = self.grid[y][x] | indicator;
+            indicator = indicator >> 1;
+        }
+    }
+    fn fill_down(&mut self, x: usize, y: usize) {
+        let mut indicator = 1;
+
+        for y in y..y + self.height {
+            let y = y % self.height;
+            self.grid[y][x] = self.grid[y][x] | indicator;
+            indicator = indicator << 1;
+        }
+    }
+    fn is_open(&self, x: usize, y: usize, minute: usize) -> bool {
+        let point = self.grid[y][x];
+        point & (1 << (minute % self.height)) == 0
+    }
+}
+
+type Position = (i32, i32);
+
+pub struct Blizzards {
+    horizontal: Horizontal,
+    vertical: Vertical,
+    width: usize,
+    height: usize,
+    entrance: Position,
+    exit: Position
+}
+
+impl Blizzards {
+    fn parse(input: &str) -> Blizzards {
+        let mut grid = lines(input).into_iter()
+            .skip(1)
+            .map(|line| {
+                let mut row = line.chars()
+                    .skip(1)
+                    .collect::<Vec<_>>();
+                row.pop().unwrap();
+                row
+            })
+            .collect::<Vec<_>>();
+        grid.pop().unwrap();
+
+        let width = grid[0].len();
+        let height = grid.len();
+
+        let horizontal = vec![vec![0; width]; height];
+        let mut horizontal = Horizontal::new(horizontal, width);
+        let vertical = vec![vec![0; width]; height];
+        let mut vertical = Vertical::new(vertical, height);
+
+        let grid = grid;
+
+        for y in 0..height {
+            let row = &grid[y];
+            for x in 0..width {
+                let c = row[x];
+                match c {
+                    '^' => vertical.fill_up(x, y),
+                    'v' => vertical.fill_down(x, y),
+                    '<' => horizontal.fill_left(x, y),
+                    '>' => horizontal.fill_right(x, y),
+                    '.' => (),
+                    _ => panic!("Invalid character: {} at {}, {}", c, x, y)
+                }
+            }
+
+        }
+
+        Blizzards {
+            horizontal,
+            vertical,
+            width,
+            height,
+            entrance: (0, -1),
+            exit: (width as i32 - 1, height as i32)
+        }
+    }
+    
+    fn is_open(&self, (x, y): Position, minute: usize) -> bool {
+        if (x, y) == self.entrance || (x, y) == self.exit {
+            true
+        } else if x < 0 || y < 0 {
+            false
+        } else {
+            let x = x as usize;
+            let y = y as usize;
+            if x >= self.width || y >= self.height {
+                false
+            } else {
+                self.vertical.is_open(x, y, minute) && 
+                self.horizontal.is_open(x, y, minute)
+            }
+        }
+    }
+
+    /// The blizzards are back where they started after this many minutes.
+    fn period(&self) -> usize {
+        self.width * self.height
+    }
+
+    /// The minute the expedition can reach `to` by, setting off from `from`
+    /// at `minute`.
+    fn find_shortest_path(&self, from: Position, to: Position, minute: usize) -> usize {
+        let path = bfs(self, [(from, minute % self.period())], |(position, _)| *position == to)
+            .expect("Terminated without finding the end");
+
+        minute + path.cost as usize
+    }
+}
+
+impl Neighbours for Blizzards {
+    // where the expedition is and the minute, as far as the blizzards can tell
+    type State = (Position, usize);
+
+    fn neighbours(&self, &((x, y), minute): &(Position, usize)) -> Vec<((Position, usize), u64)> {
+        let minute = (minute + 1) % self.period();
+        [(x, y), (x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)].iter().copied()
+            .filter(|position| self.is_open(*position, minute))
+            .map(|position| ((position, minute), 1))
+            .collect()
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Blizzards;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Blizzards::parse(input))
+    }
+
+    fn part_one(blizzards: &Self::Parsed) -> Answer {
+        let time = blizzards.find_shortest_path(blizzards.entrance, blizzards.exit, 0);
+
+        time.into()
+    }
+
+    fn part_two(blizzards: &Self::Parsed) -> Answer {
+        let there = blizzards.find_shortest_path(blizzards.entrance, blizzards.exit, 0);
+        let back = blizzards.find_shortest_path(blizzards.exit, blizzards.entrance, there);
+        let time_total = blizzards.find_shortest_path(blizzards.entrance, blizzards.exit, back);
+
+        time_total.into()
+    }
+}
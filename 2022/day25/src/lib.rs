@@ -1,16 +1,7 @@
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
-
-fn parse_snafu(line: String) -> i64 {
+fn parse_snafu(line: &str) -> i64 {
     let mut number = 0;
     let mut power = 1;
     line.chars().rev().for_each(|c| {
@@ -53,25 +44,24 @@ fn to_snafu(mut number: i64) -> String {
     result.iter().rev().collect::<String>()
 }
 
-fn part_one(file_name: &str) {
-    let sum = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| parse_snafu(line))
-        .sum::<i64>();
-    
-    println!("Part 1: {} / {}", sum, to_snafu(sum));
-}
+pub struct Day;
 
-fn part_two(file_name: &str) {
-    let lines = get_file_lines(file_name)
-        .flat_map(|line| line.ok());
-    
-    println!("Part 2: {}", "incomplete");
-}
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let sum = lines.iter()
+            .map(|line| parse_snafu(line))
+            .sum::<i64>();
 
-    println!("Done!");
+        to_snafu(sum).into()
+    }
+
+    fn part_two(_lines: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
 }
@@ -0,0 +1,70 @@
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+fn to_number(line: &str) -> u64 {
+    line.chars()
+        .map(|item| 1 << (get_priority(item) - 1))
+        .fold(0, |result, item| result | item)
+}
+
+fn determine_mis_packed(line: &str) -> u32 {
+    let size = line.len() / 2;
+
+    let left = &line[0..size];
+    let right = &line[size..];
+
+    let left = to_number(left);
+    let right = to_number(right);
+
+    let result = left & right;
+    result.trailing_zeros() + 1
+}
+
+fn get_priority(item: char) -> u32 {
+    match item.is_lowercase() {
+        true => (item as u32 - 'a' as u32) + 1,
+        false => (item as u32 - 'A' as u32) + 27
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let total: u32 = lines.iter()
+            .map(|line| determine_mis_packed(line))
+            .sum();
+
+        total.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut lines = lines.iter();
+
+        let mut total = 0;
+
+        while let Some(one) = lines.next() {
+            let two = lines.next().unwrap();
+            let three = lines.next().unwrap();
+
+            total += determine_badge(one, two, three);
+        }
+
+        total.into()
+    }
+}
+
+fn determine_badge(one: &str, two: &str, three: &str) -> u32 {
+    let one = to_number(one);
+    let two = to_number(two);
+    let three = to_number(three);
+
+    let result = one & two & three;
+    result.trailing_zeros() + 1
+}
@@ -0,0 +1,166 @@
+use aoc_common::input::lines;
+use aoc_common::interval::Interval;
+use aoc_common::{Answer, Result, Solution};
+
+#[derive(Debug)]
+struct Pair {
+    left: Interval<i32>,
+    right: Interval<i32>
+}
+
+fn parse_range(range: &str) -> Interval<i32> {
+    let mut pieces = range.split('-');
+
+    let lower = pieces.next()
+        .unwrap()
+        .parse::<i32>()
+        .unwrap();
+    let upper = pieces.next()
+        .unwrap()
+        .parse::<i32>()
+        .unwrap();
+
+    Interval::new(lower, upper)
+}
+
+impl Pair {
+    fn from_string(line: String) -> Self {
+        let mut pieces = line.split(',');
+        
+        let left = parse_range(pieces.next().unwrap());
+        let right = parse_range(pieces.next().unwrap());
+
+        Pair {
+            left,
+            right
+        }
+    }
+
+    fn has_completely_overlapping(&self) -> bool {
+        self.left.contains_interval(&self.right) || self.right.contains_interval(&self.left)
+    }
+
+    fn has_overlapping(&self) -> bool {
+        self.left.overlaps(&self.right)
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let count = lines.iter()
+            .cloned()
+            .map(Pair::from_string)
+            .filter(Pair::has_completely_overlapping)
+            .count();
+
+        count.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let count = lines.iter()
+            .cloned()
+            .map(Pair::from_string)
+            .filter(Pair::has_overlapping)
+            .count();
+
+        count.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lower_outside() {
+        let pair = Pair::from_string("5-6,7-8".to_owned());
+        assert!(!pair.has_overlapping());
+    }
+
+    #[test]
+    fn lower_partial_border() {
+        let pair = Pair::from_string("5-6,6-8".to_owned());
+        assert!(pair.has_overlapping());
+    }
+    
+    #[test]
+    fn lower_partial_over() {
+        let pair = Pair::from_string("5-7,6-8".to_owned());
+        assert!(pair.has_overlapping());
+    }
+    
+    #[test]
+    fn inside_lower() {
+        let pair = Pair::from_string("6-7,6-8".to_owned());
+        assert!(pair.has_overlapping());
+    }
+
+    #[test]
+    fn inside_upper() {
+        let pair = Pair::from_string("7-8,6-8".to_owned());
+        assert!(pair.has_overlapping());
+    }
+
+    #[test]
+    fn inside_fully() {
+        let pair = Pair::from_string("7-7,6-8".to_owned());
+        assert!(pair.has_overlapping());
+    }
+
+    #[test]
+    fn upper_outside() {
+        let pair = Pair::from_string("9-11,7-8".to_owned());
+        assert!(!pair.has_overlapping());
+    }
+
+    #[test]
+    fn upper_partial_border() {
+        let pair = Pair::from_string("8-10,6-8".to_owned());
+        assert!(pair.has_overlapping());
+    }
+    
+    #[test]
+    fn upper_partial_over() {
+        let pair = Pair::from_string("7-10,6-8".to_owned());
+        assert!(pair.has_overlapping());
+    }
+    
+    #[test]
+    fn inside_not_touching() {
+        let pair = Pair::from_string("7-10,8-9".to_owned());
+        assert!(pair.has_overlapping());
+    }
+    
+    #[test]
+    fn rev_inside_lower() {
+        let pair = Pair::from_string("7-10,7-9".to_owned());
+        assert!(pair.has_overlapping());
+    }
+    
+    #[test]
+    fn rev_inside_upper() {
+        let pair = Pair::from_string("7-10,8-10".to_owned());
+        assert!(pair.has_overlapping());
+    }
+    
+    #[test]
+    fn rev_inside_full() {
+        let pair = Pair::from_string("7-10,8-9".to_owned());
+        assert!(pair.has_overlapping());
+    }
+    
+    #[test]
+    fn rev_inside_one() {
+        let pair = Pair::from_string("7-10,8-8".to_owned());
+        assert!(pair.has_overlapping());
+    }
+
+}
@@ -1,14 +1,5 @@
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 struct Yard {
     columns: Vec<Vec<char>>
@@ -19,7 +10,7 @@ impl Yard {
         let column_count = lines.pop()
             .unwrap()
             .chars()
-            .filter(|c| c.is_digit(10))
+            .filter(|c| c.is_ascii_digit())
             .count();
             
         let mut columns: Vec<Vec<char>> = vec![Vec::new(); column_count];
@@ -30,7 +21,7 @@ impl Yard {
             
             row.split(" ")
                 .map(|piece| match piece.len() == 3 {
-                    true => piece.chars().skip(1).next().unwrap(),
+                    true => piece.chars().nth(1).unwrap(),
                     false => ' '
                 })
                 .for_each(|item| {
@@ -83,11 +74,8 @@ impl Yard {
 
 }
 
-fn solve(file_name: &str, part: usize) {
-    let advanced = part != 1;
-
-    let mut lines = get_file_lines(file_name)
-        .flat_map(|line| line.ok());
+fn solve(lines: &[String], advanced: bool) -> String {
+    let mut lines = lines.iter().cloned();
 
     let mut yard = Yard::from_lines((&mut lines)
         .take_while(|line| !line.is_empty())
@@ -95,14 +83,23 @@ fn solve(file_name: &str, part: usize) {
 
     lines.for_each(|line| yard.perform_move(line, advanced));
     
-    let message = yard.get_message();
-
-    println!("Part {}: {}", part, message);
+    yard.get_message()
 }
 
-fn main() {
-    solve("input.txt", 1);
-    solve("input.txt", 2);
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-    println!("Done!");
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        solve(lines, false).into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        solve(lines, true).into()
+    }
 }
@@ -0,0 +1,81 @@
+use std::str::Chars;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+struct CodeSet {
+    codes: Vec<u32>,
+    index: usize,
+    size: usize
+}
+
+impl CodeSet {
+    fn new(size: usize) -> CodeSet {
+        CodeSet {
+            codes: vec![0; size],
+            index: 0,
+            size
+        }
+    }
+
+    fn to_number(code: char) -> u32 {
+        1 << (code as u32 - 'a' as u32)
+    }
+
+    fn is_start(&mut self, code: char) -> bool {
+        let code = Self::to_number(code);
+        self.codes[self.index % self.size] = code;
+        
+        let mut mask = 0;
+        for i in 0..self.size {
+            let check = self.codes[i];
+            let new_mask = mask | check;
+            if new_mask == mask {
+                self.index += 1;
+                return false;
+            }
+            mask = new_mask
+        }
+        true
+    }
+
+    fn find_start(&mut self, message: Chars) -> usize {
+        for code in message {
+            if self.is_start(code) {
+                return self.index + 1;
+            }
+        }
+        panic!("No start found!");
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let message = lines.iter()
+            .next()
+            .unwrap();
+
+        let message = message.chars();
+        let mut code_set = CodeSet::new(4);
+
+        code_set.find_start(message).into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let message = lines.iter()
+            .next()
+            .unwrap();
+
+        let message = message.chars();
+        let mut code_set = CodeSet::new(14);
+
+        code_set.find_start(message).into()
+    }
+}
@@ -1,16 +1,9 @@
+#![allow(clippy::ptr_arg, clippy::redundant_closure)]
+
 use std::collections::HashMap;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
 use std::mem::swap;
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 struct Directory {
     files: HashMap<String, u64>,
@@ -25,7 +18,7 @@ impl Directory {
         }
     }
     fn add_file(&mut self, file: String, size: u64) {
-        if let None = self.files.insert(file, size) {
+        if self.files.insert(file, size).is_none() {
             self.size += size;
         }
     }
@@ -90,9 +83,7 @@ impl FileSystem {
     fn add_directory(&mut self, dir: &str) {
         let resolved = self.resolve_from_working(dir);
 
-        if !self.directories.contains_key(&resolved) {
-            self.directories.insert(resolved, Directory::new());
-        }
+        self.directories.entry(resolved).or_insert_with(|| Directory::new());
     }
 
     fn reset_listing(&mut self) {
@@ -104,7 +95,7 @@ impl FileSystem {
         self.working.add_file(file.to_owned(), size);
     }
 
-    fn read_output(&mut self, line: String) {
+    fn read_output(&mut self, line: &str) {
         let tokens = line.split(" ").collect::<Vec<_>>();
         match tokens[0] {
             "$" => match tokens[1] {
@@ -141,7 +132,7 @@ impl FileSystem {
             *size
         } else {
             let size = dir.size + self.directories.iter()
-                .filter(|(sub_path, _)| FileSystem::is_sub_dir(path, &sub_path))
+                .filter(|(sub_path, _)| FileSystem::is_sub_dir(path, sub_path))
                 .map(|(sub_path, sub_dir)| self.compute_total_size_for(sub_path, sub_dir, sizes))
                 .sum::<u64>();
             
@@ -152,53 +143,54 @@ impl FileSystem {
 
 }
 
-fn part_one(file_name: &str) {
-    let mut file_system = FileSystem::new();
+pub struct Day;
 
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .for_each(|line| file_system.read_output(line));
-    
-    file_system.change_directory("/");
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-    let sizes = file_system.compute_total_sizes();
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
 
-    let total = sizes.values()
-        .filter(|size| **size <= 100000)
-        .sum::<u64>();
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let mut file_system = FileSystem::new();
 
-    println!("Part 1: {}", total);
-}
+        lines.iter()
+            .for_each(|line| file_system.read_output(line));
 
-fn part_two(file_name: &str) {
-    let mut file_system = FileSystem::new();
+        file_system.change_directory("/");
 
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .for_each(|line| file_system.read_output(line));
-    
-    file_system.change_directory("/");
-    
-    let sizes = file_system.compute_total_sizes();
+        let sizes = file_system.compute_total_sizes();
 
-    let total = 70000000;
-    let target = 30000000;
-    let free = total - sizes.get("/").unwrap();
-    let to_free = target - free;
-    
-    let min_free_size = sizes.values()
-        .filter(|size| **size > to_free)
-        .min()
-        .unwrap();
-    
-    println!("Part 2: {}", min_free_size);
-}
+        let total = sizes.values()
+            .filter(|size| **size <= 100000)
+            .sum::<u64>();
+
+        total.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut file_system = FileSystem::new();
+
+        lines.iter()
+            .for_each(|line| file_system.read_output(line));
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+        file_system.change_directory("/");
 
-    println!("Done!");
+        let sizes = file_system.compute_total_sizes();
+
+        let total = 70000000;
+        let target = 30000000;
+        let free = total - sizes.get("/").unwrap();
+        let to_free = target - free;
+
+        let min_free_size = sizes.values()
+            .filter(|size| **size > to_free)
+            .min()
+            .unwrap();
+
+        (*min_free_size).into()
+    }
 }
 
 #[cfg(test)]
@@ -353,4 +345,4 @@ mod tests {
 
     
     
-}
\ No newline at end of file
+}
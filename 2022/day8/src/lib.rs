@@ -1,15 +1,6 @@
 use std::collections::HashSet;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::grid::{Grid, Point};
+use aoc_common::{Answer, Result, Solution};
 
 #[derive(Clone, Copy)]
 enum Direction {
@@ -19,48 +10,46 @@ enum Direction {
     Right
 }
 
-struct Trees {
-    trees: Vec<Vec<u32>>,
+impl Direction {
+    fn step(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0)
+        }
+    }
+}
+
+pub struct Trees {
+    trees: Grid<u32>,
     width: usize,
     height: usize
 }
 
 impl Trees {
-    fn new(file_name: &str) -> Trees {
-        let trees = get_file_lines(file_name)
-            .flat_map(|line| line.ok())
-            .map(|line| line.chars()
-                .map(|c| c.to_digit(10).unwrap())
-                .collect::<Vec<_>>())
-            .collect::<Vec<_>>();
+    fn new(input: &str) -> Result<Trees> {
+        let trees = Grid::parse(input, |c| c.to_digit(10))?;
         
-        let width = trees[0].len();
-        let height = trees.len();
+        let width = trees.width();
+        let height = trees.height();
 
-        Trees {
+        Ok(Trees {
             trees,
             width,
             height
-        }
+        })
     }
 
-    fn move_next(&self, direction: Direction, point: (usize, usize)) -> Option<(usize, usize)> {
-        let (x, y) = point;
-        match direction {
-            Direction::Up    if y > 0               => Some((x, y - 1)),
-            Direction::Down  if y + 1 < self.height => Some((x, y + 1)),
-            Direction::Left  if x > 0               => Some((x - 1, y)),
-            Direction::Right if x + 1 < self.width  => Some((x + 1, y)),
-            _ => None
-        }
+    fn move_next(&self, direction: Direction, point: Point) -> Option<Point> {
+        self.trees.step(point, direction.step())
     }
 
-    fn height(&self, point: (usize, usize)) -> u32 {
-        let (x, y) = point;
-        self.trees[y][x]
+    fn height(&self, point: Point) -> u32 {
+        self.trees[point]
     }
 
-    fn count_visible_trees(&self, direction: Direction, visible: &mut HashSet<(usize, usize)>) {
+    fn count_visible_trees(&self, direction: Direction, visible: &mut HashSet<Point>) {
         let (move_after_row, mut point) = match direction {
             Direction::Down => (Direction::Right, (0, 0)),
             Direction::Up => (Direction::Right, (0, self.height - 1)),
@@ -91,7 +80,7 @@ impl Trees {
         }
     }
 
-    fn count_visible_trees_from(&self, direction: Direction, mut point: (usize, usize)) -> u32 {
+    fn count_visible_trees_from(&self, direction: Direction, mut point: Point) -> u32 {
         let mut count = 0;
         let my_height = self.height(point);
         while let Some(new_point) = self.move_next(direction, point) {
@@ -105,7 +94,7 @@ impl Trees {
         count
     }
 
-    fn determine_scenic_score(&self, point: (usize, usize)) -> u32 {
+    fn determine_scenic_score(&self, point: Point) -> u32 {
         self.count_visible_trees_from(Direction::Up, point) *
         self.count_visible_trees_from(Direction::Down, point) *
         self.count_visible_trees_from(Direction::Left, point) *
@@ -125,30 +114,31 @@ impl Trees {
 
 }
 
-fn part_one(file_name: &str) {
-    let trees = Trees::new(file_name);
+pub struct Day;
 
-    let mut visible = HashSet::new();
+impl Solution for Day {
+    type Parsed = Trees;
 
-    trees.count_visible_trees(Direction::Down, &mut visible);
-    trees.count_visible_trees(Direction::Up, &mut visible);
-    trees.count_visible_trees(Direction::Left, &mut visible);
-    trees.count_visible_trees(Direction::Right, &mut visible);
-    
-    println!("Part 1: {}", visible.len());
-}
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Trees::new(input)
+    }
 
-fn part_two(file_name: &str) {
-    let trees = Trees::new(file_name);
+    fn part_one(trees: &Self::Parsed) -> Answer {
 
-    let best = trees.determine_best_scenic_score();
-    
-    println!("Part 2: {}", best);
-}
+        let mut visible = HashSet::new();
+
+        trees.count_visible_trees(Direction::Down, &mut visible);
+        trees.count_visible_trees(Direction::Up, &mut visible);
+        trees.count_visible_trees(Direction::Left, &mut visible);
+        trees.count_visible_trees(Direction::Right, &mut visible);
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+        visible.len().into()
+    }
+
+    fn part_two(trees: &Self::Parsed) -> Answer {
 
-    println!("Done!");
+        let best = trees.determine_best_scenic_score();
+
+        best.into()
+    }
 }
@@ -1,15 +1,6 @@
 use std::collections::HashSet;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 #[derive(Clone, Copy, Debug)]
 struct Point {
@@ -106,29 +97,30 @@ impl RopeBridge {
     }
 }
 
-fn part_one(file_name: &str) {
-    let mut bridge = RopeBridge::new(2);
+pub struct Day;
 
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .for_each(|line| bridge.perform_move(line.as_str()));
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-    println!("Part 1: {}", bridge.visited.len());
-}
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
 
-fn part_two(file_name: &str) {
-    let mut bridge = RopeBridge::new(10);
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let mut bridge = RopeBridge::new(2);
 
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .for_each(|line| bridge.perform_move(line.as_str()));
-    
-    println!("Part 2: {}", bridge.visited.len());
-}
+        lines.iter()
+            .for_each(|line| bridge.perform_move(line.as_str()));
+
+        bridge.visited.len().into()
+    }
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut bridge = RopeBridge::new(10);
 
-    println!("Done!");
-}
\ No newline at end of file
+        lines.iter()
+            .for_each(|line| bridge.perform_move(line.as_str()));
+
+        bridge.visited.len().into()
+    }
+}
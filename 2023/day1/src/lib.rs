@@ -0,0 +1,79 @@
+use regex::Regex;
+use map_macro::hash_map;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let sum = lines.iter()
+            .map(|line| {
+                let numbers = line.chars()
+                    .filter(|c| c.is_numeric())
+                    .collect::<Vec<_>>();
+
+                let first = numbers.iter()
+                    .take(1)
+                    .next_back()
+                    .unwrap();
+
+                let last = numbers.last()
+                    .unwrap();
+
+                let number = first.to_string() + &last.to_string();
+                number.parse::<i32>().unwrap()
+            })
+            .sum::<i32>();
+
+        sum.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let word_numbers = hash_map! {
+            "one"   => "1",
+            "two"   => "2",
+            "three" => "3",
+            "four"  => "4",
+            "five"  => "5",
+            "six"   => "6",
+            "seven" => "7",
+            "eight" => "8",
+            "nine"  => "9"
+        };
+        let word_number_regex = "one|two|three|four|five|six|seven|eight|nine";
+        let word_number_regex_rev = word_number_regex.chars().rev().collect::<String>();
+        let forward = "([1-9]|".to_owned() + word_number_regex + ")";
+        let backward = "([1-9]|".to_owned() + word_number_regex_rev.as_str() + ")";
+        let number_regex = Regex::new(forward.as_str()).unwrap();
+        let number_regex_rev = Regex::new(backward.as_str()).unwrap();
+        let sum = lines.iter()
+            .map(|line| {
+                let line_rev = line.chars().rev().collect::<String>();
+                let first = number_regex.find(line).unwrap().as_str();
+                let last = number_regex_rev.find(&line_rev).unwrap().as_str();
+                let last = last.chars().rev().collect::<String>();
+
+                let first = match word_numbers.get(&first) {
+                    Some(x) => *x,
+                    None => first
+                };
+                let last = match word_numbers.get(last.as_str()) {
+                    Some(x) => *x,
+                    None => last.as_str()
+                };
+
+                let number = first.to_string() + last;
+                number.parse::<i32>().unwrap()
+            })
+            .sum::<i32>();
+
+        sum.into()
+    }
+}
@@ -1,16 +1,7 @@
 use std::collections::{HashMap, LinkedList, HashSet};
-use std::fs::File;
 use std::mem::swap;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::grid::Grid;
+use aoc_common::{Answer, Result, Solution};
 
 #[derive(PartialEq, Debug, Clone)]
 enum Pipe {
@@ -45,47 +36,40 @@ const VALID_LEFT: [Pipe; 3] = [Pipe::NorthToEast, Pipe::SouthToEast, Pipe::EastW
 const VALID_UP: [Pipe; 3] = [Pipe::SouthToWest, Pipe::SouthToEast, Pipe::NorthSouth];
 const VALID_DOWN: [Pipe; 3] = [Pipe::NorthToWest, Pipe::NorthToEast, Pipe::NorthSouth];
 
-struct Map {
-    pipes: HashMap<(i32, i32), Pipe>
+pub struct Map {
+    pipes: Grid<Pipe>
 }
 
 impl Map {
-    fn parse(file_name: &str) -> Map {
-        let mut y = 0;
-        let pipes = get_file_lines(file_name)
-            .flat_map(|line| line.ok())
-            .flat_map(|line| {
-                let mut x = 0;
-                let result = line.chars().flat_map(move |c| {
-                    let pipe = match c {
-                        '-' => Pipe::EastWest,
-                        '|' => Pipe::NorthSouth,
-                        'L' => Pipe::NorthToEast,
-                        'J' => Pipe::NorthToWest,
-                        '7' => Pipe::SouthToWest,
-                        'F' => Pipe::SouthToEast,
-                        'S' => Pipe::Start,
-                        '.' => Pipe::Empty,
-                        x => panic!("Unexpected character: {}", x)
-                    };
-                    let result = match pipe {
-                        Pipe::Empty => None,
-                        pipe => Some(((x, y), pipe))
-                    };
-                    x += 1;
-                    result
-                }).collect::<Vec<_>>();
-                y += 1;
-                result
-            })
-            .collect::<HashMap<_,_>>();
-        Map { pipes }
+    fn parse(input: &str) -> Result<Map> {
+        let pipes = Grid::parse(input, |c| {
+            match c {
+                '-' => Some(Pipe::EastWest),
+                '|' => Some(Pipe::NorthSouth),
+                'L' => Some(Pipe::NorthToEast),
+                'J' => Some(Pipe::NorthToWest),
+                '7' => Some(Pipe::SouthToWest),
+                'F' => Some(Pipe::SouthToEast),
+                'S' => Some(Pipe::Start),
+                '.' => Some(Pipe::Empty),
+                _ => None
+            }
+        })?;
+        Ok(Map { pipes })
+    }
+
+    fn get(&self, (x, y): (i32, i32)) -> Option<&Pipe> {
+        if x < 0 || y < 0 {
+            return None;
+        }
+        self.pipes.get((x as usize, y as usize))
+            .filter(|pipe| **pipe != Pipe::Empty)
     }
 
     fn get_adjacent(&self, from: &Point, adjacent: &mut Vec<Point>) {
         let x = from.x;
         let y = from.y;
-        let current = self.pipes.get(&(x, y)).unwrap();
+        let current = self.get((x, y)).unwrap();
         // println!("Getting adjacent for {:?} - pipe is: {:?}", from, current);
         let points = match current {
             Pipe::EastWest => vec![
@@ -123,7 +107,7 @@ impl Map {
 
         points.into_iter()
             .map(|(point, valid_next)| (Point::new(point, from.source), valid_next))
-            .flat_map(|(point, valid_next)| self.pipes.get(&(point.x, point.y)).map(|pipe| (point, pipe, valid_next)))
+            .flat_map(|(point, valid_next)| self.get((point.x, point.y)).map(|pipe| (point, pipe, valid_next)))
             .filter(|(_, pipe, valid_next)| valid_next.contains(*pipe))
             .for_each(|(point, _, _)| {
                 adjacent.push(point)
@@ -152,14 +136,8 @@ impl Map {
     }
 
     fn find_loop(&self) -> Vec<(i32, i32)> {
-        let start = *self.pipes.iter()
-            .find_map(|(coord, pipe)| {
-                match pipe {
-                    Pipe::Start => Some(coord),
-                    _ => None
-                }
-            })
-            .unwrap();
+        let (x, y) = self.pipes.position(|pipe| *pipe == Pipe::Start).unwrap();
+        let start = (x as i32, y as i32);
 
         let mut visited = HashMap::<(i32, i32), Point>::new();
 
@@ -210,8 +188,9 @@ impl Map {
         let loop_path = loop_path.into_iter().collect::<HashSet<_>>();
 
         let pipes = self.pipes.iter()
+            .map(|((x, y), pipe)| ((x as i32, y as i32), pipe))
             .filter(|(coord, _)| loop_path.contains(coord))
-            .map(|(coord, pipe)| (*coord, pipe.clone()))
+            .map(|(coord, pipe)| (coord, pipe.clone()))
             .collect::<HashMap<_,_>>();
 
         let width = loop_path.iter()
@@ -257,23 +236,24 @@ impl Map {
     }
 }
 
-fn part_one(file_name: &str) {
-    let map = Map::parse(file_name);
-    let path = map.find_loop();
-    let steps = (path.len() / 2) + (path.len() % 2);
-    println!("Part 1: {}", steps);
-}
+pub struct Day;
 
-fn part_two(file_name: &str) {
-    let map = Map::parse(file_name);
-    let path = map.find_loop();
-    let count = map.count_enclosed_area(path);
-    println!("Part 2: {}", count);
-}
+impl Solution for Day {
+    type Parsed = Map;
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Map::parse(input)
+    }
+
+    fn part_one(map: &Self::Parsed) -> Answer {
+        let path = map.find_loop();
+        let steps = (path.len() / 2) + (path.len() % 2);
+        steps.into()
+    }
 
-    println!("Done!");
+    fn part_two(map: &Self::Parsed) -> Answer {
+        let path = map.find_loop();
+        let count = map.count_enclosed_area(path);
+        count.into()
+    }
 }
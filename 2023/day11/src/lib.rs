@@ -1,25 +1,17 @@
-use std::collections::HashSet;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
+#![allow(clippy::if_same_then_else, clippy::needless_range_loop)]
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use std::collections::HashSet;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 struct Universe {
     galaxies: HashSet<(i64, i64)>
 }
 
 impl Universe {
-    fn parse(file_name: &str, expansion: i64) -> Universe {
+    fn parse(lines: &[String], expansion: i64) -> Universe {
         let expansion = expansion - 1;
-        let grid = get_file_lines(file_name)
-            .flat_map(|line| line.ok())
+        let grid = lines.iter()
             .map(|line| line.chars().collect::<Vec<_>>())
             .collect::<Vec<_>>();
 
@@ -51,7 +43,7 @@ impl Universe {
             if column.is_empty() {
                 use_x += expansion;
             } else {
-                column.into_iter()
+                column.iter()
                     .map(|y| (use_x, *y))
                     .for_each(|point| { galaxies.insert(point); });
             }
@@ -77,8 +69,8 @@ impl Universe {
                 }
 
                 let dist = 
-                    (one_x - two_x).abs() as i64 + 
-                    (one_y - two_y).abs() as i64;
+                    (one_x - two_x).abs() + 
+                    (one_y - two_y).abs();
 
                 total += dist;
             }
@@ -87,21 +79,24 @@ impl Universe {
     }
 }
 
-fn part_one(file_name: &str) {
-    let universe = Universe::parse(file_name, 2);
-    let total = universe.sum_distances();
-    println!("Part 1: {}", total);
-}
+pub struct Day;
 
-fn part_two(file_name: &str) {
-    let universe = Universe::parse(file_name, 1000000);
-    let total = universe.sum_distances();
-    println!("Part 2: {}", total);
-}
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
 
-    println!("Done!");
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let universe = Universe::parse(lines, 2);
+        let total = universe.sum_distances();
+        total.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let universe = Universe::parse(lines, 1000000);
+        let total = universe.sum_distances();
+        total.into()
+    }
 }
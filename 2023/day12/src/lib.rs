@@ -1,20 +1,10 @@
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
 use std::sync::Arc;
 use std::thread;
-use std::time::Duration;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 use concurrent_queue::ConcurrentQueue;
 
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
-
 #[derive(Clone, Debug)]
 struct Report {
     counts: Vec<usize>,
@@ -23,7 +13,7 @@ struct Report {
 
 impl Report {
 
-    fn parse(line: String) -> Report {
+    fn parse(line: &str) -> Report {
         let mut parts = line.split(' ');
 
         let springs = parts.next()
@@ -72,14 +62,12 @@ impl Report {
                 break;
             }
 
-            if self.springs[start..i].iter()
-                .any(|c| *c == '#') 
+            if self.springs[start..i].contains(&'#') 
             {
                 break;
             }
             
-            let has_space = self.springs[i..i + count].iter()
-                .any(|c| *c == '.') == false;
+            let has_space = !self.springs[i..i + count].contains(&'.');
             if !has_space {
                 continue;
             }
@@ -100,8 +88,7 @@ impl Report {
             if count_index + 1 < self.counts.len() {
                 options += self.count_options(start_next, count_index + 1);
             } else {
-                let has_damaged = self.springs[i + count..].iter()
-                    .any(|c| *c == '#');
+                let has_damaged = self.springs[i + count..].contains(&'#');
                 if !has_damaged {
                     options += 1;
                 }
@@ -113,67 +100,67 @@ impl Report {
 
 }
 
-fn part_one(file_name: &str) {
-    let mut index = 0;
-    let total = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| Report::parse(line))
-        .map(|report| {
-            index += 1;
-            let count = report.count_options(0, 0);
-            // println!("#{} = {}", index, count);
-            count
-        })
-        .sum::<usize>();
-    
-    println!("Part 1: {}", total);
-}
+pub struct Day;
 
-fn part_two(file_name: &str) {
-    let reports = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| Report::parse(line))
-        .collect::<Vec<_>>();
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-    let thread_count = 10;
-    let queue = ConcurrentQueue::<(Report, usize)>::bounded(reports.len());
-    
-    let mut index = 1;
-    reports.into_iter().for_each(|mut report| {
-        report.unfold();
-        queue.push((report, index)).unwrap();
-        index += 1;
-    });
-
-    queue.close();
-
-    let queue_rc = Arc::new(queue);
-
-    let mut handles = Vec::new();
-
-    for _ in 0..thread_count {
-        let copy = Arc::clone(&queue_rc);
-        handles.push(thread::spawn(move || {
-            let mut total = 0;
-            while let Ok((report, line)) = copy.pop() {
-                let count = report.count_options(0, 0) as u64;
-                println!("Line {} = {}", line, count);
-                total += count;
-            }
-            total
-        }));
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
     }
 
-    let total = handles.into_iter()
-        .map(|handle| handle.join().unwrap())
-        .sum::<u64>();
-    
-    println!("Part 2: {}", total);
-}
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let mut index = 0;
+        let total = lines.iter()
+            .map(|line| Report::parse(line))
+            .map(|report| {
+                index += 1;
+                let count = report.count_options(0, 0);
+                // println!("#{} = {}", index, count);
+                count
+            })
+            .sum::<usize>();
+
+        total.into()
+    }
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let reports = lines.iter()
+            .map(|line| Report::parse(line))
+            .collect::<Vec<_>>();
 
-    println!("Done!");
+        let thread_count = 10;
+        let queue = ConcurrentQueue::<(Report, usize)>::bounded(reports.len());
+
+        let mut index = 1;
+        reports.into_iter().for_each(|mut report| {
+            report.unfold();
+            queue.push((report, index)).unwrap();
+            index += 1;
+        });
+
+        queue.close();
+
+        let queue_rc = Arc::new(queue);
+
+        let mut handles = Vec::new();
+
+        for _ in 0..thread_count {
+            let copy = Arc::clone(&queue_rc);
+            handles.push(thread::spawn(move || {
+                let mut total = 0;
+                while let Ok((report, _)) = copy.pop() {
+                    let count = report.count_options(0, 0) as u64;
+                    total += count;
+                }
+                total
+            }));
+        }
+
+        let total = handles.into_iter()
+            .map(|handle| handle.join().unwrap())
+            .sum::<u64>();
+
+        total.into()
+    }
 }
@@ -1,16 +1,10 @@
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
+#![allow(clippy::needless_return, clippy::println_empty_string, clippy::ptr_arg)]
 
-type Input = Lines<BufReader<File>>;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
-
-struct Map {
+#[derive(Clone)]
+pub struct Map {
     rows: Vec<u32>,
     columns: Vec<u32>,
 }
@@ -172,9 +166,8 @@ impl Map {
     }
 }
 
-fn parse_maps(file_name: &str) -> Vec<Map> {
-    let mut lines = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
+fn parse_maps(input: &str) -> Vec<Map> {
+    let mut lines = lines(input).into_iter()
         .peekable();
 
     let mut maps = Vec::new();
@@ -189,25 +182,27 @@ fn parse_maps(file_name: &str) -> Vec<Map> {
     maps
 }
 
-fn part_one(file_name: &str) {
-    let maps = parse_maps(file_name);
-    let total = maps.iter()
-        .map(|map| map.summarize(usize::MAX, usize::MAX))
-        .sum::<usize>();
-    println!("Part 1: {}", total);
-}
+pub struct Day;
 
-fn part_two(file_name: &str) {
-    let mut maps = parse_maps(file_name);
-    let total = maps.iter_mut()
-        .map(|map| map.find_mirror_flip_summary())
-        .sum::<usize>();
-    println!("Part 2: {}", total);
-}
+impl Solution for Day {
+    type Parsed = Vec<Map>;
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(parse_maps(input))
+    }
+
+    fn part_one(maps: &Self::Parsed) -> Answer {
+        let total = maps.iter()
+            .map(|map| map.summarize(usize::MAX, usize::MAX))
+            .sum::<usize>();
+        total.into()
+    }
 
-    println!("Done!");
+    fn part_two(maps: &Self::Parsed) -> Answer {
+        let mut maps = maps.clone();
+        let total = maps.iter_mut()
+            .map(|map| map.find_mirror_flip_summary())
+            .sum::<usize>();
+        total.into()
+    }
 }
@@ -0,0 +1,81 @@
+use aoc_common::cycle::brent;
+use aoc_common::grid::Grid;
+use aoc_common::{Answer, Result, Solution};
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Platform {
+    rocks: Grid<char>
+}
+
+impl Platform {
+    fn parse(input: &str) -> Result<Platform> {
+        let rocks = Grid::parse(input, |c| match c {
+            '#' | 'O' | '.' => Some(c),
+            _ => None
+        })?;
+
+        Ok(Platform { rocks })
+    }
+
+    fn calc_load(&self) -> usize {
+        let height = self.rocks.height();
+
+        self.rocks.iter()
+            .filter(|(_, rock)| **rock == 'O')
+            .map(|((_, y), _)| height - y)
+            .sum::<usize>()
+    }
+
+    fn tilt_up(&mut self) {
+        for x in 0..self.rocks.width() {
+            let mut new_y = 0;
+            for y in 0..self.rocks.height() {
+                match self.rocks[(x, y)] {
+                    '#' => new_y = y + 1,
+                    'O' => {
+                        self.rocks[(x, y)] = '.';
+                        self.rocks[(x, new_y)] = 'O';
+                        new_y += 1;
+                    },
+                    _ => ()
+                }
+            }
+        }
+    }
+
+    fn spin(&mut self) {
+        // north, then west, south and east, each coming round to the top in
+        // turn as the platform is rotated
+        for _ in 0..4 {
+            self.tilt_up();
+            self.rocks = self.rocks.rotate_clockwise();
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Platform;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Platform::parse(input)
+    }
+
+    fn part_one(platform: &Self::Parsed) -> Answer {
+        let mut platform = platform.clone();
+        platform.tilt_up();
+        let load = platform.calc_load();
+        load.into()
+    }
+
+    fn part_two(platform: &Self::Parsed) -> Answer {
+        let cycle = brent(platform.clone(), Platform::spin);
+
+        let mut platform = platform.clone();
+        for _ in 0..cycle.equivalent(1000000000) {
+            platform.spin();
+        }
+        platform.calc_load().into()
+    }
+}
@@ -1,15 +1,8 @@
-use std::collections::{HashMap, VecDeque, HashSet};
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
+#![allow(clippy::ptr_arg, clippy::redundant_closure)]
 
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use std::collections::{VecDeque, HashSet};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 fn hash_step(step: &String) -> usize {
     step.chars().fold(0, |result, c| {
@@ -37,7 +30,7 @@ impl Step {
                 operation: Operation::Add(lens)
             }
         } else {
-            let label = line[0..line.len() - 1].to_owned();;
+            let label = line[0..line.len() - 1].to_owned();
             Step {
                 label,
                 operation: Operation::Remove
@@ -108,40 +101,41 @@ impl Box {
     }
 }
 
-fn part_one(file_name: &str) {
-    let total = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .flat_map(|line| line.split(',').map(|part| part.to_owned()).collect::<Vec<_>>())
-        .map(|line| hash_step(&line))
-        .sum::<usize>();
-    
-    println!("Part 1: {}", total);
-}
+pub struct Day;
 
-fn part_two(file_name: &str) {
-    let mut boxes = vec![Box::new(); 256];
-
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .flat_map(|line| line.split(',').map(|part| part.to_owned()).collect::<Vec<_>>())
-        .map(|step_str| Step::parse(step_str))
-        .for_each(|step| step.apply(&mut boxes));
-
-    let mut index = 0;
-    let total = boxes.iter()
-        .map(|lens_box| {
-            let power = lens_box.get_focusing_power(index);
-            index += 1;
-            power
-        })
-        .sum::<u32>();
-    
-    println!("Part 2: {}", total);
-}
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let total = lines.iter()
+            .flat_map(|line| line.split(',').map(|part| part.to_owned()).collect::<Vec<_>>())
+            .map(|line| hash_step(&line))
+            .sum::<usize>();
 
-    println!("Done!");
+        total.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut boxes = vec![Box::new(); 256];
+
+        lines.iter()
+            .flat_map(|line| line.split(',').map(|part| part.to_owned()).collect::<Vec<_>>())
+            .map(|step_str| Step::parse(step_str))
+            .for_each(|step| step.apply(&mut boxes));
+
+        let mut index = 0;
+        let total = boxes.iter()
+            .map(|lens_box| {
+                let power = lens_box.get_focusing_power(index);
+                index += 1;
+                power
+            })
+            .sum::<u32>();
+
+        total.into()
+    }
 }
@@ -0,0 +1,144 @@
+use aoc_common::grid::Grid;
+use aoc_common::{Answer, Result, Solution};
+
+#[derive(Debug, Clone)]
+enum Mirror {
+    Horizontal,
+    Vertical,
+    BackSlash,
+    ForwardSlash
+}
+
+#[derive(Eq, PartialEq, Hash, Clone, Debug)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+impl Direction {
+    fn step(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0)
+        }
+    }
+    fn bit(&self) -> u8 {
+        match self {
+            Direction::Up => 1,
+            Direction::Down => 2,
+            Direction::Left => 4,
+            Direction::Right => 8
+        }
+    }
+}
+
+pub struct Arrangement {
+    mirrors: Grid<Option<Mirror>>,
+    width: usize,
+    height: usize
+}
+
+impl Arrangement {
+    fn parse(input: &str) -> Result<Arrangement> {
+        let mirrors = Grid::parse(input, |c| match c {
+            '-' => Some(Some(Mirror::Horizontal)),
+            '|' => Some(Some(Mirror::Vertical)),
+            '/' => Some(Some(Mirror::ForwardSlash)),
+            '\\' => Some(Some(Mirror::BackSlash)),
+            '.' => Some(None),
+            _ => None
+        })?;
+        let width = mirrors.width();
+        let height = mirrors.height();
+
+        Ok(Arrangement { mirrors, width, height })
+    }
+
+    fn reflect_mirror(direction: Direction, mirror: &Mirror, beams: &mut Vec<Direction>) {
+        match (&direction, mirror) {
+            (Direction::Left | Direction::Right, Mirror::Horizontal) => beams.push(direction),
+            (Direction::Up | Direction::Down, Mirror::Horizontal) => {
+                beams.push(Direction::Left);
+                beams.push(Direction::Right);
+            },
+            (Direction::Up | Direction::Down, Mirror::Vertical) => beams.push(direction), 
+            (Direction::Left | Direction::Right, Mirror::Vertical) => {
+                beams.push(Direction::Up);
+                beams.push(Direction::Down);
+            },
+            (Direction::Up, Mirror::BackSlash) => beams.push(Direction::Left),
+            (Direction::Down, Mirror::BackSlash) => beams.push(Direction::Right),
+            (Direction::Left, Mirror::BackSlash) => beams.push(Direction::Up),
+            (Direction::Right, Mirror::BackSlash) => beams.push(Direction::Down),
+            (Direction::Up, Mirror::ForwardSlash) => beams.push(Direction::Right),
+            (Direction::Down, Mirror::ForwardSlash) => beams.push(Direction::Left),
+            (Direction::Left, Mirror::ForwardSlash) => beams.push(Direction::Down),
+            (Direction::Right, Mirror::ForwardSlash) => beams.push(Direction::Up),
+        }
+    }
+
+    fn count_energized(&self, start: (usize, usize, Direction)) -> usize {
+        // the directions beams have entered each tile in so far
+        let mut entered = self.mirrors.map(|_| 0u8);
+        let (start_x, start_y, start_direction) = start;
+        let mut beams = vec![((start_x, start_y), start_direction)];
+        let mut turns = Vec::new();
+
+        while let Some((point, direction)) = beams.pop() {
+            if entered[point] & direction.bit() != 0 {
+                // we've already had a beam come this way, so anything
+                // after this would be redundant
+                continue;
+            }
+            entered[point] |= direction.bit();
+
+            match &self.mirrors[point] {
+                Some(mirror) => Arrangement::reflect_mirror(direction, mirror, &mut turns),
+                None => turns.push(direction)
+            }
+            for direction in turns.drain(..) {
+                if let Some(next) = self.mirrors.step(point, direction.step()) {
+                    beams.push((next, direction));
+                }
+            }
+        }
+
+        entered.values()
+            .filter(|directions| **directions != 0)
+            .count()
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Arrangement;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Arrangement::parse(input)
+    }
+
+    fn part_one(arrangement: &Self::Parsed) -> Answer {
+        let energized = arrangement.count_energized((0, 0, Direction::Right));
+        energized.into()
+    }
+
+    fn part_two(arrangement: &Self::Parsed) -> Answer {
+        let mut max = 0;
+
+        for x in 0..arrangement.width {
+            max = max.max(arrangement.count_energized((x, 0, Direction::Down)));
+            max = max.max(arrangement.count_energized((x, arrangement.height - 1, Direction::Up)));
+        }
+        for y in 0..arrangement.height {
+            max = max.max(arrangement.count_energized((0, y, Direction::Right)));
+            max = max.max(arrangement.count_energized((arrangement.width - 1, y, Direction::Left)));
+        }
+
+        max.into()
+    }
+}
@@ -0,0 +1,78 @@
+use aoc_common::grid::{Grid, Point, ORTHOGONAL};
+use aoc_common::search::{dijkstra, Neighbours};
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Map {
+    blocks: Grid<u32>
+}
+
+// where the crucible is, which way it's heading and how many blocks it's
+// gone in that direction
+type Crucible = (Point, (isize, isize), usize);
+
+/// The moves a crucible can make, having to go at least `min_straight` and
+/// at most `max_straight` blocks in a line before turning.
+struct Moves<'a> {
+    map: &'a Map,
+    min_straight: usize,
+    max_straight: usize
+}
+
+impl Neighbours for Moves<'_> {
+    type State = Crucible;
+
+    fn neighbours(&self, &(point, heading, straight_count): &Crucible) -> Vec<(Crucible, u64)> {
+        ORTHOGONAL.iter()
+            // it can't turn back
+            .filter(|direction| **direction != (-heading.0, -heading.1))
+            .flat_map(|&direction| {
+                let straight_count = match direction == heading {
+                    true if straight_count < self.max_straight => straight_count + 1,
+                    // it starts out not heading anywhere, so can set off any way
+                    false if straight_count >= self.min_straight || straight_count == 0 => 1,
+                    _ => return None
+                };
+                let next = self.map.blocks.step(point, direction)?;
+                Some(((next, direction, straight_count), self.map.blocks[next] as u64))
+            })
+            .collect()
+    }
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Map> {
+        Ok(Map { blocks: Grid::parse(input, |c| c.to_digit(10))? })
+    }
+
+    fn find_min_heat_loss(&self, min_straight: usize, max_straight: usize) -> u64 {
+        let moves = Moves { map: self, min_straight, max_straight };
+        let end = (self.blocks.width() - 1, self.blocks.height() - 1);
+
+        // the crucible can't stop until it's gone far enough in a line
+        dijkstra(&moves, [((0, 0), (0, 0), 0)], |(point, _, straight_count)| {
+            *point == end && *straight_count >= min_straight
+        })
+        .expect("end not found!")
+        .cost
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Map::parse(input)
+    }
+
+    fn part_one(map: &Self::Parsed) -> Answer {
+        let heat_loss = map.find_min_heat_loss(1, 3);
+        heat_loss.into()
+    }
+
+    fn part_two(map: &Self::Parsed) -> Answer {
+        let heat_loss = map.find_min_heat_loss(4, 10);
+        heat_loss.into()
+    }
+}
@@ -1,22 +1,13 @@
 use std::collections::{HashSet, BTreeSet, HashMap};
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 struct Instruction {
     vector: (i128, i128)
 }
 
 impl Instruction {
-    fn parse(line: String, hex: bool) -> Instruction {
+    fn parse(line: &str, hex: bool) -> Instruction {
         let pieces = line.split_ascii_whitespace()
             .collect::<Vec<_>>();
 
@@ -193,9 +184,8 @@ struct DigPlan {
 }
 
 impl DigPlan {
-    fn parse(file_name: &str, hex: bool) -> DigPlan {
-        let instructions = get_file_lines(file_name)
-            .flat_map(|line| line.ok())
+    fn parse(lines: &[String], hex: bool) -> DigPlan {
+        let instructions = lines.iter()
             .map(|line| Instruction::parse(line, hex))
             .collect::<Vec<_>>();
         DigPlan { instructions }
@@ -212,23 +202,26 @@ impl DigPlan {
     }
 }
 
-fn part_one(file_name: &str) {
-    let plan = DigPlan::parse(file_name, false);
-    let trench = plan.dig_trench();
-    let hole_size = trench.get_excavation_size();
-    println!("Part 1: {}", hole_size);
-}
+pub struct Day;
 
-fn part_two(file_name: &str) {
-    let plan = DigPlan::parse(file_name, true);
-    let trench = plan.dig_trench();
-    let hole_size = trench.get_excavation_size();
-    println!("Part 2: {}", hole_size);
-}
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let plan = DigPlan::parse(lines, false);
+        let trench = plan.dig_trench();
+        let hole_size = trench.get_excavation_size();
+        hole_size.into()
+    }
 
-    println!("Done!");
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let plan = DigPlan::parse(lines, true);
+        let trench = plan.dig_trench();
+        let hole_size = trench.get_excavation_size();
+        hole_size.into()
+    }
 }
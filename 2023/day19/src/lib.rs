@@ -1,16 +1,9 @@
-use std::collections::HashMap;
-use std::fmt::{Display, Write};
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
+#![allow(clippy::needless_range_loop, clippy::redundant_closure)]
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use std::collections::HashMap;
+use aoc_common::input::lines;
+use aoc_common::interval::{Interval, Region};
+use aoc_common::{Answer, Solution};
 
 const X: usize = 0;
 const M: usize = 1;
@@ -27,12 +20,6 @@ enum Result {
     Next
 }
 
-#[derive(Clone, Debug)]
-enum Requirement {
-    Greater(usize, u32),
-    Less(usize, u32)
-}
-
 enum Operation {
     Greater(usize, u32, Result),
     Less(usize, u32, Result),
@@ -122,56 +109,10 @@ impl Workflow {
     }
 }
 
-#[derive(Clone, Debug)]
-struct Range {
-    from: u32,
-    to: u32
-}
+/// Every combination of ratings from `MIN` to `MAX` in each category.
+type Parts = Region<u32, 4>;
 
-impl Range {
-    fn new(from: u32, to: u32) -> Range {
-        Range { from, to }
-    }
-    fn full() -> Range {
-        Range::new(MIN, MAX)
-    }
-    fn merge(&mut self, other: &Range) -> bool {
-        if self.from > other.to || self.to < other.from {
-            false
-        } else {
-            self.to = self.to.min(other.to);
-            self.from = self.from.max(other.from);
-            true
-        }
-    }
-    fn count(&self) -> u32 {
-        (self.to - self.from) + 1
-    }
-}
-
-#[derive(Debug)]
-struct PartSpec {
-    components: Vec<Range>
-}
-
-impl PartSpec {
-    fn new() -> PartSpec {
-        PartSpec {
-            components: vec![Range::full(); 4]
-        }
-    }
-    fn merge(&mut self, component: usize, range: &Range) -> bool {
-        let current = &mut self.components[component];
-        current.merge(range)
-    }
-    fn compute_valid_parts(&self) -> u64 {
-        self.components.iter()
-            .map(|range| range.count() as u64)
-            .product::<u64>()
-    }
-}
-
-struct Evaluator {
+pub struct Evaluator {
     start: usize,
     series: Vec<Workflow>,
     lookup: HashMap<String, usize>,
@@ -204,90 +145,54 @@ impl Evaluator {
         }
     }
 
-    fn add_requirements(
-        &self, 
-        orig_len: usize, 
-        result: &Result, 
-        requirements: &mut Vec<Requirement>, 
-        acceptable_specs: &mut Vec<PartSpec>,
-        workflow: &String) 
-    {
-        match result {
-            Result::Accept => Evaluator::merge_requirements(requirements, acceptable_specs, workflow),
-            Result::Reject => (),
-            Result::GoTo(next) => self.gather_accept_requirements(next, requirements, acceptable_specs),
-            _ => panic!("Invalid result encountered")
-        }
-        while requirements.len() > orig_len {
-            requirements.pop().unwrap();
-        }
-    }
+    /// How many of `parts` are accepted if they go through `workflow` and
+    /// what it leads to, splitting them up wherever a step's rule does.
+    fn count_accepted(&self, workflow: &str, parts: Parts) -> u64 {
+        let workflow = &self.series[self.lookup[workflow]];
 
-    fn gather_accept_requirements(
-        &self, 
-        workflow: &String, 
-        requirements: &mut Vec<Requirement>, 
-        acceptable_specs: &mut Vec<PartSpec>) 
-    {
-        let workflow_str = workflow;
-        let workflow = *self.lookup.get(workflow).unwrap();
-        let workflow = &self.series[workflow];
+        let mut accepted = 0;
+        let mut remaining = Some(parts);
         for step in workflow.steps.iter() {
-            let orig_len = requirements.len();
-            match step {
+            let parts = match remaining {
+                Some(parts) => parts,
+                None => break
+            };
+            let (matching, result, rest) = match step {
                 Operation::Greater(component, amount, result) => {
-                    requirements.push(Requirement::Greater(*component, *amount));
-                    self.add_requirements(orig_len, result, requirements, acceptable_specs, workflow_str);
-                    requirements.push(Requirement::Less(*component, *amount + 1));
+                    let (rest, matching) = parts.split_at(*component, *amount + 1);
+                    (matching, result, rest)
                 },
                 Operation::Less(component, amount, result) => {
-                    requirements.push(Requirement::Less(*component, *amount));
-                    self.add_requirements(orig_len, result, requirements, acceptable_specs, workflow_str);
-                    requirements.push(Requirement::Greater(*component, *amount - 1));
+                    let (matching, rest) = parts.split_at(*component, *amount);
+                    (matching, result, rest)
                 },
-                Operation::Result(result) => {
-                    self.add_requirements(orig_len, result, requirements, acceptable_specs, workflow_str);
-                    return;
-                }
+                Operation::Result(result) => (Some(parts), result, None)
+            };
+            if let Some(matching) = matching {
+                accepted += self.count_result(result, matching);
             }
+            remaining = rest;
         }
+        accepted
     }
 
-    fn merge_requirements(requirements: &Vec<Requirement>, acceptable_specs: &mut Vec<PartSpec>, workflow: &String) {
-        let mut spec = PartSpec::new();
-
-        for requirement in requirements {
-            let (component, range) = match requirement {
-                Requirement::Greater(component, amount) => {
-                    (component, Range::new(*amount + 1, MAX))
-                },
-                Requirement::Less(component, amount) => {
-                    (component, Range::new(MIN, *amount - 1))
-                }
-            };
-
-            if !spec.merge(*component, &range) {
-                return;
-            }
-
+    fn count_result(&self, result: &Result, parts: Parts) -> u64 {
+        match result {
+            Result::Accept => parts.volume(),
+            Result::Reject => 0,
+            Result::GoTo(next) => self.count_accepted(next, parts),
+            Result::Next => panic!("Next is not applicable here")
         }
-        acceptable_specs.push(spec);
     }
 
     fn count_acceptable_parts(&self) -> u64 {
-        let mut acceptable_specs = Vec::new();
-        let mut requirements = Vec::new();
-        self.gather_accept_requirements(&"in".to_owned(), &mut requirements, &mut acceptable_specs);
-        acceptable_specs.into_iter()
-            .map(|spec| {
-                spec.compute_valid_parts()
-            })
-            .sum::<u64>()
+        let all = Region::new([Interval::new(MIN, MAX); 4]);
+        self.count_accepted("in", all)
     }
 }
 
 #[derive(Debug)]
-struct Part {
+pub struct Part {
     components: Vec<u32>
 }
 
@@ -319,9 +224,8 @@ impl Part {
     }
 }
 
-fn parse_workflows_and_parts(file_name: &str) -> (Evaluator, Vec<Part>) {
-    let mut lines = get_file_lines(file_name)
-        .flat_map(|line| line.ok());
+fn parse_workflows_and_parts(input: &str) -> (Evaluator, Vec<Part>) {
+    let mut lines = lines(input).into_iter();
 
     let workflows = (&mut lines).take_while(|line| !line.is_empty())
         .map(Workflow::parse)
@@ -333,26 +237,26 @@ fn parse_workflows_and_parts(file_name: &str) -> (Evaluator, Vec<Part>) {
     (Evaluator::new(workflows), parts)
 }
 
-fn part_one(file_name: &str) {
-    let (evaluator, parts) = parse_workflows_and_parts(file_name);
+pub struct Day;
 
-    let total = parts.iter()
-        .filter(|part| evaluator.accept(part))
-        .map(|part| part.sum())
-        .sum::<u32>();
+impl Solution for Day {
+    type Parsed = (Evaluator, Vec<Part>);
 
-    println!("Part 1: {}", total);
-}
+    fn parse(input: &str) -> aoc_common::Result<Self::Parsed> {
+        Ok(parse_workflows_and_parts(input))
+    }
 
-fn part_two(file_name: &str) {
-    let (evaluator, _) = parse_workflows_and_parts(file_name);
-    let acceptable = evaluator.count_acceptable_parts();
-    println!("Part 2: {}", acceptable);
-}
+    fn part_one((evaluator, parts): &Self::Parsed) -> Answer {
+        let total = parts.iter()
+            .filter(|part| evaluator.accept(part))
+            .map(|part| part.sum())
+            .sum::<u32>();
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+        total.into()
+    }
 
-    println!("Done!");
+    fn part_two((evaluator, _): &Self::Parsed) -> Answer {
+        let acceptable = evaluator.count_acceptable_parts();
+        acceptable.into()
+    }
 }
@@ -1,8 +1,5 @@
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 #[derive(Debug)]
 struct Draw {
@@ -50,7 +47,7 @@ impl Draw {
 }
 
 impl Game {
-    fn parse(line: String) -> Game {
+    fn parse(line: &str) -> Game {
         let id_end = line.find(":").unwrap();
         let id_start = line.find(" ").unwrap() + 1;
         let id = line[id_start..id_end].parse::<usize>().unwrap();
@@ -71,36 +68,31 @@ impl Game {
     }
 }
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+pub struct Day;
 
-fn part_one(file_name: &str) {
-    let result = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| Game::parse(line))
-        .filter(|game| game.is_possible(12, 13, 14))
-        .map(|game| game.id)
-        .sum::<usize>();
-    
-    println!("Part 1: {}", result);
-}
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-fn part_two(file_name: &str) {
-    let result = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| Game::parse(line))
-        .map(|game| game.get_power())
-        .sum::<u32>();
-    
-    println!("Part 2: {}", result);
-}
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let result = lines.iter()
+            .map(|line| Game::parse(line))
+            .filter(|game| game.is_possible(12, 13, 14))
+            .map(|game| game.id)
+            .sum::<usize>();
 
-    println!("Done!");
+        result.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let result = lines.iter()
+            .map(|line| Game::parse(line))
+            .map(|game| game.get_power())
+            .sum::<u32>();
+
+        result.into()
+    }
 }
@@ -1,16 +1,7 @@
 use std::collections::HashMap;
-use std::fs::File;
 use std::mem::swap;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 #[derive(PartialEq)]
 enum Function {
@@ -136,19 +127,18 @@ impl State {
     }
 }
 
-struct Network {
+pub struct Network {
     modules: Vec<Module>,
     broadcast_ids: Vec<usize>,
     broadcaster: usize
 }
 
 impl Network {
-    fn parse(file_name: &str) -> Network {
+    fn parse(input: &str) -> Network {
         let mut modules = Vec::new();
         let mut name_to_id = HashMap::new();
 
-        get_file_lines(file_name)
-            .flat_map(|line| line.ok())
+        lines(input).into_iter()
             .for_each(|line| {
                 let id = modules.len();
                 let module = Module::parse(line, modules.len());
@@ -198,7 +188,7 @@ impl Network {
         let broadcast_ids = broadcaster.destination_ids.to_vec();
         let broadcaster = broadcaster.id;
 
-        Network { modules, broadcast_ids, broadcaster: broadcaster }
+        Network { modules, broadcast_ids, broadcaster }
     }
 
     fn push_button(&self, state: &mut State) {
@@ -226,31 +216,32 @@ impl Network {
     }
 }
 
-fn part_one(file_name: &str) {
-    let network = Network::parse(file_name);
-    let count = network.push_button_multi(1000);
-    println!("Part 1: {}", count);
-}
+pub struct Day;
 
-fn part_two(file_name: &str) {
-    let network = Network::parse(file_name);
-    let wait_for_id = network.modules.iter()
-        .find(|module| module.name == "rx")
-        .unwrap()
-        .id;
+impl Solution for Day {
+    type Parsed = Network;
 
-    let mut state = State::new(network.modules.len());
-    let mut pushes = 0;
-    while state.low_pulse_count[wait_for_id] == 0 {
-        network.push_button(&mut state);
-        pushes += 1;
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Network::parse(input))
     }
-    println!("Part 2: {}", pushes);
-}
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn part_one(network: &Self::Parsed) -> Answer {
+        let count = network.push_button_multi(1000);
+        count.into()
+    }
 
-    println!("Done!");
+    fn part_two(network: &Self::Parsed) -> Answer {
+        let wait_for_id = network.modules.iter()
+            .find(|module| module.name == "rx")
+            .unwrap()
+            .id;
+
+        let mut state = State::new(network.modules.len());
+        let mut pushes = 0;
+        while state.low_pulse_count[wait_for_id] == 0 {
+            network.push_button(&mut state);
+            pushes += 1;
+        }
+        pushes.into()
+    }
 }
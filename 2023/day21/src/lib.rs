@@ -0,0 +1,224 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::mem::swap;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Garden {
+    rocks: HashSet<(i32, i32)>,
+    start: (i32, i32),
+    height: i32,
+    width: i32
+}
+
+impl Garden {
+    fn parse(input: &str) -> Garden {
+        let mut y = 0;
+        let mut start = (0, 0);
+        let mut width = 0;
+        let rocks = lines(input).into_iter()
+            .flat_map(|line| {
+                let mut x = 0;
+                let cur_y = y;
+                y += 1;
+                width = line.len() as i32;
+                line.chars()
+                    .flat_map(|c| {
+                        let cur_x = x;
+                        x += 1;
+                        match c {
+                            '.' => None,
+                            '#' => Some((cur_x, cur_y)),
+                            'S' => {
+                                start = (cur_x, cur_y);
+                                None
+                            },
+                            _ => panic!("Invalid character: {}", c)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<HashSet<_>>();
+
+        Garden { rocks, start, width, height: y }
+    }
+
+    fn count_reachable_plots(&self, steps: usize) -> usize {
+        let mut current = HashSet::new();
+        let mut next = HashSet::new();
+
+        current.insert(self.start);
+
+        for _ in 0..steps {
+            for (x, y) in &current {
+                let consider = vec![
+                    (x - 1, *y),
+                    (x + 1, *y),
+                    (*x, y - 1),
+                    (*x, y + 1)
+                ];
+
+                consider.into_iter()
+                    .filter(|(x, y)| {
+                        *x >= 0 && *x < self.width && *y >= 0 && *y < self.height
+                    })
+                    .filter(|point| !self.rocks.contains(point))
+                    .for_each(|point| { next.insert(point); });
+            }
+            swap(&mut current, &mut next);
+            next.clear();
+        }
+
+        current.len()
+    }
+
+    fn is_rock_tiled(&self, (x, y): (i32, i32)) -> bool {
+        self.rocks.contains(&(x.rem_euclid(self.width), y.rem_euclid(self.height)))
+    }
+
+    /// Fewest steps to every plot within `max_steps` of the start, with the
+    /// map repeating forever in every direction.
+    fn tiled_distances(&self, max_steps: usize) -> HashMap<(i32, i32), usize> {
+        let mut distances = HashMap::new();
+        let mut queue = VecDeque::new();
+
+        distances.insert(self.start, 0);
+        queue.push_back((self.start, 0));
+
+        while let Some(((x, y), steps)) = queue.pop_front() {
+            if steps == max_steps {
+                continue;
+            }
+            for point in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+                if !self.is_rock_tiled(point) && !distances.contains_key(&point) {
+                    distances.insert(point, steps + 1);
+                    queue.push_back((point, steps + 1));
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// A plot reached in fewer steps can still be ended on by stepping back
+    /// and forth, as long as the steps left over are even.
+    fn count_within(distances: &HashMap<(i32, i32), usize>, steps: usize) -> usize {
+        distances.values()
+            .filter(|distance| **distance <= steps && **distance % 2 == steps % 2)
+            .count()
+    }
+
+    /// Brute force over the tiled map, fine for small step counts.
+    pub fn count_reachable_tiled(&self, steps: usize) -> usize {
+        Self::count_within(&self.tiled_distances(steps), steps)
+    }
+
+    /// The tiled count for any number of steps. Every `width` more steps the
+    /// reachable diamond grows by another ring of map copies, so on a square
+    /// map the count after `remainder + n * width` steps is eventually
+    /// quadratic in `n`. Counts are brute-forced for growing `n` until their
+    /// second difference settles, and the quadratic gives the rest. `None`
+    /// if the map isn't square, as nothing then repeats every `width` steps.
+    pub fn count_reachable_extrapolated(&self, steps: usize) -> Option<usize> {
+        if self.width != self.height {
+            return None;
+        }
+        let size = self.width as usize;
+        let remainder = steps % size;
+        let target = (steps / size) as i128;
+
+        let mut samples = 4;
+        while remainder + samples * size < steps {
+            let distances = self.tiled_distances(remainder + samples * size);
+            let counts = (0..=samples)
+                .map(|n| Self::count_within(&distances, remainder + n * size) as i128)
+                .collect::<Vec<_>>();
+            let second = |n: usize| counts[n + 2] - 2 * counts[n + 1] + counts[n];
+
+            // the last sample where the second difference held for two steps
+            if let Some(n) = (0..samples - 2).rev().find(|&n| second(n) == second(n + 1)) {
+                let from = target - n as i128;
+                let first = counts[n + 1] - counts[n];
+                return Some((counts[n] + from * first + from * (from - 1) / 2 * second(n)) as usize);
+            }
+            samples *= 2;
+        }
+
+        // too few steps to extrapolate from, so brute force costs no more
+        Some(self.count_reachable_tiled(steps))
+    }
+
+    /// Whether the extrapolated count after `steps` agrees with brute force.
+    /// Only for step counts small enough to brute-force.
+    pub fn cross_check(&self, steps: usize) -> bool {
+        self.count_reachable_extrapolated(steps) == Some(self.count_reachable_tiled(steps))
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Garden;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Garden::parse(input))
+    }
+
+    fn part_one(garden: &Self::Parsed) -> Answer {
+        let reachable = garden.count_reachable_plots(64);
+        reachable.into()
+    }
+
+    fn part_two(garden: &Self::Parsed) -> Answer {
+        match garden.count_reachable_extrapolated(26501365) {
+            Some(reachable) => reachable.into(),
+            None => Answer::Unsolved
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "...........\n.....###.#.\n.###.##..#.\n..#.#...#..\n....#.#....\n.##..S####.\n.##..#...#.\n.......##..\n.##.#.####.\n.##..##.##.\n...........\n";
+
+    #[test]
+    fn counts_tiled_sample() {
+        let garden = Garden::parse(SAMPLE);
+        assert_eq!(16, garden.count_reachable_plots(6));
+        for (steps, expected) in [(6, 16), (10, 50), (50, 1594), (100, 6536), (500, 167004)] {
+            assert_eq!(expected, garden.count_reachable_tiled(steps), "{} steps", steps);
+        }
+        assert_eq!(Some(668697), garden.count_reachable_extrapolated(1000));
+        assert_eq!(Some(16733044), garden.count_reachable_extrapolated(5000));
+    }
+
+    #[test]
+    fn extrapolation_matches_brute_force() {
+        // like the real input: clear border and clear row and column through the start
+        let garden = Garden::parse("\
+            .........\n\
+            .##...#..\n\
+            ..#...##.\n\
+            .#.......\n\
+            ....S....\n\
+            ...#...#.\n\
+            .##...#..\n\
+            ....#..#.\n\
+            .........\n");
+        for steps in [4 + 9 * 3, 4 + 9 * 5, 4 + 9 * 8, 7 + 9 * 6, 4 + 9 * 20] {
+            assert!(garden.cross_check(steps), "{} steps", steps);
+        }
+    }
+
+    #[test]
+    fn gives_up_on_a_map_that_isnt_square() {
+        let garden = Garden::parse("...
+.S.
+...
+...
+");
+        assert_eq!(None, garden.count_reachable_extrapolated(100));
+        assert_eq!(Answer::Unsolved, Day::part_two(&garden));
+    }
+}
@@ -1,42 +1,22 @@
+#![allow(clippy::redundant_closure)]
+
 use std::collections::{BTreeMap, HashMap, HashSet};
-use std::fs::File;
 use std::iter::FromIterator;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
-
-#[derive(Clone)]
-struct Range {
-    from: i32,
-    to: i32
-}
-
-impl Range {
-    fn new(a: i32, b: i32) -> Range {
-        Range {
-            from: a.min(b),
-            to: a.max(b)
-        }
-    }
-    fn new_from(&mut self, new_from: i32) {
-        let diff = self.from - new_from;
-        self.from = new_from;
-        self.to -= diff;
-    }
+use aoc_common::input::lines;
+use aoc_common::interval::Interval;
+use aoc_common::{Answer, Result, Solution};
+
+/// The coordinates between two ends of a block, whichever way round
+/// they're given.
+fn span(a: i32, b: i32) -> Interval<i32> {
+    Interval::new(a.min(b), a.max(b))
 }
 
 #[derive(Clone)]
 struct Block {
-    x: Range,
-    y: Range,
-    z: Range
+    x: Interval<i32>,
+    y: Interval<i32>,
+    z: Interval<i32>
 }
 
 impl Block {
@@ -49,15 +29,16 @@ impl Block {
             .map(|number| number.parse::<i32>().unwrap())
             .collect::<Vec<_>>();
 
-        let x = Range::new(start[0], end[0]);
-        let y = Range::new(start[1], end[1]);
-        let z = Range::new(start[2], end[2]);
+        let x = span(start[0], end[0]);
+        let y = span(start[1], end[1]);
+        let z = span(start[2], end[2]);
 
         Block { x, y, z }
     }
 }
 
-struct BlockPile {
+#[derive(Clone)]
+pub struct BlockPile {
     xy_to_block: HashMap<(i32, i32), Vec<usize>>,
     block_to_points: Vec<Vec<(i32, i32)>>,
     blocks: Vec<Block>,
@@ -65,7 +46,7 @@ struct BlockPile {
 }
 
 impl BlockPile {
-    fn parse(file_name: &str) -> BlockPile {
+    fn parse(input: &str) -> BlockPile {
         let mut pile = BlockPile { 
             xy_to_block: HashMap::new(),
             block_to_points: Vec::new(),
@@ -73,8 +54,7 @@ impl BlockPile {
             blocks: Vec::new()
         };
 
-        get_file_lines(file_name)
-            .flat_map(|line| line.ok())
+        lines(input).into_iter()
             .map(|line| Block::parse(line))
             .for_each(|block| pile.add_block(block));
 
@@ -85,24 +65,24 @@ impl BlockPile {
         let block_id = self.blocks.len();
         let mut points = Vec::new();
 
-        for x in block.x.from..block.x.to + 1 {
-            for y in block.y.from..block.y.to + 1 {
+        for x in block.x.range() {
+            for y in block.y.range() {
                 points.push((x, y));
             }
         }
 
         points.iter().for_each(|point| {
-            if let Some(column) = self.xy_to_block.get_mut(&point) {
+            if let Some(column) = self.xy_to_block.get_mut(point) {
                 column.push(block_id);
             } else {
                 self.xy_to_block.insert(*point, vec![block_id]);
             }
         });
 
-        if let Some(blocks) = self.z_to_block.get_mut(&block.z.from) {
+        if let Some(blocks) = self.z_to_block.get_mut(&block.z.start()) {
             blocks.push(block_id);
         } else {
-            self.z_to_block.insert(block.z.from, vec![block_id]);
+            self.z_to_block.insert(block.z.start(), vec![block_id]);
         }
 
         self.block_to_points.push(points);
@@ -118,7 +98,7 @@ impl BlockPile {
                     let below = points.iter()
                         .flat_map(|point| self.xy_to_block.get(point).unwrap())
                         .filter(|check_block_id| **check_block_id != block_id)
-                        .map(|block_id| self.blocks[*block_id].z.to)
+                        .map(|block_id| self.blocks[*block_id].z.end())
                         .filter(|test_z| *test_z < z)
                         .max()
                         .unwrap_or(0);
@@ -132,7 +112,8 @@ impl BlockPile {
                         self.z_to_block.insert(new_z_bottom, vec![block_id]);
                     }
 
-                    self.blocks[block_id].z.new_from(new_z_bottom);
+                    let z = &mut self.blocks[block_id].z;
+                    *z = z.with_start(new_z_bottom);
                 }
             }
         }
@@ -140,7 +121,7 @@ impl BlockPile {
 
     fn count_independent(&self) -> usize {
         let max = self.blocks.iter()
-            .map(|block| block.z.to)
+            .map(|block| block.z.end())
             .max()
             .unwrap();
 
@@ -152,8 +133,8 @@ impl BlockPile {
         }
 
         for block_id in 0..self.blocks.len() {
-            let top = self.blocks[block_id].z.to;
-            let bottom = self.blocks[block_id].z.from;
+            let top = self.blocks[block_id].z.end();
+            let bottom = self.blocks[block_id].z.start();
             z_top_to_blocks[top as usize].insert(block_id);
             z_bottom_to_blocks[bottom as usize].insert(block_id);
         }
@@ -169,7 +150,7 @@ impl BlockPile {
                     .copied()
                     .collect::<HashSet<_>>();
                 
-                (&mut key_is_supported_by).insert(*block, Vec::from_iter(supporting.into_iter()));
+                key_is_supported_by.insert(*block, Vec::from_iter(supporting));
             }
         }
 
@@ -177,7 +158,7 @@ impl BlockPile {
 
         for (above, below_blocks) in key_is_supported_by.iter() {
             for below in below_blocks {
-                if let Some(list) = key_is_supporting.get_mut(&below) {
+                if let Some(list) = key_is_supporting.get_mut(below) {
                     list.push(*above);
                 } else {
                     key_is_supporting.insert(*below, vec![*above]);
@@ -206,7 +187,7 @@ impl BlockPile {
     
     fn sum_fallen_if_dissolved(&self) -> usize {
         let max = self.blocks.iter()
-            .map(|block| block.z.to)
+            .map(|block| block.z.end())
             .max()
             .unwrap();
 
@@ -218,8 +199,8 @@ impl BlockPile {
         }
 
         for block_id in 0..self.blocks.len() {
-            let top = self.blocks[block_id].z.to;
-            let bottom = self.blocks[block_id].z.from;
+            let top = self.blocks[block_id].z.end();
+            let bottom = self.blocks[block_id].z.start();
             z_top_to_blocks[top as usize].insert(block_id);
             z_bottom_to_blocks[bottom as usize].insert(block_id);
         }
@@ -235,7 +216,7 @@ impl BlockPile {
                     .copied()
                     .collect::<HashSet<_>>();
                 
-                (&mut key_is_supported_by).insert(*block, Vec::from_iter(supporting.into_iter()));
+                key_is_supported_by.insert(*block, Vec::from_iter(supporting));
             }
         }
 
@@ -243,7 +224,7 @@ impl BlockPile {
 
         for (above, below_blocks) in key_is_supported_by.iter() {
             for below in below_blocks {
-                if let Some(list) = key_is_supporting.get_mut(&below) {
+                if let Some(list) = key_is_supporting.get_mut(below) {
                     list.push(*above);
                 } else {
                     key_is_supporting.insert(*below, vec![*above]);
@@ -282,23 +263,26 @@ impl BlockPile {
     }
 }
 
-fn part_one(file_name: &str) {
-    let mut pile = BlockPile::parse(file_name);
-    pile.drop_blocks();
-    let count = pile.count_independent();
-    println!("Part 1: {}", count);
-}
+pub struct Day;
 
-fn part_two(file_name: &str) {
-    let mut pile = BlockPile::parse(file_name);
-    pile.drop_blocks();
-    let total = pile.sum_fallen_if_dissolved();
-    println!("Part 2: {}", total);
-}
+impl Solution for Day {
+    type Parsed = BlockPile;
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(BlockPile::parse(input))
+    }
+
+    fn part_one(pile: &Self::Parsed) -> Answer {
+        let mut pile = pile.clone();
+        pile.drop_blocks();
+        let count = pile.count_independent();
+        count.into()
+    }
 
-    println!("Done!");
+    fn part_two(pile: &Self::Parsed) -> Answer {
+        let mut pile = pile.clone();
+        pile.drop_blocks();
+        let total = pile.sum_fallen_if_dissolved();
+        total.into()
+    }
 }
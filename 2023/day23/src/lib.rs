@@ -1,19 +1,11 @@
+#![allow(clippy::len_zero)]
+
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
 use std::mem::swap;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
-
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
-struct Forest {
+pub struct Forest {
     trails: HashMap<(i32, i32), (i32, i32)>,
     end: (i32, i32)
 }
@@ -60,10 +52,9 @@ impl Visited {
 }
 
 impl Forest {
-    fn parse(file_name: &str) -> Forest {
+    fn parse(input: &str) -> Forest {
         let mut y = 0;
-        let trails = get_file_lines(file_name)
-            .flat_map(|line| line.ok())
+        let trails = lines(input).into_iter()
             .flat_map(|line| {
                 let mut x = 0;
                 let points = line.chars()
@@ -134,10 +125,7 @@ impl Forest {
             // and haven't been visited yet
             let mut next_points = neighbors.into_iter()
                 .filter(|point| !visited.contains(point))
-                .filter_map(|point| match self.trails.get(&point) {
-                    Some(slope) => Some((point, slope, Vec::new())),
-                    None => None
-                })
+                .filter_map(|point| self.trails.get(&point).map(|slope| (point, slope, Vec::new())))
                 .collect::<Vec<_>>();
 
             // println!("From ({}, {}), next points are: {:?}", x, y, next_points);
@@ -295,21 +283,22 @@ impl Forest {
     }
 }
 
-fn part_one(file_name: &str) {
-    let forest = Forest::parse(file_name);
-    let longest_trail = forest.find_longest_trail_slopes((1, 0), HashSet::new()) - 1;
-    println!("Part 1: {}", longest_trail);
-}
+pub struct Day;
 
-fn part_two(file_name: &str) {
-    let forest = Forest::parse(file_name);
-    let longest_trail = forest.find_longest_trail_no_slopes((1, 0), &mut Visited::new()) - 1;
-    println!("Part 2: {}", longest_trail);
-}
+impl Solution for Day {
+    type Parsed = Forest;
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Forest::parse(input))
+    }
 
-    println!("Done!");
+    fn part_one(forest: &Self::Parsed) -> Answer {
+        let longest_trail = forest.find_longest_trail_slopes((1, 0), HashSet::new()) - 1;
+        longest_trail.into()
+    }
+
+    fn part_two(forest: &Self::Parsed) -> Answer {
+        let longest_trail = forest.find_longest_trail_no_slopes((1, 0), &mut Visited::new()) - 1;
+        longest_trail.into()
+    }
 }
@@ -0,0 +1,226 @@
+#![allow(clippy::needless_range_loop)]
+
+use std::collections::HashSet;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+struct Vector {
+    x: i64,
+    y: i64,
+    z: i64
+}
+
+impl Vector {
+    fn new(x: i64, y: i64, z: i64) -> Vector {
+        Vector { x, y, z }
+    }
+    fn is_xy_same(&self, other: &Vector) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+    fn wide(&self) -> [i128; 3] {
+        [self.x as i128, self.y as i128, self.z as i128]
+    }
+}
+
+pub struct Hailstone {
+    position: Vector,
+    velocity: Vector
+}
+
+impl Hailstone {
+    fn parse(line: &str) -> Hailstone {
+        let mut parts = line.split(" @ ");
+        let positions = parts.next().unwrap()
+            .split(',')
+            .map(|number| number.trim().parse::<i64>().unwrap())
+            .collect::<Vec<_>>();
+        let velocities = parts.next().unwrap()
+            .split(',')
+            .map(|number| number.trim().parse::<i64>().unwrap())
+            .collect::<Vec<_>>();
+
+        let position = Vector::new(positions[0], positions[1], positions[2]);
+        let velocity = Vector::new(velocities[0], velocities[1], velocities[2]);
+
+        Hailstone { position, velocity }
+    }
+
+    fn find_xy_intersection(&self, other: &Hailstone) -> Option<(f64, f64)> {
+        if self.velocity.is_xy_same(&other.velocity) {
+            if self.position.is_xy_same(&other.position) {
+                None
+            } else {
+                Some((self.position.x as f64, self.position.y as f64))
+            }
+        } else {
+            // xX + yY = aA + bB
+            // 
+            // from 7 to 27
+            // Hailstone A: 19, 13, 30 @ -2, 1, -2
+            // Hailstone B: 18, 19, 22 @ -1, -1, -2
+            // Hailstones' paths will cross inside the test area (at x=14.333, y=15.333).
+            // 
+            // 19 + -2x = 18 + -1x
+            // 19 + x = 18
+            // x = -1
+            //
+            // 13 + y = 19 -y
+            // 13 + 2y = 19
+            // 2y = 19 - 13
+            // 2y = 6
+            // y = 3
+            // 
+            // 
+            None
+        }
+    }
+}
+
+fn count_intersections(hailstones: &[Hailstone]) -> usize {
+    let mut intersections = HashSet::new();
+
+    for i in 0..hailstones.len() {
+        let left = &hailstones[i];
+        for j in i + 1..hailstones.len() {
+            let right = &hailstones[j];
+            if let Some((_x, _y)) = left.find_xy_intersection(right) {
+                intersections.insert(i);
+                intersections.insert(j);
+            }
+        }
+    }
+
+    intersections.len()
+}
+
+type Vec3 = [i128; 3];
+
+fn sub(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn add_scaled(a: Vec3, b: Vec3, scale: i128) -> Vec3 {
+    [a[0] + b[0] * scale, a[1] + b[1] * scale, a[2] + b[2] * scale]
+}
+
+fn cross(a: Vec3, b: Vec3) -> Vec3 {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+fn dot(a: Vec3, b: Vec3) -> i128 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn exact_div(a: i128, b: i128) -> Option<i128> {
+    match b != 0 && a % b == 0 {
+        true => Some(a / b),
+        false => None
+    }
+}
+
+/// The position and velocity of a rock thrown so it hits every hailstone,
+/// if there's one with integer position, velocity and hit times.
+///
+/// Seen from hailstone `a`, which then sits still at the origin, the rock
+/// passes through the origin. To also hit hailstone `b` its path has to lie
+/// in the plane through the origin and `b`'s path, so the times two more
+/// hailstones cross that plane are when the rock hits them, which pins down
+/// the rock's path. Everything stays in exact integers: the largest values are
+/// a position dotted with a position crossed with a velocity.
+fn find_rock(hailstones: &[Hailstone]) -> Option<(Vec3, Vec3)> {
+    let (a, rest) = hailstones.split_first()?;
+    let (a_position, a_velocity) = (a.position.wide(), a.velocity.wide());
+
+    // relative to hailstone a
+    let relative = rest.iter()
+        .map(|stone| (sub(stone.position.wide(), a_position), sub(stone.velocity.wide(), a_velocity)))
+        .collect::<Vec<_>>();
+
+    for (b, &(b_position, b_velocity)) in relative.iter().enumerate() {
+        let normal = cross(b_position, b_velocity);
+        if normal == [0; 3] {
+            continue;
+        }
+
+        // when and where the other hailstones cross the plane
+        let crossings = relative.iter()
+            .enumerate()
+            .filter(|(other, _)| *other != b)
+            .flat_map(|(_, &(position, velocity))| {
+                let time = exact_div(-dot(position, normal), dot(velocity, normal))?;
+                Some((time, add_scaled(position, velocity, time)))
+            });
+
+        let mut first = None;
+        for (time, hit) in crossings {
+            let Some((first_time, first_hit)) = first else {
+                first = Some((time, hit));
+                continue;
+            };
+            if time == first_time {
+                continue;
+            }
+
+            let step = sub(hit, first_hit);
+            let velocity = [
+                exact_div(step[0], time - first_time),
+                exact_div(step[1], time - first_time),
+                exact_div(step[2], time - first_time)
+            ];
+            let [Some(x), Some(y), Some(z)] = velocity else {
+                break;
+            };
+            let velocity = [x, y, z];
+            let position = add_scaled(first_hit, velocity, -first_time);
+
+            // back to where everything really is at time 0
+            let rock = (add_scaled(position, a_position, 1), add_scaled(velocity, a_velocity, 1));
+            if hailstones.iter().all(|stone| hits(rock, stone)) {
+                return Some(rock);
+            }
+            break;
+        }
+    }
+
+    None
+}
+
+/// Whether the rock reaches the hailstone's position at the same whole
+/// time, not before the throw, as the hailstone does.
+fn hits((position, velocity): (Vec3, Vec3), stone: &Hailstone) -> bool {
+    let gap = sub(stone.position.wide(), position);
+    let closing = sub(velocity, stone.velocity.wide());
+
+    if cross(gap, closing) != [0; 3] {
+        return false;
+    }
+    match (0..3).find(|&axis| closing[axis] != 0) {
+        Some(axis) => exact_div(gap[axis], closing[axis]).is_some_and(|time| time >= 0),
+        None => gap == [0; 3]
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Hailstone>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input).iter()
+            .map(|line| Hailstone::parse(line))
+            .collect())
+    }
+
+    fn part_one(hailstones: &Self::Parsed) -> Answer {
+        let intersections = count_intersections(hailstones);
+
+        intersections.into()
+    }
+
+    fn part_two(hailstones: &Self::Parsed) -> Answer {
+        match find_rock(hailstones) {
+            Some((position, _)) => (position[0] + position[1] + position[2]).into(),
+            None => Answer::Unsolved
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use aoc_common::{Answer, Error, Result, Solution};
+
+pub struct Modules {
+    // undirected: every wire is listed under both of its components
+    connections: HashMap<String, Vec<String>>
+}
+
+impl Modules {
+    fn parse(input: &str) -> Result<Modules> {
+        let mut connections: HashMap<String, Vec<String>> = HashMap::new();
+
+        for (index, line) in input.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let (from, to) = line.split_once(": ")
+                .ok_or_else(|| Error::parse(index + 1, format!("expected `<component>: <component> ...`, found `{}`", line)))?;
+
+            for to in to.split_whitespace() {
+                connections.entry(from.to_owned()).or_default().push(to.to_owned());
+                connections.entry(to.to_owned()).or_default().push(from.to_owned());
+            }
+        }
+
+        Ok(Modules { connections })
+    }
+
+    /// Stoer-Wagner: the components on one side of a minimum cut. If the
+    /// wiring is already in pieces, one of the pieces is returned instead.
+    fn min_cut(&self) -> HashSet<&str> {
+        let names = self.connections.keys().map(String::as_str).collect::<Vec<_>>();
+        let index = names.iter()
+            .enumerate()
+            .map(|(index, name)| (*name, index))
+            .collect::<HashMap<_, _>>();
+
+        // each vertex starts as one component; merged vertices add up their wires
+        let mut edges = names.iter()
+            .map(|name| {
+                let mut weights = HashMap::new();
+                for to in &self.connections[*name] {
+                    *weights.entry(index[to.as_str()]).or_insert(0) += 1;
+                }
+                weights
+            })
+            .collect::<Vec<HashMap<usize, u32>>>();
+        let mut members = (0..names.len()).map(|vertex| vec![vertex]).collect::<Vec<_>>();
+        let mut merged = vec![false; names.len()];
+
+        let mut best = (u32::MAX, Vec::new());
+
+        for phase in 1..names.len() {
+            // grow a set from the first vertex, always adding the vertex most
+            // tightly connected to it; the last two added are merged afterwards
+            let start = merged.iter().position(|merged| !merged).unwrap();
+            let mut weights = vec![0; names.len()];
+            let mut added = vec![false; names.len()];
+            let mut heap = BinaryHeap::new();
+            heap.push((0, start));
+
+            let mut previous = start;
+            let mut last = start;
+            let mut count = 0;
+            let remaining = names.len() - phase + 1;
+
+            while let Some((weight, vertex)) = heap.pop() {
+                if added[vertex] || weight != weights[vertex] {
+                    continue;
+                }
+                added[vertex] = true;
+                previous = last;
+                last = vertex;
+                count += 1;
+                if count == remaining {
+                    break;
+                }
+                for (&to, &wires) in &edges[vertex] {
+                    if !added[to] {
+                        weights[to] += wires;
+                        heap.push((weights[to], to));
+                    }
+                }
+            }
+
+            if count < remaining {
+                // nothing wires the set to the rest, so it's a cut of its own
+                return (0..names.len())
+                    .filter(|vertex| added[*vertex])
+                    .flat_map(|vertex| members[vertex].iter().map(|member| names[*member]))
+                    .collect();
+            }
+
+            // cutting `last` off from everything else
+            if weights[last] < best.0 {
+                best = (weights[last], members[last].clone());
+            }
+
+            let last_edges = std::mem::take(&mut edges[last]);
+            for (to, wires) in last_edges {
+                edges[to].remove(&last);
+                if to != previous {
+                    *edges[previous].entry(to).or_insert(0) += wires;
+                    *edges[to].entry(previous).or_insert(0) += wires;
+                }
+            }
+            let last_members = std::mem::take(&mut members[last]);
+            members[previous].extend(last_members);
+            merged[last] = true;
+        }
+
+        best.1.into_iter().map(|member| names[member]).collect()
+    }
+
+    /// The wires from a component in `side` to one outside it, each written
+    /// in name order.
+    fn wires_across<'a>(&'a self, side: &HashSet<&'a str>) -> Vec<(&'a str, &'a str)> {
+        let mut wires = side.iter()
+            .flat_map(|from| {
+                self.connections[*from].iter()
+                    .map(String::as_str)
+                    .filter(|to| !side.contains(to))
+                    .map(move |to| (*from.min(&to), *from.max(&to)))
+            })
+            .collect::<Vec<_>>();
+        wires.sort();
+        wires
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Modules;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Modules::parse(input)
+    }
+
+    fn part_one(modules: &Self::Parsed) -> Answer {
+        let side = modules.min_cut();
+        if modules.wires_across(&side).len() != 3 {
+            return Answer::Unsolved;
+        }
+        (side.len() * (modules.connections.len() - side.len())).into()
+    }
+
+    fn part_two(_modules: &Self::Parsed) -> Answer {
+        // the last day only has one puzzle
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_three_wires() {
+        let modules = Modules::parse(include_str!("../examples.txt").split("```").nth(1).unwrap()).unwrap();
+        let side = modules.min_cut();
+        assert_eq!(vec![("bvb", "cmg"), ("hfx", "pzl"), ("jqt", "nvd")], modules.wires_across(&side));
+        assert!(side.len() == 6 || side.len() == 9, "{:?}", side);
+    }
+
+    #[test]
+    fn separate_pieces_need_no_cut() {
+        let modules = Modules::parse("a: b c\nb: c\nd: e f\ne: f\n").unwrap();
+        let side = modules.min_cut();
+        assert_eq!(3, side.len());
+        assert!(modules.wires_across(&side).is_empty());
+    }
+}
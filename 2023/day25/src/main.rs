@@ -1,37 +0,0 @@
-use std::collections::HashMap;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
-
-struct Modules {
-    connections: HashMap<String, Vec<String>>
-}
-
-fn part_one(file_name: &str) {
-    let lines = get_file_lines(file_name)
-        .flat_map(|line| line.ok());
-    
-    println!("Part 1: {}", "incomplete");
-}
-
-fn part_two(file_name: &str) {
-    let lines = get_file_lines(file_name)
-        .flat_map(|line| line.ok());
-    
-    println!("Part 2: {}", "incomplete");
-}
-
-fn main() {
-    part_one("sample.txt");
-    part_two("sample.txt");
-
-    println!("Done!");
-}
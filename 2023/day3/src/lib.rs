@@ -1,11 +1,8 @@
 use std::collections::HashMap;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
-type Input = Lines<BufReader<File>>;
-
-struct Schematic {
+pub struct Schematic {
     symbols: HashMap<(i32, i32), String>,
     parts: HashMap<(i32, i32), String>,
     gears_to_parts: HashMap<(i32, i32), Vec<(i32, i32)>>,
@@ -66,11 +63,10 @@ impl Schematic {
         .map(|(coord, part)| (*coord, part.to_owned()))
         .collect::<HashMap<_,_>>();
     }
-    fn parse(file_name: &str) -> Schematic {
+    fn parse(input: &str) -> Schematic {
         let mut schematic = Schematic::new();
 
-        get_file_lines(file_name)
-            .flat_map(|line| line.ok())
+        lines(input).into_iter()
             .for_each(|line| schematic.parse_line(line));
         
         schematic.prune_false_parts();
@@ -86,11 +82,10 @@ impl Schematic {
             let new_remaining = &remaining[start..];
             let is_part = new_remaining.chars()
                 .nth(0)
-                .unwrap()
-                .is_digit(10);
+                .unwrap().is_ascii_digit();
             let end_condition = match is_part {
-                true => |c: char| !c.is_digit(10),
-                false => |c: char| c == '.' || c.is_digit(10)
+                true => |c: char| !c.is_ascii_digit(),
+                false => |c: char| c == '.' || c.is_ascii_digit()
             };
             let end = match new_remaining.find(end_condition) {
                 Some(end) => end,
@@ -134,25 +129,20 @@ impl Schematic {
     
 }
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+pub struct Day;
 
-fn part_one(file_name: &str) {
-    let schematic = Schematic::parse(file_name);
-    println!("Part 1: {}", schematic.sum_parts());
-}
+impl Solution for Day {
+    type Parsed = Schematic;
 
-fn part_two(file_name: &str) {
-    let schematic = Schematic::parse(file_name);
-    println!("Part 2: {}", schematic.sum_gear_ratios());
-}
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Schematic::parse(input))
+    }
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn part_one(schematic: &Self::Parsed) -> Answer {
+        schematic.sum_parts().into()
+    }
 
-    println!("Done!");
+    fn part_two(schematic: &Self::Parsed) -> Answer {
+        schematic.sum_gear_ratios().into()
+    }
 }
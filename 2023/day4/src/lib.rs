@@ -1,15 +1,6 @@
 use std::collections::{HashSet, HashMap};
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 struct Scratchcard {
     id: usize,
@@ -18,7 +9,7 @@ struct Scratchcard {
 }
 
 impl Scratchcard {
-    fn parse(line: String) -> Scratchcard {
+    fn parse(line: &str) -> Scratchcard {
 
         let parts = line.split(":")
             .map(|line| line.trim())
@@ -74,40 +65,41 @@ impl Scratchcard {
     }
 }
 
-fn part_one(file_name: &str) {
-    let total = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| Scratchcard::parse(line))
-        .map(|scratchcard| scratchcard.get_points())
-        .sum::<u32>();
-    
-    println!("Part 1: {}", total);
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-fn part_two(file_name: &str) {
-    let mut card_count = HashMap::<usize, u32>::new();
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
 
-    let scratchcards = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| Scratchcard::parse(line))
-        .collect::<Vec<_>>();
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let total = lines.iter()
+            .map(|line| Scratchcard::parse(line))
+            .map(|scratchcard| scratchcard.get_points())
+            .sum::<u32>();
 
-    for i in 0..scratchcards.len() {
-        card_count.insert(i + 1, 1);
+        total.into()
     }
 
-    scratchcards.iter().for_each(|scratchcard| {
-        scratchcard.add_card_counts(&mut card_count)
-    });
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut card_count = HashMap::<usize, u32>::new();
 
-    let total = card_count.values().sum::<u32>();
-    
-    println!("Part 2: {}", total);
-}
+        let scratchcards = lines.iter()
+            .map(|line| Scratchcard::parse(line))
+            .collect::<Vec<_>>();
+
+        for i in 0..scratchcards.len() {
+            card_count.insert(i + 1, 1);
+        }
+
+        scratchcards.iter().for_each(|scratchcard| {
+            scratchcard.add_card_counts(&mut card_count)
+        });
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+        let total = card_count.values().sum::<u32>();
 
-    println!("Done!");
+        total.into()
+    }
 }
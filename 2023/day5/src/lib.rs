@@ -0,0 +1,246 @@
+use std::fmt;
+use aoc_common::input::lines;
+use aoc_common::interval::{Interval, IntervalSet};
+use aoc_common::{Answer, Result, Solution};
+
+/// Moves every number in `source` along by the same amount, so that the
+/// start of it goes to `dest`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Mapping {
+    source: Interval<u64>,
+    dest: u64
+}
+
+impl Mapping {
+    fn identity(source: Interval<u64>) -> Mapping {
+        Mapping { source, dest: source.start() }
+    }
+
+    fn offset(&self) -> i128 {
+        self.dest as i128 - self.source.start() as i128
+    }
+
+    /// Where the numbers in `source` end up.
+    fn image(&self) -> Interval<u64> {
+        self.source.with_start(self.dest)
+    }
+}
+
+/// One map of the almanac, from one kind of number to the next, as mappings
+/// that don't overlap in order of their sources. Any number none of them
+/// covers stays the same.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stage {
+    mappings: Vec<Mapping>
+}
+
+impl Stage {
+    fn new(mut mappings: Vec<Mapping>) -> Stage {
+        mappings.sort_by_key(|mapping| mapping.source);
+        Stage { mappings }
+    }
+
+    fn apply(&self, value: u64) -> u64 {
+        let index = self.mappings.partition_point(|mapping| mapping.source.end() < value);
+        match self.mappings.get(index) {
+            Some(mapping) if mapping.source.contains(value) => (value as i128 + mapping.offset()) as u64,
+            _ => value
+        }
+    }
+
+    /// `range` cut wherever a mapping starts or ends, with where the start
+    /// of each piece goes.
+    fn split(&self, range: Interval<u64>) -> Vec<(Interval<u64>, u64)> {
+        let mut pieces = Vec::new();
+        let mut rest = Some(range);
+        for mapping in self.mappings.iter() {
+            let range = match rest {
+                Some(range) => range,
+                None => break
+            };
+            let split = range.split(&mapping.source);
+            pieces.extend(split.below.map(|below| (below, below.start())));
+            pieces.extend(split.inside.map(|inside| (inside, (inside.start() as i128 + mapping.offset()) as u64)));
+            rest = split.above;
+        }
+        pieces.extend(rest.map(|rest| (rest, rest.start())));
+        pieces
+    }
+
+    /// Where every number in `ranges` goes.
+    fn apply_ranges(&self, ranges: &IntervalSet<u64>) -> IntervalSet<u64> {
+        ranges.iter()
+            .flat_map(|range| self.split(*range))
+            .map(|(piece, dest)| piece.with_start(dest))
+            .collect()
+    }
+
+    /// Every number's mapping, including those that stay the same.
+    fn pieces(&self) -> Vec<Mapping> {
+        let mut pieces = Vec::new();
+        let mut next = Some(0);
+        for mapping in self.mappings.iter() {
+            if let Some(start) = next.filter(|start| *start < mapping.source.start()) {
+                pieces.push(Mapping::identity(Interval::new(start, mapping.source.start() - 1)));
+            }
+            pieces.push(mapping.clone());
+            next = mapping.source.end().checked_add(1);
+        }
+        pieces.extend(next.map(|start| Mapping::identity(Interval::new(start, u64::MAX))));
+        pieces
+    }
+
+    /// This stage followed by `next` as a single stage.
+    fn then(&self, next: &Stage) -> Stage {
+        let mut mappings = Vec::<Mapping>::new();
+        for piece in self.pieces() {
+            let image_start = piece.dest;
+            for (part, dest) in next.split(piece.image()) {
+                let source = part.with_start(piece.source.start() + (part.start() - image_start));
+                let mapping = Mapping { source, dest };
+                match mappings.last_mut() {
+                    // carry on the last one if it moves numbers just as far
+                    Some(last) if last.source.end() + 1 == source.start() && last.offset() == mapping.offset() =>
+                        last.source = Interval::new(last.source.start(), source.end()),
+                    _ => mappings.push(mapping)
+                }
+            }
+        }
+        mappings.retain(|mapping| mapping.offset() != 0);
+        Stage { mappings }
+    }
+}
+
+/// Written out the way the almanac writes its maps.
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for mapping in self.mappings.iter() {
+            writeln!(f, "{} {} {}", mapping.dest, mapping.source.start(), mapping.source.size())?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Almanac {
+    stages: Vec<Stage>
+}
+
+impl Almanac {
+    /// Every stage one after the other, from seed straight to location.
+    fn seed_to_location(&self) -> Stage {
+        self.stages.iter()
+            .skip(1)
+            .fold(self.stages[0].clone(), |combined, stage| combined.then(stage))
+    }
+}
+
+fn parse_map(lines: Vec<String>) -> Stage {
+    let mappings = lines.into_iter()
+        .map(|line| {
+            line.split_ascii_whitespace()
+                .map(|number| number.parse::<u64>().unwrap())
+                .collect::<Vec<_>>()
+        })
+        .map(|numbers| {
+            let dest = numbers[0];
+            let source = numbers[1];
+            let length = numbers[2];
+            Mapping { source: Interval::new(source, source + length - 1), dest }
+        })
+        .collect::<Vec<_>>();
+
+    Stage::new(mappings)
+}
+
+fn parse_input(input: &str) -> (Almanac, Vec<u64>) {
+    let mut lines = lines(input).into_iter();
+
+    let seeds = lines.next().unwrap();
+    lines.next().unwrap(); // skip blank line
+
+    let seeds = seeds[seeds.find(':').unwrap() + 1..].trim();
+    let seeds = seeds.split(" ")
+        .map(|number| number.parse::<u64>().unwrap())
+        .collect::<Vec<_>>();
+
+    let stages = (0..7).map(|_| {
+        parse_map((&mut lines).take_while(|line| !line.is_empty())
+            .skip(1)
+            .collect::<Vec<_>>())
+    })
+    .collect::<Vec<_>>();
+
+    let almanac = Almanac { stages };
+
+    (almanac, seeds)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (Almanac, Vec<u64>);
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(parse_input(input))
+    }
+
+    fn part_one((almanac, seeds): &Self::Parsed) -> Answer {
+        let seed_to_location = almanac.seed_to_location();
+        let minimum = seeds.iter()
+            .map(|seed| seed_to_location.apply(*seed))
+            .min()
+            .unwrap();
+
+        minimum.into()
+    }
+
+    fn part_two((almanac, seeds): &Self::Parsed) -> Answer {
+        let seeds = seeds.chunks(2)
+            .filter(|pair| pair[1] > 0)
+            .map(|pair| Interval::new(pair[0], pair[0] + pair[1] - 1))
+            .collect::<IntervalSet<_>>();
+
+        let locations = almanac.seed_to_location().apply_ranges(&seeds);
+        // every seed range could have been empty
+        let lowest = locations.iter().next().map(|interval| interval.start());
+        match lowest {
+            Some(lowest) => lowest.into(),
+            None => Answer::Unsolved
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combined_stages_match_each_in_turn() {
+        let (almanac, _) = parse_input(include_str!("../examples.txt").split("```").nth(1).unwrap().trim_start());
+        let combined = almanac.seed_to_location();
+        for seed in 0..120 {
+            let location = almanac.stages.iter().fold(seed, |value, stage| stage.apply(value));
+            assert_eq!(location, combined.apply(seed), "seed {}", seed);
+        }
+
+        // printed out, it reads back in as the same map
+        let printed = combined.to_string();
+        assert_eq!(combined, parse_map(printed.lines().map(str::to_owned).collect()));
+    }
+
+    #[test]
+    fn splits_ranges_at_mappings() {
+        let stage = parse_map(vec!["50 98 2".to_owned(), "52 50 48".to_owned()]);
+        let seeds = IntervalSet::from(Interval::new(45, 98));
+        let soil = stage.apply_ranges(&seeds);
+        assert_eq!(vec![Interval::new(45, 50), Interval::new(52, 99)], soil.iter().copied().collect::<Vec<_>>());
+        assert_eq!(54, soil.size());
+    }
+
+    #[test]
+    fn skips_empty_seed_ranges() {
+        let input = include_str!("../examples.txt").split("```").nth(1).unwrap().trim_start();
+        let parsed = Day::parse(&input.replacen("seeds: 79 14", "seeds: 79 14 60 0", 1)).unwrap();
+        assert_eq!(Answer::from(46u64), Day::part_two(&parsed));
+    }
+}
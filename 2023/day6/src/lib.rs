@@ -0,0 +1,79 @@
+#![allow(clippy::useless_conversion)]
+
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+struct Race {
+    time: u64,
+    distance: u64
+}
+
+impl Race {
+    fn new(time: u64, distance: u64) -> Race {
+        Race { time, distance }
+    }
+    /**
+     * distance = Tp * (time - Tp)
+     */
+    fn get_win_count(&self) -> u64 {
+        (1..self.time).into_iter()
+            .map(|t| t * (self.time - t))
+            .filter(|distance| *distance > self.distance)
+            .count() as u64
+    }
+}
+
+fn parse_races(lines: &[String]) -> Vec<Race> {
+    let numbers = lines.iter()
+        .map(|line| {
+            line.split_ascii_whitespace()
+                .skip(1)
+                .map(|number| number.parse::<u64>().unwrap())
+                .collect::<Vec<_>>()
+        }).collect::<Vec<_>>();
+
+    numbers[0].iter()
+        .zip(numbers[1].iter())
+        .map(|(time, distance)| Race::new(*time, *distance))
+        .collect::<Vec<_>>()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let races = parse_races(lines);
+
+        let product = races.into_iter()
+            .map(|race| race.get_win_count())
+            .product::<u64>();
+
+        product.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let numbers = lines.iter()
+            .map(|line| {
+                line.split(':')
+                    .next_back()
+                    .unwrap()
+                    .chars()
+                    .filter(|c| *c != ' ')
+                    .collect::<String>()
+            })
+            .map(|number| number.parse::<u64>().unwrap())
+            .collect::<Vec<_>>();
+
+        let time = numbers[0];
+        let distance = numbers[1];
+        let race = Race::new(time, distance);
+
+        race.get_win_count().into()
+    }
+}
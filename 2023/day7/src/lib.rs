@@ -1,16 +1,8 @@
-use std::cmp::Ordering;
-use std::collections::HashMap;
-use std::fs::File;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
-
-type Input = Lines<BufReader<File>>;
+#![allow(clippy::ptr_arg, clippy::useless_conversion)]
 
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
+use std::cmp::Ordering;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
 const HIGH: usize = 0;
 const PAIR: usize = 1;
@@ -30,7 +22,7 @@ struct Hand {
 impl Hand {
 
     fn get_hand_type_one(cards: &Vec<usize>) -> usize {
-        let mut counts = vec![0; 15];
+        let mut counts = [0; 15];
         let mut common_counts: Vec<usize> = vec![0; 6];
 
         for card in cards {
@@ -64,7 +56,7 @@ impl Hand {
     }
 
     fn get_hand_type_two(cards: &Vec<usize>) -> usize {
-        let mut counts = vec![0; 15];
+        let mut counts = [0; 15];
         let mut common_counts: Vec<usize> = vec![0; 6];
 
         for card in cards {
@@ -114,7 +106,7 @@ impl Hand {
     }
 
     fn parse<F>(
-        line: String, 
+        line: &str, 
         jack_value: u32,
         get_hand_type: F) -> Hand 
         where F: Fn(&Vec<usize>) -> usize
@@ -141,12 +133,7 @@ impl Hand {
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        let hand_compare = self.hand_type.partial_cmp(&other.hand_type).unwrap();
-        if  hand_compare == Ordering::Equal {
-            self.cards.partial_cmp(&other.cards)
-        } else {
-            Some(hand_compare)
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -167,35 +154,36 @@ fn compute_winnings(hands: &Vec<Hand>) -> u64 {
         .sum::<u64>()
 }
 
-fn part_one(file_name: &str) {
-    let mut hands = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| Hand::parse(line, 11, Hand::get_hand_type_one))
-        .collect::<Vec<_>>();
+pub struct Day;
 
-    hands.sort();
+impl Solution for Day {
+    type Parsed = Vec<String>;
 
-    let winnings = compute_winnings(&hands);
-    
-    println!("Part 1: {}", winnings);
-}
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
 
-fn part_two(file_name: &str) {
-    let mut hands = get_file_lines(file_name)
-        .flat_map(|line| line.ok())
-        .map(|line| Hand::parse(line, 1, Hand::get_hand_type_two))
-        .collect::<Vec<_>>();
-    
-    hands.sort();
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let mut hands = lines.iter()
+            .map(|line| Hand::parse(line, 11, Hand::get_hand_type_one))
+            .collect::<Vec<_>>();
 
-    let winnings = compute_winnings(&hands);
-    
-    println!("Part 2: {}", winnings);
-}
+        hands.sort();
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+        let winnings = compute_winnings(&hands);
+
+        winnings.into()
+    }
 
-    println!("Done!");
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let mut hands = lines.iter()
+            .map(|line| Hand::parse(line, 1, Hand::get_hand_type_two))
+            .collect::<Vec<_>>();
+
+        hands.sort();
+
+        let winnings = compute_winnings(&hands);
+
+        winnings.into()
+    }
 }
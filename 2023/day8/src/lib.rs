@@ -0,0 +1,205 @@
+use std::collections::{HashMap, HashSet};
+use aoc_common::cycle::{find_cycle, Cycle};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+enum Turn {
+    Left,
+    Right
+}
+
+pub struct Map {
+    lookup: HashMap<String, usize>,
+    turns: Vec<Turn>,
+    nodes: HashMap<usize, (usize, usize)>
+}
+
+fn to_id(lookup: &mut HashMap<String, usize>, id_str: &str) -> usize {
+    if let Some(id) = lookup.get(id_str) {
+        *id
+    } else {
+        let next = lookup.len();
+        lookup.insert(id_str.to_owned(), next);
+        next
+    }
+}
+
+fn parse_node(lookup: &mut HashMap<String, usize>, line: String) -> (usize, (usize, usize)) {
+    let parts = line.split_ascii_whitespace().collect::<Vec<_>>();
+    let from = to_id(lookup, parts[0]);
+    let left = to_id(lookup, &parts[2][1..4]);
+    let right = to_id(lookup, &parts[3][0..3]);
+    (from, (left, right))
+}
+
+impl Map {
+    fn parse(input: &str) -> Map {
+        let mut lines = lines(input).into_iter();
+
+        let turns = lines.next()
+            .unwrap()
+            .chars()
+            .map(|c| match c {
+                'L' => Turn::Left,
+                'R' => Turn::Right,
+                x => panic!("Unexpected turn character: {}", x)
+            })
+            .collect::<Vec<_>>();
+
+        let mut lookup = HashMap::<String, usize>::new();
+
+        let nodes = lines.skip(1)
+            .map(|line| parse_node(&mut lookup, line))
+            .collect::<HashMap<_,_>>();
+
+        Map { lookup, nodes, turns }
+    }
+
+    fn get_distance_to_end(&self, start: usize, end: usize, mut index: u64) -> Option<u64> {
+        let start_index = index;
+        let mut current = start;
+        let num_turns = self.turns.len() as u64;
+        let limit_end  = if index > 0 {
+            index * 2
+        } else {
+            u64::MAX
+        };
+        while current != end && index <= limit_end {
+            let (left, right) = self.nodes.get(&current).unwrap();
+            let next = match self.turns[(index % num_turns) as usize] {
+                Turn::Left => left,
+                Turn::Right => right
+            };
+            current = *next;
+            index += 1;
+        }
+        if current != end {
+            None
+        } else {
+            Some(index - start_index)
+        }
+    }
+
+    /// Moves a ghost on one step.
+    fn step(&self, (node, turn): &mut Ghost) {
+        let (left, right) = self.nodes.get(node).unwrap();
+        *node = match self.turns[*turn] {
+            Turn::Left => *left,
+            Turn::Right => *right
+        };
+        *turn = (*turn + 1) % self.turns.len();
+    }
+
+    /// The loop a ghost setting off from `start` ends up going round, and
+    /// every step up to the end of its first time round that it's on one of
+    /// the `ends`.
+    fn ghost_loop(&self, start: usize, ends: &HashSet<usize>) -> (Cycle, Vec<usize>) {
+        let cycle = find_cycle((start, 0), |ghost| self.step(ghost), |ghost| *ghost);
+
+        let mut ghost = (start, 0);
+        let mut at_end = Vec::new();
+        for step in 0..cycle.start + cycle.length {
+            if ends.contains(&ghost.0) {
+                at_end.push(step);
+            }
+            self.step(&mut ghost);
+        }
+
+        (cycle, at_end)
+    }
+}
+
+/// Where a ghost is: its node and how far through the turns it's got.
+type Ghost = (usize, usize);
+
+fn gcd(a: u64, b: u64) -> u64 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b)
+    }
+}
+
+/// The steps that are both `a` mod `m` and `b` mod `n`, as a remainder mod
+/// the lowest common multiple, if there are any.
+fn combine((a, m): (u64, u64), (b, n): (u64, u64)) -> Option<(u64, u64)> {
+    let lcm = m / gcd(m, n) * n;
+    (0..n)
+        .map(|k| a + k * m)
+        .take_while(|step| *step < lcm)
+        .find(|step| step % n == b)
+        .map(|step| (step, lcm))
+}
+
+/// The first step where every ghost is on an end at once.
+fn all_at_end(ghosts: &[(Cycle, Vec<usize>)]) -> Option<u64> {
+    // until they're all going round their loops, try each step in turn
+    let settled = ghosts.iter()
+        .map(|(cycle, _)| cycle.start)
+        .max()
+        .unwrap_or(0);
+    let found = (0..settled)
+        .find(|step| ghosts.iter().all(|(cycle, at_end)| at_end.contains(&cycle.equivalent(*step))));
+    if let Some(step) = found {
+        return Some(step as u64);
+    }
+
+    // after that each ghost is on an end some whole number of times round its
+    // loop after each end in the loop, so the steps they're all on one
+    // together are where those line up
+    let mut together = vec![(0, 1)];
+    for (cycle, at_end) in ghosts {
+        let length = cycle.length as u64;
+        let mut next = Vec::new();
+        for step in at_end.iter().filter(|step| **step >= cycle.start) {
+            for both in &together {
+                next.extend(combine(*both, (*step as u64 % length, length)));
+            }
+        }
+        together = next;
+    }
+
+    let settled = settled as u64;
+    together.into_iter()
+        .map(|(step, modulus)| match step < settled {
+            true => step + (settled - step).div_ceil(modulus) * modulus,
+            false => step
+        })
+        .min()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Map::parse(input))
+    }
+
+    fn part_one(map: &Self::Parsed) -> Answer {
+        let start = map.lookup.get("AAA").unwrap();
+        let end = map.lookup.get("ZZZ").unwrap();
+        let count = map.get_distance_to_end(*start, *end, 0);
+        count.unwrap().into()
+    }
+
+    fn part_two(map: &Self::Parsed) -> Answer {
+        let starts = map.lookup.iter()
+            .filter(|(name, _)| name.ends_with('A'))
+            .map(|(_, id)| *id)
+            .collect::<Vec<_>>();
+
+        let ends = map.lookup.iter()
+            .filter(|(name, _)| name.ends_with('Z'))
+            .map(|(_, id)| *id)
+            .collect::<HashSet<_>>();
+
+        let ghosts = starts.iter()
+            .map(|start| map.ghost_loop(*start, &ends))
+            .collect::<Vec<_>>();
+
+        all_at_end(&ghosts)
+            .expect("The ghosts are never all at an end together")
+            .into()
+    }
+}
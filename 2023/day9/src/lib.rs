@@ -1,21 +1,10 @@
 use std::collections::LinkedList;
-use std::f32::consts::E;
-use std::fs::File;
 use std::iter::FromIterator;
-use std::path::Path;
-use std::io::{BufRead, BufReader, Lines};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
 
-type Input = Lines<BufReader<File>>;
-
-fn get_file_lines(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let file = File::open(path).unwrap();
-    BufReader::new(file).lines()
-}
-
-fn parse_numbers(file_name: &str) -> Vec<Vec<i64>> {
-    get_file_lines(file_name)
-        .flat_map(|line| line.ok())
+fn parse_numbers(input: &str) -> Vec<Vec<i64>> {
+    lines(input).into_iter()
         .map(|line| {
             line.split_ascii_whitespace()
                 .map(|number| number.parse::<i64>().unwrap())
@@ -68,25 +57,30 @@ fn get_next_point(numbers: Vec<i64>, end: bool) -> i64 {
     }
 }
 
-fn part_one(file_name: &str) {
-    let sum_of_next = parse_numbers(file_name).into_iter()
-        .map(|numbers| get_next_point(numbers, true))
-        .sum::<i64>();
-    
-    println!("Part 1: {}", sum_of_next);
-}
+pub struct Day;
 
-fn part_two(file_name: &str) {
-    let sum_of_next = parse_numbers(file_name).into_iter()
-        .map(|numbers| get_next_point(numbers, false))
-        .sum::<i64>();
-    
-    println!("Part 2: {}", sum_of_next);
-}
+impl Solution for Day {
+    type Parsed = Vec<Vec<i64>>;
 
-fn main() {
-    part_one("input.txt");
-    part_two("input.txt");
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(parse_numbers(input))
+    }
 
-    println!("Done!");
+    fn part_one(numbers: &Self::Parsed) -> Answer {
+        let sum_of_next = numbers.iter()
+            .cloned()
+            .map(|numbers| get_next_point(numbers, true))
+            .sum::<i64>();
+
+        sum_of_next.into()
+    }
+
+    fn part_two(numbers: &Self::Parsed) -> Answer {
+        let sum_of_next = numbers.iter()
+            .cloned()
+            .map(|numbers| get_next_point(numbers, false))
+            .sum::<i64>();
+
+        sum_of_next.into()
+    }
 }
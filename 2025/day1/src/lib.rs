@@ -0,0 +1,77 @@
+#![allow(clippy::assign_op_pattern)]
+
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Answer {
+        let turns = lines.iter()
+            .map(|line| match line.chars().next().unwrap() {
+                'L' => -(line[1..line.len()].parse::<i32>().unwrap() % 100),
+                'R' => line[1..line.len()].parse::<i32>().unwrap(),
+                _   => panic!("Unexpected line start"),
+            })
+            .collect::<Vec<i32>>();
+
+        let mut count = 0;
+        let mut current = 50;
+
+        for number in turns {
+            current = current + number;
+            if current < 0 {
+                current = current + 100;
+            } else {
+                current = current % 100;
+            }
+            if current == 0 {
+                count += 1;
+            }
+        }
+
+        count.into()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Answer {
+        let turns = lines.iter()
+            .map(|line| match line.chars().next().unwrap() {
+                'L' => -line[1..line.len()].parse::<i32>().unwrap(),
+                'R' => line[1..line.len()].parse::<i32>().unwrap(),
+                _   => panic!("Unexpected line start"),
+            })
+            .collect::<Vec<i32>>();
+
+        let mut count = 0;
+        let mut current = 50;
+
+        for number in turns {
+            if number == 0 {
+                continue;
+            }
+            current = current + number;
+            if current < 0 {
+                let zero = -current;
+                count += 1 + (zero / 100);
+                if current == number {
+                    count -= 1;
+                }
+                current = -(zero % 100) + 100;
+            } else if current >= 100 {
+                let zero = current - 100;
+                count += 1 + (zero / 100);
+                current = current % 100;
+            } else if current == 0 {
+                count += 1;
+            }
+        }
+
+        count.into()
+    }
+}
@@ -0,0 +1,239 @@
+#![allow(clippy::let_and_return, clippy::useless_conversion)]
+
+use std::collections::{HashMap, HashSet};
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Machine {
+    target: usize,
+    button_map: Vec<HashMap<usize, usize>>,
+    // the counters each button adds one to
+    buttons: Vec<Vec<usize>>,
+    joltages: Vec<usize>
+}
+
+impl Machine {
+    fn parse(line: &str) -> Self {
+        let parts = line.split_ascii_whitespace()
+            .collect::<Vec<_>>();
+        let len = parts[0].len() - 3;
+        let mut max = 0;
+        for _ in 0..len + 1 {
+            max = (max << 1) | 1
+        }
+        let target = Machine::parse_target(&parts[0][1..parts[0].len()-1]);
+
+        let buttons = parts[1..parts.len() - 1].iter()
+            .map(|part| part[1..part.len()-1].split(",")
+                .map(|str| str.parse::<usize>().unwrap())
+                .collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let button_map = buttons.iter()
+            .map(|button| Machine::parse_button(button, max, len))
+            .collect::<Vec<_>>();
+
+        let joltage_part = parts[parts.len() - 1];
+        let joltages = joltage_part[1..joltage_part.len() - 1].split(",")
+            .map(|str| str.parse::<usize>().unwrap())
+            .collect::<Vec<_>>();
+        
+        Machine { target, button_map, buttons, joltages }
+    }
+    
+    fn smallest_presses_to_target(&self) -> usize {
+        let mut results = HashMap::<usize, usize>::new();
+        let mut from = HashSet::new();
+        from.insert(self.target);
+        let mut pushes = 0;
+        while !results.contains_key(&0) {
+            let new_from = from.iter().flat_map(|source| 
+                self.button_map.iter()
+                    .flat_map(|map| map.get(source))
+                    .filter(|dest| !results.contains_key(dest))
+                    .collect::<Vec<_>>()
+            )
+            .cloned()
+            .collect::<HashSet<_>>();
+
+            pushes += 1;
+            new_from.iter().for_each(|num| { 
+                results.insert(*num, pushes); 
+            });
+            from = new_from;
+        }
+        pushes
+    }
+    
+    fn parse_button(button: &[usize], max: usize, len: usize) -> HashMap<usize, usize> {
+        let mask = button.iter()
+            .map(|num| 1 << (len - num))
+            .fold(0, |acc, item| acc | item);
+
+        let map = (0..max + 1).into_iter()
+            .map(|orig| (orig ^ mask, orig))
+            .collect::<HashMap<_, _>>();
+
+        map
+    }
+
+    /// Fewest presses that take every counter from 0 to exactly its joltage.
+    ///
+    /// Each counter gives an equation over the press counts of the buttons
+    /// that touch it. Gaussian elimination leaves a few buttons free; every
+    /// combination of those is tried (each bounded by the smallest joltage it
+    /// adds to) and the rest follow from the reduced equations.
+    fn fewest_presses_for_joltages(&self) -> Option<usize> {
+        let buttons = self.buttons.len();
+
+        // one row per counter: which buttons add to it, then its joltage
+        let mut rows = self.joltages.iter()
+            .enumerate()
+            .map(|(counter, joltage)| {
+                let mut row = self.buttons.iter()
+                    .map(|button| button.contains(&counter) as i64)
+                    .collect::<Vec<_>>();
+                row.push(*joltage as i64);
+                row
+            })
+            .collect::<Vec<_>>();
+
+        // integer-only elimination to reduced row echelon form
+        let mut pivots = Vec::new();
+        for column in 0..buttons {
+            let row = pivots.len();
+            let Some(found) = (row..rows.len()).find(|&r| rows[r][column] != 0) else {
+                continue;
+            };
+            rows.swap(row, found);
+            let pivot_row = rows[row].clone();
+            for (other, other_row) in rows.iter_mut().enumerate() {
+                if other == row || other_row[column] == 0 {
+                    continue;
+                }
+                let (pivot, factor) = (pivot_row[column], other_row[column]);
+                for (value, pivot_value) in other_row.iter_mut().zip(pivot_row.iter()) {
+                    *value = *value * pivot - pivot_value * factor;
+                }
+                reduce(other_row);
+            }
+            pivots.push(column);
+        }
+
+        // a row with no buttons left has to be 0 = 0
+        if rows[pivots.len()..].iter().any(|row| row[buttons] != 0) {
+            return None;
+        }
+
+        let free = (0..buttons).filter(|column| !pivots.contains(column)).collect::<Vec<_>>();
+        let bounds = free.iter()
+            .map(|&button| self.buttons[button].iter().map(|&counter| self.joltages[counter]).min().unwrap_or(0))
+            .collect::<Vec<_>>();
+
+        rows.truncate(pivots.len());
+        let reduced = Reduced { rows, pivots, free, bounds };
+        let mut best = None;
+        let mut presses = vec![0; reduced.free.len()];
+        reduced.search(&mut presses, 0, &mut best);
+        best
+    }
+
+    fn parse_target(part: &str) -> usize {
+        let len = part.len() - 1;
+        part.char_indices()
+            .map(|(i, c)| match c {
+                '#' => 1 << (len - i),
+                _ => 0
+            })
+            .fold(0, |acc, item| acc | item)
+    }
+}
+
+/// A machine's equations after elimination: one row per pivot button, whose
+/// presses follow from those of the free buttons.
+struct Reduced {
+    rows: Vec<Vec<i64>>,
+    pivots: Vec<usize>,
+    free: Vec<usize>,
+    bounds: Vec<usize>
+}
+
+impl Reduced {
+    fn search(&self, presses: &mut Vec<usize>, index: usize, best: &mut Option<usize>) {
+        let free_total = presses[..index].iter().sum::<usize>();
+        if best.is_some_and(|best| free_total >= best) {
+            return;
+        }
+
+        if index < self.free.len() {
+            for count in 0..=self.bounds[index] {
+                presses[index] = count;
+                self.search(presses, index + 1, best);
+            }
+            presses[index] = 0;
+            return;
+        }
+
+        // every pivot button's presses follow from its row
+        let mut total = free_total;
+        for (row, &pivot) in self.rows.iter().zip(self.pivots.iter()) {
+            let mut rest = row[row.len() - 1];
+            for (button, count) in self.free.iter().zip(presses.iter()) {
+                rest -= row[*button] * *count as i64;
+            }
+            if rest % row[pivot] != 0 || rest / row[pivot] < 0 {
+                return;
+            }
+            total += (rest / row[pivot]) as usize;
+        }
+
+        if best.is_none_or(|best| total < best) {
+            *best = Some(total);
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input).iter()
+            .map(|line| Machine::parse(line))
+            .collect())
+    }
+
+    fn part_one(machines: &Self::Parsed) -> Answer {
+        let min_presses = machines.iter()
+            .map(|machine| machine.smallest_presses_to_target())
+            .sum::<usize>();
+
+        min_presses.into()
+    }
+
+    fn part_two(machines: &Self::Parsed) -> Answer {
+        let min_presses = machines.iter()
+            .map(|machine| machine.fewest_presses_for_joltages())
+            .sum::<Option<usize>>();
+
+        match min_presses {
+            Some(min_presses) => min_presses.into(),
+            None => Answer::Unsolved
+        }
+    }
+}
+
+/// Divides out the row's common factor, keeping the elimination's numbers small.
+fn reduce(row: &mut [i64]) {
+    let gcd = row.iter().fold(0, |gcd, value| {
+        let (mut a, mut b) = (gcd, value.abs());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    });
+    if gcd > 1 {
+        row.iter_mut().for_each(|value| *value /= gcd);
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Reactor {
+    outputs: HashMap<String, Vec<String>>
+}
+
+impl Reactor {
+    fn parse(input: &str) -> Self {
+        let outputs = lines(input).iter()
+            .map(|line| {
+                let (device, outputs) = line.split_once(": ").unwrap();
+                (device.to_owned(), outputs.split_whitespace().map(|output| output.to_owned()).collect())
+            })
+            .collect();
+        Reactor { outputs }
+    }
+
+    /// Number of paths from `device` to `out` that pass through every one of
+    /// `required`, tracked as a bitmask of the ones seen so far. The devices
+    /// never loop back, so each (device, seen) pair only has to be counted once.
+    fn count_paths<'a>(&'a self, device: &'a str, required: &[&str], seen: u32, memo: &mut HashMap<(&'a str, u32), u64>) -> u64 {
+        let seen = required.iter()
+            .position(|name| *name == device)
+            .map_or(seen, |index| seen | 1 << index);
+
+        if device == "out" {
+            return match seen.count_ones() as usize == required.len() {
+                true => 1,
+                false => 0
+            };
+        }
+        if let Some(count) = memo.get(&(device, seen)) {
+            return *count;
+        }
+
+        let count = self.outputs.get(device)
+            .map(|outputs| outputs.iter()
+                .map(|output| self.count_paths(output, required, seen, memo))
+                .sum())
+            .unwrap_or(0);
+        memo.insert((device, seen), count);
+        count
+    }
+
+    fn count_paths_from(&self, start: &str, required: &[&str]) -> u64 {
+        match self.outputs.contains_key(start) {
+            true => self.count_paths(start, required, 0, &mut HashMap::new()),
+            false => 0
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Reactor;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Reactor::parse(input))
+    }
+
+    fn part_one(reactor: &Self::Parsed) -> Answer {
+        reactor.count_paths_from("you", &[]).into()
+    }
+
+    fn part_two(reactor: &Self::Parsed) -> Answer {
+        reactor.count_paths_from("svr", &["dac", "fft"]).into()
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::HashSet;
+use aoc_common::input::paragraphs;
+use aoc_common::{Answer, Result, Solution};
+
+// a present's filled cells as (dx, dy), sorted so the first is the top-left
+// most and sits at (0, 0)
+type Orientation = Vec<(i32, i32)>;
+
+struct Shape {
+    size: usize,
+    orientations: Vec<Orientation>
+}
+
+impl Shape {
+    fn parse(block: &[String]) -> Self {
+        let cells = block.iter()
+            .skip(1)
+            .enumerate()
+            .flat_map(|(y, line)| line.char_indices()
+                .filter(|(_, c)| *c == '#')
+                .map(move |(x, _)| (x as i32, y as i32)))
+            .collect::<Vec<_>>();
+
+        // every rotation, flipped and not
+        let mut orientations = HashSet::new();
+        let mut current = cells.clone();
+        for _ in 0..4 {
+            current = current.iter().map(|(x, y)| (-y, *x)).collect();
+            orientations.insert(Self::normalise(&current));
+            orientations.insert(Self::normalise(&current.iter().map(|(x, y)| (-x, *y)).collect::<Vec<_>>()));
+        }
+
+        Shape { size: cells.len(), orientations: orientations.into_iter().collect() }
+    }
+    fn normalise(cells: &[(i32, i32)]) -> Orientation {
+        let mut cells = cells.iter().map(|(x, y)| (*y, *x)).collect::<Vec<_>>();
+        cells.sort();
+        let (top, left) = cells[0];
+        cells.iter().map(|(y, x)| (x - left, y - top)).collect()
+    }
+}
+
+struct Region {
+    width: usize,
+    height: usize,
+    counts: Vec<usize>
+}
+
+impl Region {
+    fn parse(line: &str) -> Self {
+        let (size, counts) = line.split_once(": ").unwrap();
+        let (width, height) = size.split_once('x').unwrap();
+        Region {
+            width: width.parse().unwrap(),
+            height: height.parse().unwrap(),
+            counts: counts.split_whitespace().map(|count| count.parse().unwrap()).collect()
+        }
+    }
+
+    fn fits(&self, shapes: &[Shape]) -> bool {
+        let area = self.width * self.height;
+        let needed = self.counts.iter().zip(shapes).map(|(count, shape)| count * shape.size).sum::<usize>();
+        if needed > area {
+            return false;
+        }
+        // every present fits in a 3x3 box, so side by side boxes always work
+        if self.counts.iter().sum::<usize>() <= (self.width / 3) * (self.height / 3) {
+            return true;
+        }
+
+        let mut packing = Packing {
+            width: self.width,
+            height: self.height,
+            filled: vec![false; area],
+            counts: self.counts.clone(),
+            slack: area - needed
+        };
+        packing.fill(0, shapes)
+    }
+}
+
+/// Backtracking over the cells in reading order: the first undecided cell is
+/// either left empty, using up some of the slack, or is the top-left cell of
+/// some orientation of a present still to place.
+struct Packing {
+    width: usize,
+    height: usize,
+    filled: Vec<bool>,
+    counts: Vec<usize>,
+    slack: usize
+}
+
+impl Packing {
+    fn cell(&self, x: i32, y: i32) -> Option<usize> {
+        match x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height {
+            true => Some(y as usize * self.width + x as usize),
+            false => None
+        }
+    }
+    fn is_free(&self, x: i32, y: i32, orientation: &Orientation) -> bool {
+        orientation.iter()
+            .all(|(dx, dy)| self.cell(x + dx, y + dy).is_some_and(|cell| !self.filled[cell]))
+    }
+    fn set(&mut self, x: i32, y: i32, orientation: &Orientation, filled: bool) {
+        for (dx, dy) in orientation {
+            let cell = self.cell(x + dx, y + dy).unwrap();
+            self.filled[cell] = filled;
+        }
+    }
+    fn fill(&mut self, from: usize, shapes: &[Shape]) -> bool {
+        if self.counts.iter().all(|count| *count == 0) {
+            return true;
+        }
+        let Some(cell) = (from..self.filled.len()).find(|cell| !self.filled[*cell]) else {
+            return false;
+        };
+        let (x, y) = ((cell % self.width) as i32, (cell / self.width) as i32);
+
+        for (index, shape) in shapes.iter().enumerate() {
+            if self.counts[index] == 0 {
+                continue;
+            }
+            for orientation in &shape.orientations {
+                if !self.is_free(x, y, orientation) {
+                    continue;
+                }
+                self.set(x, y, orientation, true);
+                self.counts[index] -= 1;
+                if self.fill(cell + 1, shapes) {
+                    return true;
+                }
+                self.counts[index] += 1;
+                self.set(x, y, orientation, false);
+            }
+        }
+
+        if self.slack > 0 {
+            self.slack -= 1;
+            self.filled[cell] = true;
+            if self.fill(cell + 1, shapes) {
+                return true;
+            }
+            self.filled[cell] = false;
+            self.slack += 1;
+        }
+        false
+    }
+}
+
+pub struct Farm {
+    shapes: Vec<Shape>,
+    regions: Vec<Region>
+}
+
+impl Farm {
+    fn parse(input: &str) -> Self {
+        let paragraphs = paragraphs(input);
+        let (regions, shapes) = paragraphs.split_last().unwrap();
+        Farm {
+            shapes: shapes.iter().map(|block| Shape::parse(block)).collect(),
+            regions: regions.iter().map(|line| Region::parse(line)).collect()
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Farm;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Farm::parse(input))
+    }
+
+    fn part_one(farm: &Self::Parsed) -> Answer {
+        farm.regions.iter()
+            .filter(|region| region.fits(&farm.shapes))
+            .count()
+            .into()
+    }
+
+    fn part_two(_farm: &Self::Parsed) -> Answer {
+        // the last day only has one puzzle
+        Answer::Unsolved
+    }
+}
@@ -0,0 +1,52 @@
+use aoc_common::input::lines;
+use aoc_common::interval::{Interval, IntervalSet};
+use aoc_common::{Answer, Result, Solution};
+
+fn parse_range(text: &str) -> Interval<u64> {
+    let parts = text.split("-").collect::<Vec<_>>();
+    let start = parts[0].parse::<u64>().unwrap();
+    let end = parts[1].parse::<u64>().unwrap();
+    Interval::new(start, end)
+}
+
+/// Whether `id` is the same digits over and over, `pieces` times.
+fn is_repeated(id: &str, pieces: usize) -> bool {
+    if !id.len().is_multiple_of(pieces) {
+        return false;
+    }
+    let piece_len = id.len() / pieces;
+    let piece = &id[0..piece_len];
+    (1..pieces).all(|j| id[j * piece_len..(j + 1) * piece_len] == *piece)
+}
+
+/// The sum of every ID in the ranges that `is_bad`, counting any the
+/// ranges share only once.
+fn sum_bad_ids<F>(ranges: &IntervalSet<u64>, is_bad: F) -> u64 where F: Fn(&str) -> bool {
+    ranges.iter()
+        .flat_map(|range| range.range())
+        .filter(|id| is_bad(&id.to_string()))
+        .sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = IntervalSet<u64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input).into_iter()
+            .next()
+            .unwrap()
+            .split(",")
+            .map(parse_range)
+            .collect())
+    }
+
+    fn part_one(ranges: &Self::Parsed) -> Answer {
+        sum_bad_ids(ranges, |id| is_repeated(id, 2)).into()
+    }
+
+    fn part_two(ranges: &Self::Parsed) -> Answer {
+        sum_bad_ids(ranges, |id| (2..id.len() + 1).any(|pieces| is_repeated(id, pieces))).into()
+    }
+}
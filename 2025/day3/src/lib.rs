@@ -0,0 +1,60 @@
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Bank {
+    batteries: Vec<u32>
+}
+
+impl Bank {
+    fn parse(line: &str) -> Self {
+        let batteries = line.chars()
+            .map(|c| c.to_digit(10).unwrap())
+            .collect::<Vec<_>>();
+        Bank { batteries }
+    }
+    fn get_largest_joltage(&self, size: usize) -> u64 {
+        let mut result = String::with_capacity(size);
+        let mut max = 0;
+        let mut start = 0;
+        for c in 0..size {
+            let from = start;
+            for i in from..self.batteries.len() - (size - (c + 1)) {
+                if self.batteries[i] > max {
+                    max = self.batteries[i];
+                    start = i + 1;
+                }
+            }
+            result += max.to_string().as_str();
+            max = 0;
+        }
+        result.parse::<u64>().unwrap()
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Bank>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input).iter()
+            .map(|line| Bank::parse(line))
+            .collect())
+    }
+
+    fn part_one(banks: &Self::Parsed) -> Answer {
+        let output = banks.iter()
+            .map(|bank| bank.get_largest_joltage(2))
+            .sum::<u64>();
+
+        output.into()
+    }
+
+    fn part_two(banks: &Self::Parsed) -> Answer {
+        let output = banks.iter()
+            .map(|bank| bank.get_largest_joltage(12))
+            .sum::<u64>();
+
+        output.into()
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+#[derive(Clone)]
+pub struct Map {
+    grid: HashSet<(i32,i32)>
+}
+
+impl Map {
+    fn new(grid: HashSet<(i32,i32)>) -> Self {
+        Map { grid }
+    }
+    fn neighbours(x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> {
+        (-1..=1).flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|(dx, dy)| *dx != 0 || *dy != 0)
+            .map(move |(dx, dy)| (x + dx, y + dy))
+    }
+    fn count_adjacent_rolls(&self, x: i32, y: i32) -> usize {
+        Self::neighbours(x, y)
+            .filter(|point| self.grid.contains(point))
+            .count()
+    }
+    fn is_accessible(&self, x: i32, y: i32) -> bool {
+        self.count_adjacent_rolls(x, y) < 4
+    }
+    fn count_accessible_rolls(&self) -> usize {
+        self.grid.iter()
+            .filter(|(x, y)| self.is_accessible(*x, *y))
+            .count()
+    }
+    /// Keeps removing accessible rolls until none are left, returning how many
+    /// were removed. Removing a roll can only free up its neighbours, so only
+    /// those are checked again.
+    fn remove_accessible_rolls(&mut self) -> usize {
+        let mut worklist = self.grid.iter()
+            .filter(|(x, y)| self.is_accessible(*x, *y))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut removed = 0;
+
+        while let Some((x, y)) = worklist.pop() {
+            if !self.grid.contains(&(x, y)) || !self.is_accessible(x, y) {
+                continue;
+            }
+            self.grid.remove(&(x, y));
+            removed += 1;
+
+            worklist.extend(Self::neighbours(x, y)
+                .filter(|point| self.grid.contains(point)));
+        }
+
+        removed
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let mut y: i32 = 0;
+        Ok(Map::new(lines(input).iter()
+            .flat_map(|line| {
+                let result = line.char_indices()
+                    .flat_map(|(x, c)| match c == '@' {
+                        true => Some((x as i32, y)),
+                        false => None
+                    }).collect::<Vec<_>>();
+                y += 1;
+                result
+            })
+            .collect::<HashSet<_>>()))
+    }
+
+    fn part_one(grid: &Self::Parsed) -> Answer {
+        grid.count_accessible_rolls().into()
+    }
+
+    fn part_two(grid: &Self::Parsed) -> Answer {
+        let mut grid = grid.clone();
+        grid.remove_accessible_rolls().into()
+    }
+}
@@ -0,0 +1,65 @@
+use aoc_common::input::paragraphs;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Inventory {
+    // fresh id ranges, inclusive, sorted and merged so none overlap
+    fresh: Vec<(u64, u64)>,
+    available: Vec<u64>
+}
+
+impl Inventory {
+    fn parse(input: &str) -> Self {
+        let paragraphs = paragraphs(input);
+
+        let mut ranges = paragraphs[0].iter()
+            .map(|line| {
+                let (start, end) = line.split_once("-").unwrap();
+                (start.parse::<u64>().unwrap(), end.parse::<u64>().unwrap())
+            })
+            .collect::<Vec<_>>();
+        ranges.sort();
+
+        let mut fresh: Vec<(u64, u64)> = Vec::new();
+        for (start, end) in ranges {
+            match fresh.last_mut() {
+                Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+                _ => fresh.push((start, end))
+            }
+        }
+
+        let available = paragraphs[1].iter()
+            .map(|line| line.parse::<u64>().unwrap())
+            .collect::<Vec<_>>();
+
+        Inventory { fresh, available }
+    }
+    fn is_fresh(&self, id: u64) -> bool {
+        // the last range starting at or before the id is the only one it can be in
+        let index = self.fresh.partition_point(|(start, _)| *start <= id);
+        index > 0 && id <= self.fresh[index - 1].1
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Inventory;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Inventory::parse(input))
+    }
+
+    fn part_one(inventory: &Self::Parsed) -> Answer {
+        inventory.available.iter()
+            .filter(|id| inventory.is_fresh(**id))
+            .count()
+            .into()
+    }
+
+    fn part_two(inventory: &Self::Parsed) -> Answer {
+        inventory.fresh.iter()
+            .map(|(start, end)| end - start + 1)
+            .sum::<u64>()
+            .into()
+    }
+}
@@ -0,0 +1,93 @@
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Problem {
+    operator: char,
+    // the problem's columns of the worksheet, one string per row, padded
+    rows: Vec<String>
+}
+
+impl Problem {
+    /// Splits the worksheet into problems at the columns that are blank in
+    /// every row.
+    fn parse_all(input: &str) -> Vec<Problem> {
+        let lines = input.lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect::<Vec<_>>();
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let grid = lines.iter()
+            .map(|line| format!("{:width$}", line, width = width).chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let (operators, numbers) = grid.split_last().unwrap();
+
+        let is_blank = |column: usize| grid.iter().all(|row| row[column] == ' ');
+
+        let mut problems = Vec::new();
+        let mut start = 0;
+        for column in 0..=width {
+            if column < width && !is_blank(column) {
+                continue;
+            }
+            if column > start {
+                let operator = operators[start..column].iter()
+                    .find(|c| **c != ' ')
+                    .cloned()
+                    .unwrap();
+                let rows = numbers.iter()
+                    .map(|row| row[start..column].iter().collect::<String>())
+                    .collect::<Vec<_>>();
+                problems.push(Problem { operator, rows });
+            }
+            start = column + 1;
+        }
+        problems
+    }
+    fn solve(&self, numbers: Vec<u64>) -> u64 {
+        match self.operator {
+            '+' => numbers.iter().sum(),
+            '*' => numbers.iter().product(),
+            _ => panic!("Invalid operator: {}", self.operator)
+        }
+    }
+    /// A number per row, as people write them.
+    fn solve_by_rows(&self) -> u64 {
+        self.solve(self.rows.iter()
+            .map(|row| row.trim().parse::<u64>().unwrap())
+            .collect())
+    }
+    /// A number per column, read top to bottom, as cephalopods write them.
+    fn solve_by_columns(&self) -> u64 {
+        let width = self.rows[0].len();
+        self.solve((0..width).rev()
+            .map(|column| self.rows.iter()
+                .map(|row| row.as_bytes()[column] as char)
+                .filter(|c| *c != ' ')
+                .collect::<String>())
+            .filter(|number| !number.is_empty())
+            .map(|number| number.parse::<u64>().unwrap())
+            .collect())
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Problem>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Problem::parse_all(input))
+    }
+
+    fn part_one(problems: &Self::Parsed) -> Answer {
+        problems.iter()
+            .map(|problem| problem.solve_by_rows())
+            .sum::<u64>()
+            .into()
+    }
+
+    fn part_two(problems: &Self::Parsed) -> Answer {
+        problems.iter()
+            .map(|problem| problem.solve_by_columns())
+            .sum::<u64>()
+            .into()
+    }
+}
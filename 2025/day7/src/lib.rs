@@ -0,0 +1,62 @@
+use aoc_common::input::char_grid;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Manifold {
+    grid: Vec<Vec<char>>,
+    start: usize
+}
+
+impl Manifold {
+    fn parse(input: &str) -> Self {
+        let grid = char_grid(input);
+        let start = grid[0].iter().position(|c| *c == 'S').unwrap();
+        Manifold { grid, start }
+    }
+    /// Sends the beam down row by row, returning how many splitters it hits
+    /// and how many timelines end up at the bottom, counting a particle that
+    /// takes each side of a splitter as two.
+    fn run_beam(&self) -> (usize, u64) {
+        let width = self.grid[0].len();
+        let mut timelines = vec![0u64; width];
+        timelines[self.start] = 1;
+        let mut splits = 0;
+
+        for row in &self.grid[1..] {
+            let mut next = vec![0u64; width];
+            for (x, count) in timelines.iter().enumerate().filter(|(_, count)| **count > 0) {
+                if row[x] == '^' {
+                    splits += 1;
+                    if x > 0 {
+                        next[x - 1] += count;
+                    }
+                    if x + 1 < width {
+                        next[x + 1] += count;
+                    }
+                } else {
+                    next[x] += count;
+                }
+            }
+            timelines = next;
+        }
+
+        (splits, timelines.iter().sum())
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Manifold;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Manifold::parse(input))
+    }
+
+    fn part_one(manifold: &Self::Parsed) -> Answer {
+        manifold.run_beam().0.into()
+    }
+
+    fn part_two(manifold: &Self::Parsed) -> Answer {
+        manifold.run_beam().1.into()
+    }
+}
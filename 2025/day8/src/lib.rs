@@ -0,0 +1,116 @@
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Playground {
+    boxes: Vec<(i64, i64, i64)>,
+    // every pair of boxes, closest first
+    pairs: Vec<(usize, usize)>
+}
+
+impl Playground {
+    fn parse(input: &str) -> Self {
+        let boxes = lines(input).iter()
+            .map(|line| {
+                let coords = line.split(",")
+                    .map(|str| str.parse::<i64>().unwrap())
+                    .collect::<Vec<_>>();
+                (coords[0], coords[1], coords[2])
+            })
+            .collect::<Vec<_>>();
+
+        let distance = |a: usize, b: usize| {
+            let (ax, ay, az) = boxes[a];
+            let (bx, by, bz) = boxes[b];
+            (ax - bx).pow(2) + (ay - by).pow(2) + (az - bz).pow(2)
+        };
+        let mut pairs = (0..boxes.len())
+            .flat_map(|a| (a + 1..boxes.len()).map(move |b| (a, b)))
+            .collect::<Vec<_>>();
+        pairs.sort_by_key(|(a, b)| distance(*a, *b));
+
+        Playground { boxes, pairs }
+    }
+
+    /// The product of the three largest circuits' sizes once the closest
+    /// `connections` pairs are joined.
+    fn largest_circuits(&self, connections: usize) -> usize {
+        let mut circuits = Circuits::new(self.boxes.len());
+        for (a, b) in self.pairs.iter().take(connections) {
+            circuits.connect(*a, *b);
+        }
+
+        let mut sizes = (0..self.boxes.len())
+            .filter(|node| circuits.parent[*node] == *node)
+            .map(|root| circuits.size[root])
+            .collect::<Vec<_>>();
+        sizes.sort_by(|a, b| b.cmp(a));
+
+        sizes.iter().take(3).product()
+    }
+}
+
+struct Circuits {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    count: usize
+}
+
+impl Circuits {
+    fn new(boxes: usize) -> Self {
+        Circuits { parent: (0..boxes).collect(), size: vec![1; boxes], count: boxes }
+    }
+    fn find(&mut self, mut node: usize) -> usize {
+        while self.parent[node] != node {
+            self.parent[node] = self.parent[self.parent[node]];
+            node = self.parent[node];
+        }
+        node
+    }
+    fn connect(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        let (big, small) = if self.size[a] >= self.size[b] { (a, b) } else { (b, a) };
+        self.parent[small] = big;
+        self.size[big] += self.size[small];
+        self.count -= 1;
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Playground;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Playground::parse(input))
+    }
+
+    fn part_one(playground: &Self::Parsed) -> Answer {
+        playground.largest_circuits(1000).into()
+    }
+
+    fn part_two(playground: &Self::Parsed) -> Answer {
+        let mut circuits = Circuits::new(playground.boxes.len());
+        for (a, b) in &playground.pairs {
+            circuits.connect(*a, *b);
+            if circuits.count == 1 {
+                return (playground.boxes[*a].0 * playground.boxes[*b].0).into();
+            }
+        }
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_joins_ten_pairs() {
+        // the sample only joins its 10 closest pairs, so examples.txt can't check part one
+        let playground = Playground::parse(include_str!("../sample.txt"));
+        assert_eq!(40, playground.largest_circuits(10));
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::VecDeque;
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Theater {
+    // the red tiles, in order around the loop
+    red: Vec<(i64, i64)>
+}
+
+impl Theater {
+    fn parse(input: &str) -> Self {
+        let red = lines(input).iter()
+            .map(|line| {
+                let (x, y) = line.split_once(",").unwrap();
+                (x.parse::<i64>().unwrap(), y.parse::<i64>().unwrap())
+            })
+            .collect::<Vec<_>>();
+        Theater { red }
+    }
+    fn area((ax, ay): (i64, i64), (bx, by): (i64, i64)) -> i64 {
+        ((ax - bx).abs() + 1) * ((ay - by).abs() + 1)
+    }
+    fn largest_rectangle<F>(&self, allowed: F) -> i64 where F: Fn((i64, i64), (i64, i64)) -> bool {
+        let mut largest = 0;
+        for (i, a) in self.red.iter().enumerate() {
+            for b in &self.red[i + 1..] {
+                let area = Self::area(*a, *b);
+                if area > largest && allowed(*a, *b) {
+                    largest = area;
+                }
+            }
+        }
+        largest
+    }
+}
+
+/// The floor squashed down to the columns and rows the red tiles are on plus
+/// one for each gap between them, with a ring of outside around it, so
+/// "is every tile of this rectangle red or green" is a prefix-sum lookup.
+struct Compressed {
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+    // outside[y][x] summed over everything above and left of (x, y)
+    outside_sums: Vec<Vec<u32>>
+}
+
+impl Compressed {
+    fn new(red: &[(i64, i64)]) -> Self {
+        let mut xs = red.iter().map(|(x, _)| *x).collect::<Vec<_>>();
+        let mut ys = red.iter().map(|(_, y)| *y).collect::<Vec<_>>();
+        xs.sort();
+        xs.dedup();
+        ys.sort();
+        ys.dedup();
+
+        let width = xs.len() * 2 + 1;
+        let height = ys.len() * 2 + 1;
+        let mut wall = vec![vec![false; width]; height];
+
+        let cell = |x: i64, y: i64| {
+            (xs.binary_search(&x).unwrap() * 2 + 1, ys.binary_search(&y).unwrap() * 2 + 1)
+        };
+        for (i, a) in red.iter().enumerate() {
+            let b = red[(i + 1) % red.len()];
+            let (ax, ay) = cell(a.0, a.1);
+            let (bx, by) = cell(b.0, b.1);
+            for row in &mut wall[ay.min(by)..=ay.max(by)] {
+                row[ax.min(bx)..=ax.max(bx)].fill(true);
+            }
+        }
+
+        // flood the outside in from the corner, which is always outside
+        let mut outside = vec![vec![false; width]; height];
+        let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+        outside[0][0] = true;
+        queue.push_back((0, 0));
+        while let Some((x, y)) = queue.pop_front() {
+            let neighbours = [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)];
+            for (nx, ny) in neighbours {
+                if nx < width && ny < height && !wall[ny][nx] && !outside[ny][nx] {
+                    outside[ny][nx] = true;
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+
+        let mut outside_sums = vec![vec![0; width + 1]; height + 1];
+        for y in 0..height {
+            for x in 0..width {
+                outside_sums[y + 1][x + 1] = outside[y][x] as u32
+                    + outside_sums[y][x + 1] + outside_sums[y + 1][x] - outside_sums[y][x];
+            }
+        }
+
+        Compressed { xs, ys, outside_sums }
+    }
+    fn is_inside(&self, (ax, ay): (i64, i64), (bx, by): (i64, i64)) -> bool {
+        let x = |x: i64| self.xs.binary_search(&x).unwrap() * 2 + 1;
+        let y = |y: i64| self.ys.binary_search(&y).unwrap() * 2 + 1;
+        let (x1, x2) = (x(ax.min(bx)), x(ax.max(bx)) + 1);
+        let (y1, y2) = (y(ay.min(by)), y(ay.max(by)) + 1);
+        let sums = &self.outside_sums;
+        sums[y2][x2] + sums[y1][x1] - sums[y1][x2] - sums[y2][x1] == 0
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Theater;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Theater::parse(input))
+    }
+
+    fn part_one(theater: &Self::Parsed) -> Answer {
+        theater.largest_rectangle(|_, _| true).into()
+    }
+
+    fn part_two(theater: &Self::Parsed) -> Answer {
+        let compressed = Compressed::new(&theater.red);
+        theater.largest_rectangle(|a, b| compressed.is_inside(a, b)).into()
+    }
+}
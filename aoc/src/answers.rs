@@ -0,0 +1,142 @@
+//! The recorded answers every solution is checked against.
+//!
+//! `answers.txt` at the workspace root holds one answer per line:
+//!
+//! ```text
+//! # year day part input answer
+//! 2021 12 1 sample2.txt 19
+//! ```
+//!
+//! The answer is the rest of the line, with any newlines in a multi-line
+//! answer written as `\n`.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+use aoc_common::{Error, Result};
+
+use crate::{workspace_root, Part};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recorded {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    /// Input file name within the day's directory.
+    pub input: String,
+    pub answer: String
+}
+
+pub fn path() -> PathBuf {
+    workspace_root().join("answers.txt")
+}
+
+/// Loads the recorded answers, treating a missing file as having none.
+pub fn load() -> Result<Vec<Recorded>> {
+    let path = path();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let text = aoc_common::input::read_to_string(&path.to_string_lossy())?;
+    parse(&text).map_err(|err| err.in_file(path))
+}
+
+pub fn parse(text: &str) -> Result<Vec<Recorded>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| parse_line(line).ok_or_else(|| {
+            Error::parse(index + 1, format!("expected `year day part input answer`, found `{}`", line))
+        }))
+        .collect()
+}
+
+fn parse_line(line: &str) -> Option<Recorded> {
+    let mut fields = line.splitn(5, ' ');
+    let year = fields.next()?.parse().ok()?;
+    let day = fields.next()?.parse().ok()?;
+    let part = Part::from_number(fields.next()?.parse().ok()?)?;
+    let input = fields.next()?.to_owned();
+    let answer = unescape(fields.next()?);
+    Some(Recorded { year, day, part, input, answer })
+}
+
+/// Writes `recorded` back out, ordered by year, day, input and part.
+pub fn save(recorded: &[Recorded]) -> Result<()> {
+    let mut recorded = recorded.to_vec();
+    recorded.sort_by(|a, b| {
+        (a.year, a.day, &a.input, a.part as u8).cmp(&(b.year, b.day, &b.input, b.part as u8))
+    });
+
+    let mut text = String::from("# year day part input answer\n");
+    for entry in &recorded {
+        writeln!(text, "{} {} {} {} {}",
+            entry.year, entry.day, entry.part, entry.input, escape(&entry.answer)).unwrap();
+    }
+
+    let path = path();
+    fs::write(&path, text).map_err(|source| Error::Io { path, source })
+}
+
+/// Records `answer`, replacing any earlier answer for the same input and part.
+pub fn record(recorded: &mut Vec<Recorded>, entry: Recorded) {
+    recorded.retain(|existing| {
+        (existing.year, existing.day, existing.part, &existing.input)
+            != (entry.year, entry.day, entry.part, &entry.input)
+    });
+    recorded.push(entry);
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub(crate) fn unescape(answer: &str) -> String {
+    let mut result = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                result.push('\n');
+                chars.next();
+            },
+            ('\\', Some('\\')) => {
+                result.push('\\');
+                chars.next();
+            },
+            _ => result.push(c)
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_comments_and_blank_lines() {
+        let recorded = parse("# header\n\n2021 12 2 sample2.txt 103\n").unwrap();
+        assert_eq!(vec![Recorded {
+            year: 2021,
+            day: 12,
+            part: Part::Two,
+            input: "sample2.txt".to_owned(),
+            answer: "103".to_owned()
+        }], recorded);
+    }
+
+    #[test]
+    fn multi_line_answers_round_trip() {
+        let answer = "#..#\n.##.";
+        assert_eq!(answer, unescape(&escape(answer)));
+        assert_eq!("#..#\\n.##.", escape(answer));
+    }
+
+    #[test]
+    fn reports_the_bad_line() {
+        let err = parse("2021 12 1 sample2.txt 19\n2021 12 3 sample2.txt 1\n").unwrap_err();
+        assert!(err.to_string().starts_with("line 2:"), "{}", err);
+    }
+}
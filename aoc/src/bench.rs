@@ -0,0 +1,156 @@
+//! Times parsing and each part of a day over repeated runs.
+//!
+//! Reports hold one timing per line, in nanoseconds:
+//!
+//! ```text
+//! # year day input step min median
+//! 2022 16 input.txt part2 812345678 820000000
+//! ```
+//!
+//! where the step is `parse`, `part1` or `part2`.
+
+use std::fmt::Write;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc_common::{Error, Result};
+
+use crate::{Day, Part};
+
+/// Changes smaller than this are noise however large they are relatively.
+const NOISE: Duration = Duration::from_micros(100);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub year: u32,
+    pub day: u32,
+    pub input: String,
+    /// `parse`, `part1` or `part2`.
+    pub step: String,
+    pub min: Duration,
+    pub median: Duration
+}
+
+impl Timing {
+    fn new(day: &Day, input: &str, step: &str, mut samples: Vec<Duration>) -> Timing {
+        samples.sort();
+        Timing {
+            year: day.year,
+            day: day.day,
+            input: input.to_owned(),
+            step: step.to_owned(),
+            min: samples[0],
+            median: samples[samples.len() / 2]
+        }
+    }
+
+    fn key(&self) -> (u32, u32, &str, &str) {
+        (self.year, self.day, &self.input, &self.step)
+    }
+}
+
+/// Runs `day` on `input` (a file in its directory) `runs` times, timing a
+/// fresh parse each run and both parts on it.
+pub fn bench(day: &Day, input: &str, runs: usize) -> Result<Vec<Timing>> {
+    let path = day.dir().join(input);
+    let text = aoc_common::input::read_to_string(&path.to_string_lossy())?;
+
+    let mut parse = Vec::with_capacity(runs);
+    let mut parts = [Vec::with_capacity(runs), Vec::with_capacity(runs)];
+
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let parsed = black_box(day.solution.parse(&text).map_err(|err| err.in_file(&path))?);
+        parse.push(start.elapsed());
+
+        for (part, samples) in [Part::One, Part::Two].into_iter().zip(parts.iter_mut()) {
+            let start = Instant::now();
+            black_box(day.solution.solve(part, parsed.as_ref()));
+            samples.push(start.elapsed());
+        }
+    }
+
+    let [part1, part2] = parts;
+    Ok(vec![
+        Timing::new(day, input, "parse", parse),
+        Timing::new(day, input, "part1", part1),
+        Timing::new(day, input, "part2", part2)
+    ])
+}
+
+pub fn load(path: &Path) -> Result<Vec<Timing>> {
+    let text = aoc_common::input::read_to_string(&path.to_string_lossy())?;
+    parse(&text).map_err(|err| err.in_file(path))
+}
+
+pub fn parse(text: &str) -> Result<Vec<Timing>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| parse_line(line).ok_or_else(|| {
+            Error::parse(index + 1, format!("expected `year day input step min median`, found `{}`", line))
+        }))
+        .collect()
+}
+
+fn parse_line(line: &str) -> Option<Timing> {
+    let fields = line.split(' ').collect::<Vec<_>>();
+    if fields.len() != 6 {
+        return None;
+    }
+    Some(Timing {
+        year: fields[0].parse().ok()?,
+        day: fields[1].parse().ok()?,
+        input: fields[2].to_owned(),
+        step: fields[3].to_owned(),
+        min: Duration::from_nanos(fields[4].parse().ok()?),
+        median: Duration::from_nanos(fields[5].parse().ok()?)
+    })
+}
+
+pub fn save(path: &Path, timings: &[Timing]) -> Result<()> {
+    let mut text = String::from("# year day input step min median\n");
+    for timing in timings {
+        writeln!(text, "{} {} {} {} {} {}", timing.year, timing.day, timing.input, timing.step,
+            timing.min.as_nanos(), timing.median.as_nanos()).unwrap();
+    }
+    fs::write(path, text).map_err(|source| Error::Io { path: path.to_path_buf(), source })
+}
+
+/// Timings whose median grew by more than `threshold` (0.1 for 10%) over the
+/// same step in `previous`, paired with the earlier timing.
+pub fn regressions<'a>(previous: &'a [Timing], current: &'a [Timing], threshold: f64) -> Vec<(&'a Timing, &'a Timing)> {
+    current.iter()
+        .flat_map(|timing| {
+            previous.iter()
+                .find(|before| before.key() == timing.key())
+                .map(|before| (before, timing))
+        })
+        .filter(|(before, after)| {
+            after.median > before.median + NOISE
+                && after.median.as_secs_f64() > before.median.as_secs_f64() * (1.0 + threshold)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_only_real_slowdowns() {
+        let previous = parse("2022 16 input.txt part1 900000 1000000\n\
+                              2022 16 input.txt part2 900000 1000000\n\
+                              2022 17 input.txt parse 9000 10000\n").unwrap();
+        let current = parse("2022 16 input.txt part1 900000 1050000\n\
+                             2022 16 input.txt part2 1900000 2000000\n\
+                             2022 17 input.txt parse 30000 40000\n\
+                             2022 18 input.txt parse 9000000 9000000\n").unwrap();
+
+        let flagged = regressions(&previous, &current, 0.1);
+        assert_eq!(1, flagged.len());
+        assert_eq!((2022, 16, "input.txt", "part2"), flagged[0].1.key());
+    }
+}
@@ -0,0 +1,180 @@
+//! The worked examples from each puzzle, with their expected answers.
+//!
+//! A day's `examples.txt` lists its examples, each starting with an
+//! `example <name>` line followed by the expected answer of any part it has
+//! one for. The input is either a file in the day's directory or written out
+//! between ```` ``` ```` fences:
+//!
+//! ````text
+//! example small
+//! part 1: 10
+//! part 2: 36
+//! ```
+//! start-A
+//! A-end
+//! ```
+//!
+//! example larger
+//! file: sample2.txt
+//! part 1: 19
+//! ````
+//!
+//! Multi-line answers are written with `\n` as in `answers.txt`.
+
+use std::path::PathBuf;
+
+use aoc_common::{Error, Result};
+
+use crate::answers::unescape;
+use crate::{Day, Part};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A file name within the day's directory.
+    File(String),
+    Inline(String)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub source: Source,
+    pub answers: Vec<(Part, String)>
+}
+
+/// An expected answer an example didn't produce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub part: Part,
+    pub expected: String,
+    pub actual: String
+}
+
+pub fn path(day: &Day) -> PathBuf {
+    day.dir().join("examples.txt")
+}
+
+/// Loads the day's examples, treating a missing manifest as having none.
+pub fn load(day: &Day) -> Result<Vec<Example>> {
+    let path = path(day);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let text = aoc_common::input::read_to_string(&path.to_string_lossy())?;
+    parse(&text).map_err(|err| err.in_file(path))
+}
+
+pub fn parse(text: &str) -> Result<Vec<Example>> {
+    let mut examples: Vec<Example> = Vec::new();
+    let mut lines = text.lines().enumerate();
+
+    while let Some((index, line)) = lines.next() {
+        let line_number = index + 1;
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix("example ") {
+            examples.push(Example {
+                name: name.trim().to_owned(),
+                source: Source::Inline(String::new()),
+                answers: Vec::new()
+            });
+            continue;
+        }
+
+        let example = examples.last_mut()
+            .ok_or_else(|| Error::parse(line_number, "expected `example <name>` first"))?;
+
+        if line == "```" {
+            let mut input = String::new();
+            loop {
+                match lines.next() {
+                    Some((_, "```")) => break,
+                    Some((_, line)) => {
+                        input.push_str(line);
+                        input.push('\n');
+                    },
+                    None => return Err(Error::parse(line_number, "input isn't closed with ```"))
+                }
+            }
+            example.source = Source::Inline(input);
+        } else if let Some(file) = line.strip_prefix("file: ") {
+            example.source = Source::File(file.trim().to_owned());
+        } else if let Some((part, answer)) = parse_answer(line) {
+            example.answers.push((part, unescape(answer)));
+        } else {
+            return Err(Error::parse(line_number, format!("expected `part <1|2>: <answer>`, `file: <name>` or ```, found `{}`", line)));
+        }
+    }
+
+    Ok(examples)
+}
+
+fn parse_answer(line: &str) -> Option<(Part, &str)> {
+    let (part, answer) = line.strip_prefix("part ")?.split_once(": ")?;
+    Some((Part::from_number(part.parse().ok()?)?, answer))
+}
+
+/// Runs `example` through `day`, returning the expected answers it got wrong.
+pub fn check(day: &Day, example: &Example) -> Result<Vec<Mismatch>> {
+    // with nothing to compare, don't make the day parse an input that may
+    // not be written yet
+    if example.answers.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let parts = example.answers.iter().map(|(part, _)| *part).collect::<Vec<_>>();
+
+    let answers = match &example.source {
+        Source::File(file) => day.run(&parts, &day.dir().join(file).to_string_lossy())?,
+        Source::Inline(input) => day.solve(&parts, input)?
+    };
+
+    Ok(example.answers.iter()
+        .zip(answers)
+        .map(|((part, expected), actual)| (*part, expected, actual))
+        .filter(|(_, expected, actual)| **expected != actual.to_string())
+        .map(|(part, expected, actual)| Mismatch {
+            part,
+            expected: expected.clone(),
+            actual: actual.to_string()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_files_and_inline_inputs() {
+        let examples = parse("# comment\nexample small\npart 2: 36\n```\nstart-A\n\nA-end\n```\n\nexample larger\nfile: sample2.txt\npart 1: 19\n").unwrap();
+        assert_eq!(vec![
+            Example {
+                name: "small".to_owned(),
+                source: Source::Inline("start-A\n\nA-end\n".to_owned()),
+                answers: vec![(Part::Two, "36".to_owned())]
+            },
+            Example {
+                name: "larger".to_owned(),
+                source: Source::File("sample2.txt".to_owned()),
+                answers: vec![(Part::One, "19".to_owned())]
+            }
+        ], examples);
+    }
+
+    #[test]
+    fn skips_examples_without_answers() {
+        // 2025 day 2 can't parse an empty input
+        let day = crate::find(2025, 2).unwrap();
+        let example = Example { name: "sample".to_owned(), source: Source::Inline(String::new()), answers: Vec::new() };
+        assert_eq!(Vec::<Mismatch>::new(), check(day, &example).unwrap());
+    }
+
+    #[test]
+    fn reports_an_unclosed_input() {
+        let err = parse("example small\n```\nstart-A\n").unwrap_err();
+        assert!(err.to_string().starts_with("line 2:"), "{}", err);
+    }
+}
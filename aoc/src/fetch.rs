@@ -0,0 +1,184 @@
+//! Downloads puzzle inputs, and makes the requests `submit` sends to the site.
+//!
+//! Inputs are cached as `input.txt` in the day's directory under the cache
+//! directory (the workspace root by default), so each input is requested at
+//! most once.
+//! Responses that aren't an input, such as the "please log in" page or the
+//! 404 for a day that hasn't unlocked, are reported rather than saved. Requests
+//! are spaced at least `min_interval` apart, across runs, using the time of
+//! the last request kept in `.last-request` in the cache directory.
+
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::workspace_root;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = "aoc input fetcher (andrewglowacki)";
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// No session cookie was configured, or the site didn't accept it.
+    NotLoggedIn,
+    /// The site has no input for the day, usually because it hasn't unlocked yet.
+    NotFound { year: u32, day: u32 },
+    /// Any other response the site shouldn't give for an input.
+    Unexpected { status: u16, body: String },
+    Http(String),
+    Io { path: PathBuf, source: io::Error }
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::NotLoggedIn => {
+                write!(f, "not logged in: put your session cookie in AOC_SESSION or ~/aoc-cookie")
+            },
+            FetchError::NotFound { year, day } => {
+                write!(f, "no input for {} day {} (has it unlocked yet?)", year, day)
+            },
+            FetchError::Unexpected { status, body } => {
+                write!(f, "unexpected response ({}): {}", status, body.lines().next().unwrap_or(""))
+            },
+            FetchError::Http(message) => write!(f, "request failed: {}", message),
+            FetchError::Io { path, source } => write!(f, "{}: {}", path.display(), source)
+        }
+    }
+}
+
+impl error::Error for FetchError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            FetchError::Io { source, .. } => Some(source),
+            _ => None
+        }
+    }
+}
+
+pub struct Fetcher {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub cache_dir: PathBuf,
+    pub min_interval: Duration
+}
+
+impl Fetcher {
+    /// Configured from the environment: `AOC_BASE_URL` overrides the site and
+    /// the session cookie comes from `AOC_SESSION` or else `~/aoc-cookie`.
+    pub fn from_env() -> Fetcher {
+        let session = env::var("AOC_SESSION").ok()
+            .or_else(|| {
+                let home = env::var_os("HOME")?;
+                fs::read_to_string(Path::new(&home).join("aoc-cookie")).ok()
+            })
+            .map(|session| session.trim().to_owned())
+            .filter(|session| !session.is_empty());
+
+        Fetcher {
+            base_url: env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned()),
+            session,
+            cache_dir: workspace_root(),
+            min_interval: DEFAULT_MIN_INTERVAL
+        }
+    }
+
+    /// The registered day's directory (2020's days 10 to 15 are `dayA` to
+    /// `dayF`), or `{year}/day{day}` for a day that isn't registered yet.
+    pub fn input_path(&self, year: u32, day: u32) -> PathBuf {
+        let dir = match crate::find(year, day) {
+            Some(solution) => PathBuf::from(solution.dir),
+            None => Path::new(&year.to_string()).join(format!("day{}", day))
+        };
+        self.cache_dir.join(dir).join("input.txt")
+    }
+
+    /// Returns the day's input, downloading it only if it isn't cached yet.
+    pub fn fetch(&self, year: u32, day: u32) -> Result<String, FetchError> {
+        let path = self.input_path(year, day);
+        if path.exists() {
+            return fs::read_to_string(&path).map_err(|source| FetchError::Io { path, source });
+        }
+
+        let input = self.download(year, day)?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|source| FetchError::Io { path: dir.to_path_buf(), source })?;
+        }
+        fs::write(&path, &input).map_err(|source| FetchError::Io { path, source })?;
+        Ok(input)
+    }
+
+    fn download(&self, year: u32, day: u32) -> Result<String, FetchError> {
+        let (status, body) = self.request(&format!("/{}/day/{}/input", year, day), None)?;
+
+        // without a valid session the site answers 400 (or 500 for a stale
+        // cookie) with a "please log in" message instead of the input
+        match status {
+            404 => Err(FetchError::NotFound { year, day }),
+            _ if body.to_ascii_lowercase().contains("please log in") => Err(FetchError::NotLoggedIn),
+            400 | 401 | 403 | 500 => Err(FetchError::NotLoggedIn),
+            200 if !body.trim_start().starts_with('<') => Ok(body),
+            _ => Err(FetchError::Unexpected { status, body })
+        }
+    }
+
+    /// Makes a rate-limited request for `path` on the site, a GET or else a
+    /// form POST of `form`, returning the status and body of any response.
+    pub(crate) fn request(&self, path: &str, form: Option<&[(&str, &str)]>) -> Result<(u16, String), FetchError> {
+        let session = self.session.as_ref().ok_or(FetchError::NotLoggedIn)?;
+        let url = format!("{}{}", self.base_url.trim_end_matches('/'), path);
+
+        self.wait_turn()?;
+
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        let cookie = format!("session={}", session);
+        let response = match form {
+            Some(form) => agent.post(&url).set("Cookie", &cookie).send_form(form),
+            None => agent.get(&url).set("Cookie", &cookie).call()
+        };
+
+        match response {
+            Ok(response) => Ok((response.status(), read_body(response)?)),
+            Err(ureq::Error::Status(status, response)) => Ok((status, read_body(response)?)),
+            Err(err) => Err(FetchError::Http(err.to_string()))
+        }
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request, then
+    /// records this one.
+    fn wait_turn(&self) -> Result<(), FetchError> {
+        let path = self.cache_dir.join(".last-request");
+
+        let last = fs::read_to_string(&path).ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+
+        if let Some(last) = last {
+            let elapsed = SystemTime::now().duration_since(last).unwrap_or_default();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        fs::create_dir_all(&self.cache_dir)
+            .and_then(|_| fs::write(&path, now.as_millis().to_string()))
+            .map_err(|source| FetchError::Io { path, source })
+    }
+}
+
+fn read_body(response: ureq::Response) -> Result<String, FetchError> {
+    response.into_string().map_err(|err| FetchError::Http(err.to_string()))
+}
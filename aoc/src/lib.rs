@@ -0,0 +1,117 @@
+use std::any::Any;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{Answer, Solution};
+
+pub mod answers;
+pub mod bench;
+pub mod examples;
+pub mod fetch;
+pub mod scaffold;
+pub mod submit;
+mod registry;
+
+pub use registry::DAYS;
+
+/// A single puzzle day linked into the runner.
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    /// Directory of the day's crate, relative to the workspace root.
+    pub dir: &'static str,
+    pub solution: &'static dyn Solver
+}
+
+/// A `Solution` with its parsed input type erased, so every day can sit in
+/// the one registry.
+pub trait Solver: Sync {
+    fn parse(&self, input: &str) -> aoc_common::Result<Box<dyn Any>>;
+
+    fn solve(&self, part: Part, parsed: &dyn Any) -> Answer;
+}
+
+impl<S> Solver for S
+    where S: Solution + Sync, S::Parsed: 'static
+{
+    fn parse(&self, input: &str) -> aoc_common::Result<Box<dyn Any>> {
+        S::parse(input).map(|parsed| Box::new(parsed) as Box<dyn Any>)
+    }
+
+    fn solve(&self, part: Part, parsed: &dyn Any) -> Answer {
+        let parsed = parsed.downcast_ref::<S::Parsed>()
+            .expect("parsed input belongs to a different solution");
+        match part {
+            Part::One => S::part_one(parsed),
+            Part::Two => S::part_two(parsed)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two
+}
+
+impl Part {
+    pub fn from_number(number: u8) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2")
+        }
+    }
+}
+
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.year == year && entry.day == day)
+}
+
+impl Day {
+    pub fn dir(&self) -> PathBuf {
+        workspace_root().join(self.dir)
+    }
+
+    /// Resolves `input` as given if it exists, otherwise relative to the
+    /// day's directory so `--input sample.txt` picks up that day's sample.
+    pub fn input_path(&self, input: &str) -> PathBuf {
+        let path = Path::new(input);
+        if path.is_absolute() || path.exists() {
+            path.to_path_buf()
+        } else {
+            self.dir().join(path)
+        }
+    }
+
+    /// Reads and parses `file_name` once, then solves each of `parts`.
+    pub fn run(&self, parts: &[Part], file_name: &str) -> aoc_common::Result<Vec<Answer>> {
+        let input = aoc_common::input::read_to_string(file_name)?;
+        self.solve(parts, &input).map_err(|err| err.in_file(file_name))
+    }
+
+    /// Parses `input` once, then solves each of `parts`.
+    pub fn solve(&self, parts: &[Part], input: &str) -> aoc_common::Result<Vec<Answer>> {
+        let parsed = self.solution.parse(input)?;
+
+        Ok(parts.iter()
+            .map(|part| self.solution.solve(*part, parsed.as_ref()))
+            .collect())
+    }
+}
@@ -0,0 +1,311 @@
+use std::path::PathBuf;
+use std::process;
+
+use aoc::answers::{self, Recorded};
+use aoc::bench;
+use aoc::examples;
+use aoc::fetch::Fetcher;
+use aoc::submit::{self, Ledger, Verdict};
+use aoc::Part;
+use aoc_common::Answer;
+use clap::{Args, Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one or both parts of a day against an input file
+    Run(RunArgs),
+    /// Create a day's crate from the dayX template and register it with the runner
+    New {
+        #[arg(long)]
+        year: u32,
+        #[arg(long)]
+        day: u32
+    },
+    /// Check a day's answers for the examples in its examples.txt, or every day's
+    Test {
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long)]
+        day: Option<u32>
+    },
+    /// Time parsing and both parts, for one day or every day with the input present
+    Bench(BenchArgs),
+    /// Solve a part and submit the answer, unless an earlier verdict rules it out
+    Submit(SubmitArgs),
+    /// Download a day's input into its directory, unless it's already there
+    Fetch {
+        #[arg(long)]
+        year: u32,
+        #[arg(long)]
+        day: u32
+    }
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(long)]
+    year: u32,
+    #[arg(long)]
+    day: u32,
+    /// Part to run (1 or 2), both parts when omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Input file, either a path or a file name within the day's directory
+    #[arg(long, default_value = "input.txt")]
+    input: String,
+    /// Save the answers to answers.txt as the expected ones for this input
+    #[arg(long)]
+    record: bool
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only bench days of this year
+    #[arg(long)]
+    year: Option<u32>,
+    /// Only bench this day
+    #[arg(long)]
+    day: Option<u32>,
+    /// Input file name within each day's directory
+    #[arg(long, default_value = "input.txt")]
+    input: String,
+    /// Times to run each day
+    #[arg(long, default_value_t = 10)]
+    runs: usize,
+    /// Where to write the report
+    #[arg(long, default_value = "bench.txt")]
+    report: PathBuf,
+    /// An earlier report to flag regressions against
+    #[arg(long)]
+    compare: Option<PathBuf>,
+    /// Percent a median has to grow by to count as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    #[arg(long)]
+    year: u32,
+    #[arg(long)]
+    day: u32,
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Input file, either a path or a file name within the day's directory
+    #[arg(long, default_value = "input.txt")]
+    input: String
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let day = aoc::find(args.year, args.day)
+        .ok_or_else(|| format!("No solution registered for {} day {}", args.year, args.day))?;
+
+    let path = day.input_path(&args.input);
+    if !path.exists() {
+        return Err(format!("Input file not found: {}", path.display()));
+    }
+    let file_name = path.to_string_lossy();
+
+    let parts = match args.part.and_then(Part::from_number) {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two]
+    };
+
+    let answers = day.run(&parts, &file_name).map_err(|err| err.to_string())?;
+    for (part, answer) in parts.iter().zip(&answers) {
+        let answer = answer.to_string();
+        if answer.contains('\n') {
+            println!("Part {}:\n{}", part, answer);
+        } else {
+            println!("Part {}: {}", part, answer);
+        }
+    }
+
+    if args.record {
+        record(day, &args.input, &parts, answers)?;
+    }
+
+    Ok(())
+}
+
+fn record(day: &aoc::Day, input: &str, parts: &[Part], answers: Vec<Answer>) -> Result<(), String> {
+    if input.contains(['/', '\\']) {
+        return Err(format!("--record needs the name of a file in {}, not a path", day.dir));
+    }
+
+    let mut recorded = answers::load().map_err(|err| err.to_string())?;
+    for (part, answer) in parts.iter().zip(answers) {
+        if answer == Answer::Unsolved {
+            continue;
+        }
+        answers::record(&mut recorded, Recorded {
+            year: day.year,
+            day: day.day,
+            part: *part,
+            input: input.to_owned(),
+            answer: answer.to_string()
+        });
+    }
+    answers::save(&recorded).map_err(|err| err.to_string())?;
+
+    println!("Recorded in {}", answers::path().display());
+    Ok(())
+}
+
+/// The registered days matching the given year and day, when given.
+fn select(year: Option<u32>, day: Option<u32>) -> Result<Vec<&'static aoc::Day>, String> {
+    let days = aoc::DAYS.iter()
+        .filter(|entry| year.is_none_or(|year| entry.year == year))
+        .filter(|entry| day.is_none_or(|day| entry.day == day))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        return Err("No solutions match".to_owned());
+    }
+    Ok(days)
+}
+
+fn test(year: Option<u32>, day: Option<u32>) -> Result<(), String> {
+    let mut checked = 0;
+    let mut failed = 0;
+
+    for day in select(year, day)? {
+        for example in examples::load(day).map_err(|err| err.to_string())? {
+            let name = format!("{} day {} example {}", day.year, day.day, example.name);
+            checked += 1;
+            match examples::check(day, &example) {
+                Ok(mismatches) if mismatches.is_empty() => println!("{}: ok", name),
+                Ok(mismatches) => {
+                    failed += 1;
+                    for mismatch in mismatches {
+                        println!("{} part {}: expected {:?}, got {:?}", name, mismatch.part, mismatch.expected, mismatch.actual);
+                    }
+                },
+                Err(err) => {
+                    failed += 1;
+                    println!("{}: {}", name, err);
+                }
+            }
+        }
+    }
+
+    if checked == 0 {
+        return Err("No examples to check".to_owned());
+    }
+    if failed > 0 {
+        return Err(format!("{} of {} examples failed", failed, checked));
+    }
+    println!("{} examples passed", checked);
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    // read before anything is written in case it's the same file as the report
+    let previous = match &args.compare {
+        Some(path) => Some(bench::load(path).map_err(|err| err.to_string())?),
+        None => None
+    };
+
+    // every day runs at least once
+    let runs = args.runs.max(1);
+    let mut timings = Vec::new();
+    for day in select(args.year, args.day)? {
+        if !day.dir().join(&args.input).exists() {
+            continue;
+        }
+        let day_timings = bench::bench(day, &args.input, runs).map_err(|err| err.to_string())?;
+        let columns = day_timings.iter()
+            .map(|timing| format!("{} {:>10.3?} / {:>10.3?}", timing.step, timing.min, timing.median))
+            .collect::<Vec<_>>();
+        println!("{} day {:>2}  {}", day.year, day.day, columns.join("  "));
+        timings.extend(day_timings);
+    }
+    if timings.is_empty() {
+        return Err(format!("None of the matching days have {}", args.input));
+    }
+
+    bench::save(&args.report, &timings).map_err(|err| err.to_string())?;
+    println!("(min / median of {} runs) written to {}", runs, args.report.display());
+
+    if let Some(previous) = previous {
+        let regressions = bench::regressions(&previous, &timings, args.threshold / 100.0);
+        for (before, after) in &regressions {
+            println!("{} day {} {}: {:.3?} -> {:.3?}", after.year, after.day, after.step, before.median, after.median);
+        }
+        if !regressions.is_empty() {
+            return Err(format!("{} regressions against {}", regressions.len(), args.compare.unwrap().display()));
+        }
+    }
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<(), String> {
+    let day = aoc::find(args.year, args.day)
+        .ok_or_else(|| format!("No solution registered for {} day {}", args.year, args.day))?;
+
+    let path = day.input_path(&args.input);
+    if !path.exists() {
+        return Err(format!("Input file not found: {}", path.display()));
+    }
+
+    let part = Part::from_number(args.part).unwrap();
+    let answer = day.run(&[part], &path.to_string_lossy())
+        .map_err(|err| err.to_string())?
+        .remove(0);
+    if answer == Answer::Unsolved {
+        return Err(format!("Part {} isn't solved yet", part));
+    }
+    println!("Part {}: {}", part, answer);
+
+    let fetcher = Fetcher::from_env();
+    let mut ledger = Ledger::load(&fetcher).map_err(|err| err.to_string())?;
+    let verdict = submit::submit(&fetcher, &mut ledger, day.year, day.day, part, &answer.to_string())?;
+    println!("{}", verdict);
+
+    // a right answer is the expected one for this input from now on
+    if verdict == Verdict::Correct && !args.input.contains(['/', '\\']) {
+        record(day, &args.input, &[part], vec![answer])?;
+    }
+    Ok(())
+}
+
+fn new(year: u32, day: u32) -> Result<(), String> {
+    let dir = aoc::scaffold::new_day(&aoc::workspace_root(), year, day)?;
+    println!("Created {}", dir.display());
+    Ok(())
+}
+
+fn fetch(year: u32, day: u32) -> Result<(), String> {
+    let fetcher = Fetcher::from_env();
+    let input = fetcher.fetch(year, day).map_err(|err| err.to_string())?;
+
+    println!("Input in {}, head:", fetcher.input_path(year, day).display());
+    for line in input.lines().take(10) {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Test { year, day } => test(year, day),
+        Command::Bench(args) => bench(args),
+        Command::Submit(args) => submit(args),
+        Command::New { year, day } => new(year, day),
+        Command::Fetch { year, day } => fetch(year, day)
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
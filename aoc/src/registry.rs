@@ -0,0 +1,134 @@
+use crate::Day;
+
+macro_rules! days {
+    ($($year:literal, $day:literal, $dir:literal => $krate:ident;)*) => {
+        /// Every solved day, ordered by year and then day.
+        pub static DAYS: &[Day] = &[
+            $(Day {
+                year: $year,
+                day: $day,
+                dir: concat!(stringify!($year), "/", $dir),
+                solution: &$krate::Day
+            },)*
+        ];
+    };
+}
+
+days! {
+    2020, 1, "day1" => aoc2020_day1;
+    2020, 2, "day2" => aoc2020_day2;
+    2020, 3, "day3" => aoc2020_day3;
+    2020, 4, "day4" => aoc2020_day4;
+    2020, 5, "day5" => aoc2020_day5;
+    2020, 6, "day6" => aoc2020_day6;
+    2020, 7, "day7" => aoc2020_day7;
+    2020, 8, "day8" => aoc2020_day8;
+    2020, 9, "day9" => aoc2020_day9;
+    2020, 10, "dayA" => aoc2020_day10;
+    2020, 11, "dayB" => aoc2020_day11;
+    2020, 12, "dayC" => aoc2020_day12;
+    2020, 13, "dayD" => aoc2020_day13;
+    2020, 14, "dayE" => aoc2020_day14;
+    2020, 15, "dayF" => aoc2020_day15;
+    2020, 16, "day16" => aoc2020_day16;
+    2020, 17, "day17" => aoc2020_day17;
+    2020, 18, "day18" => aoc2020_day18;
+    2020, 19, "day19" => aoc2020_day19;
+    2020, 20, "day20" => aoc2020_day20;
+    2020, 21, "day21" => aoc2020_day21;
+    2020, 22, "day22" => aoc2020_day22;
+    2020, 23, "day23" => aoc2020_day23;
+    2020, 24, "day24" => aoc2020_day24;
+    2020, 25, "day25" => aoc2020_day25;
+
+    2021, 1, "day1" => aoc2021_day1;
+    2021, 2, "day2" => aoc2021_day2;
+    2021, 3, "day3" => aoc2021_day3;
+    2021, 4, "day4" => aoc2021_day4;
+    2021, 5, "day5" => aoc2021_day5;
+    2021, 6, "day6" => aoc2021_day6;
+    2021, 7, "day7" => aoc2021_day7;
+    2021, 8, "day8" => aoc2021_day8;
+    2021, 9, "day9" => aoc2021_day9;
+    2021, 10, "day10" => aoc2021_day10;
+    2021, 11, "day11" => aoc2021_day11;
+    2021, 12, "day12" => aoc2021_day12;
+    2021, 13, "day13" => aoc2021_day13;
+    2021, 14, "day14" => aoc2021_day14;
+    2021, 15, "day15" => aoc2021_day15;
+    2021, 16, "day16" => aoc2021_day16;
+    2021, 17, "day17" => aoc2021_day17;
+    2021, 18, "day18" => aoc2021_day18;
+    2021, 19, "day19" => aoc2021_day19;
+    2021, 20, "day20" => aoc2021_day20;
+    2021, 21, "day21" => aoc2021_day21;
+    2021, 22, "day22" => aoc2021_day22;
+    2021, 23, "day23" => aoc2021_day23;
+    2021, 24, "day24" => aoc2021_day24;
+    2021, 25, "day25" => aoc2021_day25;
+
+    2022, 1, "day1" => aoc2022_day1;
+    2022, 2, "day2" => aoc2022_day2;
+    2022, 3, "day3" => aoc2022_day3;
+    2022, 4, "day4" => aoc2022_day4;
+    2022, 5, "day5" => aoc2022_day5;
+    2022, 6, "day6" => aoc2022_day6;
+    2022, 7, "day7" => aoc2022_day7;
+    2022, 8, "day8" => aoc2022_day8;
+    2022, 9, "day9" => aoc2022_day9;
+    2022, 10, "day10" => aoc2022_day10;
+    2022, 11, "day11" => aoc2022_day11;
+    2022, 12, "day12" => aoc2022_day12;
+    2022, 13, "day13" => aoc2022_day13;
+    2022, 14, "day14" => aoc2022_day14;
+    2022, 15, "day15" => aoc2022_day15;
+    2022, 16, "day16" => aoc2022_day16;
+    2022, 17, "day17" => aoc2022_day17;
+    2022, 18, "day18" => aoc2022_day18;
+    2022, 19, "day19" => aoc2022_day19;
+    2022, 20, "day20" => aoc2022_day20;
+    2022, 21, "day21" => aoc2022_day21;
+    2022, 22, "day22" => aoc2022_day22;
+    2022, 23, "day23" => aoc2022_day23;
+    2022, 24, "day24" => aoc2022_day24;
+    2022, 25, "day25" => aoc2022_day25;
+
+    2023, 1, "day1" => aoc2023_day1;
+    2023, 2, "day2" => aoc2023_day2;
+    2023, 3, "day3" => aoc2023_day3;
+    2023, 4, "day4" => aoc2023_day4;
+    2023, 5, "day5" => aoc2023_day5;
+    2023, 6, "day6" => aoc2023_day6;
+    2023, 7, "day7" => aoc2023_day7;
+    2023, 8, "day8" => aoc2023_day8;
+    2023, 9, "day9" => aoc2023_day9;
+    2023, 10, "day10" => aoc2023_day10;
+    2023, 11, "day11" => aoc2023_day11;
+    2023, 12, "day12" => aoc2023_day12;
+    2023, 13, "day13" => aoc2023_day13;
+    2023, 14, "day14" => aoc2023_day14;
+    2023, 15, "day15" => aoc2023_day15;
+    2023, 16, "day16" => aoc2023_day16;
+    2023, 17, "day17" => aoc2023_day17;
+    2023, 18, "day18" => aoc2023_day18;
+    2023, 19, "day19" => aoc2023_day19;
+    2023, 20, "day20" => aoc2023_day20;
+    2023, 21, "day21" => aoc2023_day21;
+    2023, 22, "day22" => aoc2023_day22;
+    2023, 23, "day23" => aoc2023_day23;
+    2023, 24, "day24" => aoc2023_day24;
+    2023, 25, "day25" => aoc2023_day25;
+
+    2025, 1, "day1" => aoc2025_day1;
+    2025, 2, "day2" => aoc2025_day2;
+    2025, 3, "day3" => aoc2025_day3;
+    2025, 4, "day4" => aoc2025_day4;
+    2025, 5, "day5" => aoc2025_day5;
+    2025, 6, "day6" => aoc2025_day6;
+    2025, 7, "day7" => aoc2025_day7;
+    2025, 8, "day8" => aoc2025_day8;
+    2025, 9, "day9" => aoc2025_day9;
+    2025, 10, "day10" => aoc2025_day10;
+    2025, 11, "day11" => aoc2025_day11;
+    2025, 12, "day12" => aoc2025_day12;
+}
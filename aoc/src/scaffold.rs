@@ -0,0 +1,154 @@
+//! `aoc new`: creates a day's crate from the `dayX` template and wires it
+//! into the workspace and the runner.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE_MANIFEST: &str = include_str!("../../dayX/Cargo.toml");
+const TEMPLATE_LIB: &str = include_str!("../../dayX/src/lib.rs");
+
+/// Commented out until the puzzle's example answers are filled in.
+const EXAMPLES: &str = "# example sample\n# file: sample.txt\n# part 1: <answer>\n";
+
+/// Creates `{year}/day{day}` under `root` and registers it. Refuses to touch
+/// a day that already has a directory or a registry entry.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<PathBuf, String> {
+    let dir = root.join(year.to_string()).join(format!("day{}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let registry_path = root.join("aoc/src/registry.rs");
+    let registry = read(&registry_path)?;
+    if entries(&registry, registry_key).any(|key| key == (year, day)) {
+        return Err(format!("{} day {} is already registered in {}", year, day, registry_path.display()));
+    }
+
+    let krate = format!("aoc{}-day{}", year, day);
+
+    fs::create_dir_all(dir.join("src")).map_err(|err| format!("failed to create {}: {}", dir.display(), err))?;
+    write(&dir.join("Cargo.toml"), &TEMPLATE_MANIFEST.replace("aocYYYY-dayX", &krate))?;
+    write(&dir.join("src/lib.rs"), TEMPLATE_LIB)?;
+    write(&dir.join("sample.txt"), "")?;
+    write(&dir.join("examples.txt"), EXAMPLES)?;
+
+    let workspace_path = root.join("Cargo.toml");
+    let workspace = read(&workspace_path)?;
+    write(&workspace_path, &add_workspace_year(&workspace, year))?;
+
+    let runner_path = root.join("aoc/Cargo.toml");
+    let runner = read(&runner_path)?;
+    let dependency = format!("{} = {{ path = \"../{}/day{}\" }}", krate, year, day);
+    write(&runner_path, &insert_sorted(&runner, dependency_key, (year, day), &dependency, false))?;
+
+    let entry = format!("    {}, {}, \"day{}\" => {};", year, day, day, krate.replace('-', "_"));
+    write(&registry_path, &insert_sorted(&registry, registry_key, (year, day), &entry, true))?;
+
+    Ok(dir)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("failed to read {}: {}", path.display(), err))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|err| format!("failed to write {}: {}", path.display(), err))
+}
+
+/// `aoc2022-day17 = { path = ... }` in the runner's dependencies.
+fn dependency_key(line: &str) -> Option<(u32, u32)> {
+    let name = line.strip_prefix("aoc")?.split(' ').next()?;
+    let (year, day) = name.split_once("-day")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// `    2022, 17, "day17" => aoc2022_day17;` in the registry.
+fn registry_key(line: &str) -> Option<(u32, u32)> {
+    let mut fields = line.trim().split(", ");
+    let year = fields.next()?.parse().ok()?;
+    let day = fields.next()?.parse().ok()?;
+    Some((year, day))
+}
+
+fn entries<'a>(text: &'a str, key: fn(&str) -> Option<(u32, u32)>) -> impl Iterator<Item = (u32, u32)> + 'a {
+    text.lines().flat_map(key)
+}
+
+/// Inserts `new_line` among the lines `key` recognises, keeping them ordered
+/// by year and day. With `separate_years` a day that starts a new year gets
+/// a blank line between it and the year before.
+fn insert_sorted(
+    text: &str,
+    key: fn(&str) -> Option<(u32, u32)>,
+    new_key: (u32, u32),
+    new_line: &str,
+    separate_years: bool) -> String
+{
+    let mut lines = text.lines().map(|line| line.to_owned()).collect::<Vec<_>>();
+
+    let keyed = lines.iter()
+        .enumerate()
+        .flat_map(|(index, line)| key(line).map(|key| (index, key)))
+        .collect::<Vec<_>>();
+
+    let before = keyed.iter().rfind(|(_, key)| *key < new_key);
+    let index = match (before, keyed.first()) {
+        (Some((index, _)), _) => index + 1,
+        (None, Some((index, _))) => *index,
+        (None, None) => panic!("no entries to insert {} next to", new_line)
+    };
+
+    let mut inserted = vec![new_line.to_owned()];
+    if separate_years {
+        if let Some((_, (year, _))) = before {
+            if *year != new_key.0 {
+                inserted.insert(0, String::new());
+            }
+        }
+    }
+    lines.splice(index..index, inserted);
+
+    lines.join("\n") + "\n"
+}
+
+/// Adds `"{year}/*"` to the workspace members unless the year is already there.
+fn add_workspace_year(manifest: &str, year: u32) -> String {
+    let member = format!("\"{}/*\",", year);
+    if manifest.lines().any(|line| line.trim() == member) {
+        return manifest.to_owned();
+    }
+
+    let year_key = |line: &str| {
+        let year = line.trim().strip_prefix('"')?.strip_suffix("/*\",")?;
+        year.parse().ok().map(|year| (year, 0))
+    };
+    insert_sorted(manifest, year_key, (year, 0), &format!("    {}", member), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "days! {\n    2023, 25, \"day25\" => aoc2023_day25;\n\n    2025, 1, \"day1\" => aoc2025_day1;\n    2025, 10, \"day10\" => aoc2025_day10;\n}\n";
+
+    #[test]
+    fn keeps_days_in_order() {
+        let text = insert_sorted(REGISTRY, registry_key, (2025, 5), "    2025, 5, \"day5\" => aoc2025_day5;", true);
+        assert_eq!(vec![(2023, 25), (2025, 1), (2025, 5), (2025, 10)], entries(&text, registry_key).collect::<Vec<_>>());
+        assert!(text.contains("aoc2025_day1;\n    2025, 5,"));
+    }
+
+    #[test]
+    fn new_year_gets_its_own_block() {
+        let text = insert_sorted(REGISTRY, registry_key, (2026, 1), "    2026, 1, \"day1\" => aoc2026_day1;", true);
+        assert!(text.contains("aoc2025_day10;\n\n    2026, 1, \"day1\" => aoc2026_day1;\n}"));
+    }
+
+    #[test]
+    fn adds_workspace_year_once() {
+        let manifest = "members = [\n    \"aoc\",\n    \"2023/*\",\n    \"2025/*\",\n]\n";
+        let added = add_workspace_year(manifest, 2026);
+        assert!(added.contains("\"2025/*\",\n    \"2026/*\",\n]"));
+        assert_eq!(added, add_workspace_year(&added, 2026));
+    }
+}
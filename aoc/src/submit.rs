@@ -0,0 +1,254 @@
+//! Submits answers and keeps a ledger of the site's verdicts.
+//!
+//! `submissions.txt` in the fetcher's cache directory holds one verdict per
+//! line:
+//!
+//! ```text
+//! # year day part verdict answer
+//! 2022 17 2 too-low 1514285714288
+//! ```
+//!
+//! An answer the ledger already rules out (the same answer was wrong, a
+//! bound it's past was too high or too low, or the part is already solved)
+//! is refused without asking the site.
+
+use std::fmt::{self, Write};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc_common::{Error, Result};
+
+use crate::fetch::{FetchError, Fetcher};
+use crate::Part;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the previous answer; says how long to wait
+    /// when the site does.
+    RateLimited(Option<Duration>),
+    /// The part was already solved (or its first part wasn't yet).
+    WrongLevel
+}
+
+impl Verdict {
+    /// Parses the verdict out of the page the site answers a submission with.
+    pub fn parse(page: &str) -> Option<Verdict> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Some(Verdict::TooHigh)
+            } else if page.contains("your answer is too low") {
+                Some(Verdict::TooLow)
+            } else {
+                Some(Verdict::Wrong)
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Some(Verdict::RateLimited(parse_wait(page)))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Verdict::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the verdict is about the answer itself and belongs in the ledger.
+    pub fn is_recorded(&self) -> bool {
+        matches!(self, Verdict::Correct | Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::RateLimited(_) => "rate-limited",
+            Verdict::WrongLevel => "wrong-level"
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Verdict> {
+        [Verdict::Correct, Verdict::Wrong, Verdict::TooHigh, Verdict::TooLow].into_iter()
+            .find(|verdict| verdict.name() == name)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::Wrong => write!(f, "That's not the right answer."),
+            Verdict::TooHigh => write!(f, "That's not the right answer, it's too high."),
+            Verdict::TooLow => write!(f, "That's not the right answer, it's too low."),
+            Verdict::RateLimited(Some(wait)) => {
+                write!(f, "Answered too recently, try again in {}s.", wait.as_secs())
+            },
+            Verdict::RateLimited(None) => write!(f, "Answered too recently, try again later."),
+            Verdict::WrongLevel => write!(f, "That part isn't open: already solved, or the first part isn't yet.")
+        }
+    }
+}
+
+/// "You have 1m 23s left to wait."
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for amount in page[start..end].split_whitespace() {
+        let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String
+}
+
+pub struct Ledger {
+    pub path: PathBuf,
+    pub submissions: Vec<Submission>
+}
+
+impl Ledger {
+    /// Loads the ledger kept beside `fetcher`'s inputs, empty if there isn't one yet.
+    pub fn load(fetcher: &Fetcher) -> Result<Ledger> {
+        let path = fetcher.cache_dir.join("submissions.txt");
+        if !path.exists() {
+            return Ok(Ledger { path, submissions: Vec::new() });
+        }
+        let text = aoc_common::input::read_to_string(&path.to_string_lossy())?;
+        let submissions = parse(&text).map_err(|err| err.in_file(&path))?;
+        Ok(Ledger { path, submissions })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let mut text = String::from("# year day part verdict answer\n");
+        for entry in &self.submissions {
+            writeln!(text, "{} {} {} {} {}",
+                entry.year, entry.day, entry.part, entry.verdict.name(), entry.answer).unwrap();
+        }
+        fs::write(&self.path, text).map_err(|source| Error::Io { path: self.path.clone(), source })
+    }
+
+    /// Why `answer` can't be right according to earlier verdicts, if it can't.
+    pub fn refusal(&self, year: u32, day: u32, part: Part, answer: &str) -> Option<String> {
+        let number = answer.parse::<i128>().ok();
+
+        self.submissions.iter()
+            .filter(|entry| (entry.year, entry.day, entry.part) == (year, day, part))
+            .find_map(|entry| {
+                let bound = entry.answer.parse::<i128>().ok();
+                match (entry.verdict, number, bound) {
+                    (Verdict::Correct, _, _) => {
+                        Some(format!("part {} is already solved with {}", part, entry.answer))
+                    },
+                    _ if entry.answer == answer => {
+                        Some(format!("{} was already submitted: {}", answer, entry.verdict))
+                    },
+                    (Verdict::TooHigh, Some(number), Some(bound)) if number >= bound => {
+                        Some(format!("{} was too high, so {} is too", bound, number))
+                    },
+                    (Verdict::TooLow, Some(number), Some(bound)) if number <= bound => {
+                        Some(format!("{} was too low, so {} is too", bound, number))
+                    },
+                    _ => None
+                }
+            })
+    }
+}
+
+fn parse(text: &str) -> Result<Vec<Submission>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| parse_line(line).ok_or_else(|| {
+            Error::parse(index + 1, format!("expected `year day part verdict answer`, found `{}`", line))
+        }))
+        .collect()
+}
+
+fn parse_line(line: &str) -> Option<Submission> {
+    let mut fields = line.splitn(5, ' ');
+    let year = fields.next()?.parse().ok()?;
+    let day = fields.next()?.parse().ok()?;
+    let part = Part::from_number(fields.next()?.parse().ok()?)?;
+    let verdict = Verdict::from_name(fields.next()?)?;
+    let answer = fields.next()?.to_owned();
+    Some(Submission { year, day, part, verdict, answer })
+}
+
+/// Submits `answer` unless the ledger rules it out, recording the verdict.
+pub fn submit(fetcher: &Fetcher, ledger: &mut Ledger, year: u32, day: u32, part: Part, answer: &str) -> std::result::Result<Verdict, String> {
+    if answer.is_empty() || answer.contains('\n') {
+        return Err(format!("{:?} doesn't look like an answer, submit it by hand", answer));
+    }
+    if let Some(reason) = ledger.refusal(year, day, part, answer) {
+        return Err(format!("not submitting: {}", reason));
+    }
+
+    let level = part.to_string();
+    let form = [("level", level.as_str()), ("answer", answer)];
+    let (status, page) = fetcher.request(&format!("/{}/day/{}/answer", year, day), Some(&form))
+        .map_err(|err| err.to_string())?;
+
+    let verdict = match Verdict::parse(&page) {
+        Some(verdict) => verdict,
+        None if page.to_ascii_lowercase().contains("please log in") || status != 200 => {
+            return Err(FetchError::NotLoggedIn.to_string());
+        },
+        None => return Err(FetchError::Unexpected { status, body: page }.to_string())
+    };
+
+    if verdict.is_recorded() {
+        ledger.submissions.push(Submission { year, day, part, verdict, answer: answer.to_owned() });
+        ledger.save().map_err(|err| err.to_string())?;
+    }
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_verdicts() {
+        let page = "<article><p>That's not the right answer; your answer is too high.  If you're stuck...</p></article>";
+        assert_eq!(Some(Verdict::TooHigh), Verdict::parse(page));
+
+        let page = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 7s left to wait.</p></article>";
+        assert_eq!(Some(Verdict::RateLimited(Some(Duration::from_secs(67)))), Verdict::parse(page));
+    }
+
+    #[test]
+    fn refuses_answers_ruled_out() {
+        let ledger = Ledger {
+            path: PathBuf::new(),
+            submissions: parse("2022 1 1 too-high 100\n2022 1 1 wrong 42\n2022 1 2 correct 7\n").unwrap()
+        };
+        assert!(ledger.refusal(2022, 1, Part::One, "100").is_some());
+        assert!(ledger.refusal(2022, 1, Part::One, "150").is_some());
+        assert!(ledger.refusal(2022, 1, Part::One, "42").is_some());
+        assert!(ledger.refusal(2022, 1, Part::Two, "8").is_some());
+        assert_eq!(None, ledger.refusal(2022, 1, Part::One, "99"));
+        assert_eq!(None, ledger.refusal(2022, 2, Part::One, "100"));
+    }
+}
@@ -0,0 +1,64 @@
+//! Runs every day against the answers recorded in `answers.txt`. Inputs that
+//! aren't checked in (puzzle inputs usually aren't) are skipped rather than
+//! failed, so the suite passes on a fresh clone and tightens as inputs appear.
+//! Only recorded parts are checked: a sample often defines one part's answer
+//! and not the other's, so a part without a recorded answer proves nothing.
+
+use std::collections::BTreeSet;
+
+use aoc::answers;
+
+#[test]
+fn answers_match_recorded() {
+    let recorded = answers::load().unwrap();
+
+    let inputs = recorded.iter()
+        .map(|entry| (entry.year, entry.day, entry.input.clone()))
+        .collect::<BTreeSet<_>>();
+
+    let mut failures = Vec::new();
+    let mut skipped = 0;
+    let mut checked = 0;
+
+    for (year, day, input) in inputs {
+        let expected = recorded.iter()
+            .filter(|entry| (entry.year, entry.day, entry.input.as_str()) == (year, day, input.as_str()))
+            .collect::<Vec<_>>();
+
+        let Some(solution) = aoc::find(year, day) else {
+            failures.push(format!("{} day {}: no solution registered", year, day));
+            continue;
+        };
+
+        let path = solution.dir().join(&input);
+        if !path.exists() {
+            skipped += expected.len();
+            continue;
+        }
+
+        let parts = expected.iter().map(|entry| entry.part).collect::<Vec<_>>();
+        let answers = match solution.run(&parts, &path.to_string_lossy()) {
+            Ok(answers) => answers,
+            Err(err) => {
+                failures.push(format!("{} day {} {}: {}", year, day, input, err));
+                continue;
+            }
+        };
+
+        for (entry, answer) in expected.iter().zip(answers) {
+            let answer = answer.to_string();
+            if entry.answer != answer {
+                failures.push(format!("{} day {} part {} {}: expected {:?}, got {:?}",
+                    year, day, entry.part, input, entry.answer, answer));
+            } else {
+                checked += 1;
+            }
+        }
+    }
+
+    if skipped > 0 {
+        eprintln!("skipped {} recorded answers whose input isn't present", skipped);
+    }
+    assert!(failures.is_empty(), "{} answers differ:\n{}", failures.len(), failures.join("\n"));
+    assert!(checked > 0, "no recorded answer was checked; is answers.txt empty?");
+}
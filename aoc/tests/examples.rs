@@ -0,0 +1,30 @@
+//! Checks every day against the examples in its `examples.txt`.
+
+use aoc::examples;
+
+#[test]
+fn examples_match() {
+    let mut failures = Vec::new();
+
+    for day in aoc::DAYS {
+        let day_examples = match examples::load(day) {
+            Ok(day_examples) => day_examples,
+            Err(err) => {
+                failures.push(err.to_string());
+                continue;
+            }
+        };
+
+        for example in day_examples {
+            let name = format!("{} day {} example {}", day.year, day.day, example.name);
+            match examples::check(day, &example) {
+                Ok(mismatches) => failures.extend(mismatches.into_iter().map(|mismatch| {
+                    format!("{} part {}: expected {:?}, got {:?}", name, mismatch.part, mismatch.expected, mismatch.actual)
+                })),
+                Err(err) => failures.push(format!("{}: {}", name, err))
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{} examples differ:\n{}", failures.len(), failures.join("\n"));
+}
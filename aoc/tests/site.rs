@@ -0,0 +1,120 @@
+//! Runs the input fetcher and answer submission against a local stub of the site.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::fetch::{FetchError, Fetcher};
+use aoc::submit::{self, Ledger, Verdict};
+use aoc::Part;
+
+/// Serves `(status, body)` for every request and keeps each request's line
+/// and body.
+fn stub(status: u16, body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let seen = requests.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            reader.read_line(&mut request).unwrap();
+            let mut length = 0;
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    length = value.trim().parse().unwrap();
+                }
+                line.clear();
+            }
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+
+            seen.lock().unwrap().push(format!("{}{}", request, String::from_utf8(content).unwrap()));
+            write!(stream, "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status, body.len(), body).unwrap();
+        }
+    });
+
+    (url, requests)
+}
+
+fn fetcher(name: &str, base_url: String, min_interval: Duration) -> Fetcher {
+    let cache_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&cache_dir);
+    Fetcher { base_url, session: Some("cookie".to_owned()), cache_dir, min_interval }
+}
+
+#[test]
+fn caches_the_input() {
+    let (url, requests) = stub(200, "1\n2\n3\n");
+    let fetcher = fetcher("caches_the_input", url, Duration::ZERO);
+
+    assert_eq!("1\n2\n3\n", fetcher.fetch(2022, 1).unwrap());
+    assert_eq!("1\n2\n3\n", fetcher.fetch(2022, 1).unwrap());
+    assert_eq!(1, requests.lock().unwrap().len());
+    assert_eq!("1\n2\n3\n", fs::read_to_string(fetcher.input_path(2022, 1)).unwrap());
+}
+
+#[test]
+fn saves_where_the_runner_reads() {
+    let (url, _) = stub(200, "0,3,6\n");
+    let fetcher = fetcher("saves_where_the_runner_reads", url, Duration::ZERO);
+
+    fetcher.fetch(2020, 15).unwrap();
+    let path = fetcher.cache_dir.join(aoc::find(2020, 15).unwrap().dir).join("input.txt");
+    assert!(path.ends_with("2020/dayF/input.txt"), "{}", path.display());
+    assert_eq!("0,3,6\n", fs::read_to_string(path).unwrap());
+}
+
+#[test]
+fn does_not_save_the_login_page() {
+    let (url, _) = stub(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n");
+    let fetcher = fetcher("does_not_save_the_login_page", url, Duration::ZERO);
+
+    assert!(matches!(fetcher.fetch(2022, 1), Err(FetchError::NotLoggedIn)));
+    assert!(!fetcher.input_path(2022, 1).exists());
+}
+
+#[test]
+fn does_not_save_a_locked_day() {
+    let (url, _) = stub(404, "Please don't repeatedly request this endpoint before it unlocks!\n");
+    let fetcher = fetcher("does_not_save_a_locked_day", url, Duration::ZERO);
+
+    assert!(matches!(fetcher.fetch(2022, 25), Err(FetchError::NotFound { year: 2022, day: 25 })));
+    assert!(!fetcher.input_path(2022, 25).exists());
+}
+
+#[test]
+fn spaces_out_requests() {
+    let (url, requests) = stub(200, "input\n");
+    let fetcher = fetcher("spaces_out_requests", url, Duration::from_millis(300));
+
+    let start = Instant::now();
+    fetcher.fetch(2022, 1).unwrap();
+    fetcher.fetch(2022, 2).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(2, requests.lock().unwrap().len());
+}
+
+#[test]
+fn records_verdicts_and_refuses_known_wrong_answers() {
+    let (url, requests) = stub(200, "<main><article><p>That's not the right answer; your answer is too low.</p></article></main>");
+    let fetcher = fetcher("records_verdicts_and_refuses_known_wrong_answers", url, Duration::ZERO);
+
+    let mut ledger = Ledger::load(&fetcher).unwrap();
+    assert_eq!(Ok(Verdict::TooLow), submit::submit(&fetcher, &mut ledger, 2022, 17, Part::Two, "1000"));
+    assert_eq!("POST /2022/day/17/answer HTTP/1.1\r\nlevel=2&answer=1000", requests.lock().unwrap()[0]);
+
+    let mut ledger = Ledger::load(&fetcher).unwrap();
+    assert!(submit::submit(&fetcher, &mut ledger, 2022, 17, Part::Two, "999").is_err());
+    assert_eq!(1, requests.lock().unwrap().len());
+}
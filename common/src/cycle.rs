@@ -0,0 +1,147 @@
+//! Skipping ahead in simulations that eventually repeat themselves.
+//!
+//! Puzzles that ask for the state after a billion steps of something almost
+//! always fall into a loop long before then. [`find_cycle`] spots the loop by
+//! remembering a key for every state it's seen, so the key can leave out
+//! whatever doesn't affect what happens next, and [`brent`] compares states
+//! directly, keeping only two at a time. Either gives a [`Cycle`], which says
+//! which early step any later step matches and can extrapolate anything that
+//! grows by the same amount each time round.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The state after `start` steps comes round again every `length` steps
+/// from then on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize
+}
+
+impl Cycle {
+    /// The first step in the same state as `step`, which is before
+    /// `start + length`.
+    pub fn equivalent(&self, step: usize) -> usize {
+        match step < self.start {
+            true => step,
+            false => self.start + (step - self.start) % self.length
+        }
+    }
+
+    /// How many times round the cycle it takes to get from
+    /// `equivalent(step)` to `step`.
+    pub fn laps(&self, step: usize) -> usize {
+        match step < self.start {
+            true => 0,
+            false => (step - self.start) / self.length
+        }
+    }
+
+    /// The value at `step` of something that goes up by the same amount each
+    /// time round the cycle, like the height of a tower, where `measure`
+    /// gives its value at any step up to `start + length`.
+    pub fn extrapolate<F>(&self, step: usize, mut measure: F) -> u64 where F: FnMut(usize) -> u64 {
+        let laps = self.laps(step) as u64;
+        let value = measure(self.equivalent(step));
+        match laps {
+            0 => value,
+            _ => value + laps * (measure(self.start + self.length) - measure(self.start))
+        }
+    }
+}
+
+/// Runs `step` from `state` until a state has the same `key` as one before
+/// it. Only the keys are kept, so they should be small and need only
+/// include what decides the states that follow.
+pub fn find_cycle<S, K, F, G>(mut state: S, mut step: F, mut key: G) -> Cycle
+    where F: FnMut(&mut S), G: FnMut(&S) -> K, K: Eq + Hash
+{
+    let mut seen = HashMap::new();
+    let mut index = 0;
+    loop {
+        match seen.entry(key(&state)) {
+            Entry::Occupied(entry) => return Cycle { start: *entry.get(), length: index - entry.get() },
+            Entry::Vacant(entry) => entry.insert(index)
+        };
+        step(&mut state);
+        index += 1;
+    }
+}
+
+/// Brent's cycle detection, which compares whole states and only keeps two
+/// of them, for when the states are cheap to compare but too big to keep
+/// every one of.
+pub fn brent<S, F>(start: S, mut step: F) -> Cycle where S: Clone + Eq, F: FnMut(&mut S) {
+    // the hare runs ahead while the tortoise waits at each power of two, so
+    // the hare comes back round to it within a lap of entering the cycle
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    step(&mut hare);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        step(&mut hare);
+        length += 1;
+    }
+
+    // with the hare a lap ahead, they meet where the cycle starts
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..length {
+        step(&mut hare);
+    }
+    let mut cycle_start = 0;
+    while tortoise != hare {
+        step(&mut tortoise);
+        step(&mut hare);
+        cycle_start += 1;
+    }
+
+    Cycle { start: cycle_start, length }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 6, 7, 3, 4, ...
+    fn next(n: &mut u32) {
+        *n = if *n == 7 { 3 } else { *n + 1 };
+    }
+
+    #[test]
+    fn finds_where_it_repeats() {
+        let cycle = Cycle { start: 3, length: 5 };
+        assert_eq!(cycle, find_cycle(0, next, |n| *n));
+        assert_eq!(cycle, brent(0, next));
+        assert_eq!(Cycle { start: 0, length: 5 }, brent(4, next));
+    }
+
+    #[test]
+    fn keys_can_merge_states() {
+        // the step count never repeats, but nothing that follows depends on it
+        let step = |(n, steps): &mut (u32, usize)| {
+            next(n);
+            *steps += 1;
+        };
+        assert_eq!(Cycle { start: 3, length: 5 }, find_cycle((0, 0), step, |(n, _)| *n));
+    }
+
+    #[test]
+    fn skips_whole_laps() {
+        let cycle = Cycle { start: 3, length: 5 };
+        assert_eq!((2, 0), (cycle.equivalent(2), cycle.laps(2)));
+        assert_eq!((5, 2), (cycle.equivalent(15), cycle.laps(15)));
+        // twice the step, plus one every time round the cycle
+        let measure = |step: usize| (step * 2 + (step.max(3) - 3) / 5) as u64;
+        assert_eq!(measure(1_000_003), cycle.extrapolate(1_000_003, measure));
+        assert_eq!(4, cycle.extrapolate(2, measure));
+    }
+}
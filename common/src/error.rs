@@ -0,0 +1,62 @@
+use std::error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The input file could not be read.
+    Io {
+        path: PathBuf,
+        source: io::Error
+    },
+    /// A token in the input could not be parsed. `line` is 1-based.
+    Parse {
+        path: Option<PathBuf>,
+        line: usize,
+        message: String
+    }
+}
+
+impl Error {
+    pub fn parse(line: usize, message: impl Into<String>) -> Error {
+        Error::Parse { path: None, line, message: message.into() }
+    }
+
+    /// Attaches the file a parse error came from, leaving I/O errors as they are.
+    pub fn in_file(self, file: impl Into<PathBuf>) -> Error {
+        match self {
+            Error::Parse { path: None, line, message } => {
+                Error::Parse { path: Some(file.into()), line, message }
+            },
+            other => other
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => {
+                write!(f, "failed to read {}: {}", path.display(), source)
+            },
+            Error::Parse { path: Some(path), line, message } => {
+                write!(f, "{}:{}: {}", path.display(), line, message)
+            },
+            Error::Parse { path: None, line, message } => {
+                write!(f, "line {}: {}", line, message)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse { .. } => None
+        }
+    }
+}
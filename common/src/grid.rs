@@ -0,0 +1,267 @@
+//! A rectangular grid of cells, for the many puzzles whose input is a map.
+//!
+//! Points are `(x, y)` with `x` counting columns from the left and `y` rows
+//! from the top, matching how the input reads.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{Error, Result};
+
+pub type Point = (usize, usize);
+
+/// The four orthogonal steps: up, right, down and left.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// All eight steps to a neighbouring cell, diagonals included.
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    // row by row
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(width * height, cells.len(), "a {}x{} grid needs {} cells", width, height, width * height);
+        Grid { width, height, cells }
+    }
+
+    /// Panics if the rows aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(rows.iter().all(|row| row.len() == width), "rows have different lengths");
+        Grid::new(width, height, rows.into_iter().flatten().collect())
+    }
+
+    /// One row per non-blank line, turning each character into a cell with
+    /// `cell`, which returns None for characters that don't belong.
+    pub fn parse<F>(input: &str, mut cell: F) -> Result<Grid<T>> where F: FnMut(char) -> Option<T> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (index, line) in input.lines().enumerate().filter(|(_, line)| !line.is_empty()) {
+            let before = cells.len();
+            for c in line.chars() {
+                let value = cell(c)
+                    .ok_or_else(|| Error::parse(index + 1, format!("unexpected character '{}'", c)))?;
+                cells.push(value);
+            }
+
+            let length = cells.len() - before;
+            match width {
+                Some(width) if width != length =>
+                    return Err(Error::parse(index + 1, format!("expected a row of {} cells, found {}", width, length))),
+                _ => width = Some(length)
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Point) -> Option<&T> {
+        match x < self.width && y < self.height {
+            true => Some(&self.cells[y * self.width + x]),
+            false => None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Point) -> Option<&mut T> {
+        match x < self.width && y < self.height {
+            true => Some(&mut self.cells[y * self.width + x]),
+            false => None
+        }
+    }
+
+    /// The point one `(dx, dy)` step away, if that's still on the grid.
+    pub fn step(&self, (x, y): Point, (dx, dy): (isize, isize)) -> Option<Point> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        match x < self.width && y < self.height {
+            true => Some((x, y)),
+            false => None
+        }
+    }
+
+    /// The up to four points directly above, right of, below and left of
+    /// `point`.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ORTHOGONAL.iter().flat_map(move |direction| self.step(point, *direction))
+    }
+
+    /// The up to eight points around `point`, diagonals included.
+    pub fn all_neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ALL_DIRECTIONS.iter().flat_map(move |direction| self.step(point, *direction))
+    }
+
+    /// Every point, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// The first point, row by row, whose cell matches.
+    pub fn position<F>(&self, matches: F) -> Option<Point> where F: FnMut(&T) -> bool {
+        self.cells.iter()
+            .position(matches)
+            .map(|index| (index % self.width, index / self.width))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside a grid {} wide", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U> where F: FnMut(&T) -> U {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Builds a grid of the given size from the cell each point should hold.
+    fn from_points<F>(width: usize, height: usize, cell: F) -> Grid<T> where F: Fn(Point) -> T {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(cell)
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    /// Rows become columns, flipping the grid over its main diagonal.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_points(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Turned a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        Grid::from_points(self.height, self.width, |(x, y)| self[(y, self.height - 1 - x)].clone())
+    }
+
+    /// Turned a quarter turn anticlockwise, so the top row becomes the left
+    /// column.
+    pub fn rotate_anticlockwise(&self) -> Grid<T> {
+        Grid::from_points(self.height, self.width, |(x, y)| self[(self.width - 1 - y, x)].clone())
+    }
+}
+
+impl Grid<char> {
+    /// The input's characters as they are.
+    pub fn chars(input: &str) -> Result<Grid<char>> {
+        Grid::parse(input, Some)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Point) -> &T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside a {}x{} grid", x, y, self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Point) -> &mut T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside a {}x{} grid", x, y, self.width, self.height);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Each cell printed in turn with a line per row, which for single character
+/// cells draws the grid as it appeared in the input.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_draws() {
+        let grid = Grid::chars("#..\n.#.\n\n").unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&'#'), grid.get((1, 1)));
+        assert_eq!(None, grid.get((3, 0)));
+        assert_eq!("#..\n.#.\n", grid.to_string());
+    }
+
+    #[test]
+    fn parse_reports_the_bad_line() {
+        let err = Grid::parse("12\n3x\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!("line 2: unexpected character 'x'", err.to_string());
+        let err = Grid::chars("..\n...\n").unwrap_err();
+        assert_eq!("line 2: expected a row of 2 cells, found 3", err.to_string());
+    }
+
+    #[test]
+    fn neighbours_stay_on_the_grid() {
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(vec![(1, 0), (0, 1)], grid.neighbours((0, 0)).collect::<Vec<_>>());
+        assert_eq!(8, grid.all_neighbours((1, 1)).count());
+        assert_eq!(3, grid.all_neighbours((2, 2)).count());
+    }
+
+    #[test]
+    fn turns_and_flips() {
+        let grid = Grid::chars("ab\ncd\nef\n").unwrap();
+        assert_eq!("ace\nbdf\n", grid.transpose().to_string());
+        assert_eq!("eca\nfdb\n", grid.rotate_clockwise().to_string());
+        assert_eq!("bdf\nace\n", grid.rotate_anticlockwise().to_string());
+        assert_eq!(grid, grid.rotate_clockwise().rotate_anticlockwise());
+        assert_eq!(vec!['b', 'd', 'f'], grid.column(1).copied().collect::<Vec<_>>());
+        assert_eq!(&['c', 'd'], grid.row(1));
+    }
+}
@@ -0,0 +1,137 @@
+//! Loading and splitting puzzle input.
+//!
+//! The `read_*` functions load a file and hand it to the matching parser so
+//! that a missing file is reported by name instead of panicking. The parsers
+//! work on the input text directly for callers that already have it in memory.
+
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::{Error, Result};
+
+/// Owned lines of an input, for parsers that consume them a section at a time.
+pub type Lines = std::vec::IntoIter<String>;
+
+pub fn read_to_string(file_name: &str) -> Result<String> {
+    let path = Path::new(file_name);
+    fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source
+    })
+}
+
+pub fn read_lines(file_name: &str) -> Result<Vec<String>> {
+    read_to_string(file_name).map(|input| lines(&input))
+}
+
+pub fn read_paragraphs(file_name: &str) -> Result<Vec<Vec<String>>> {
+    read_to_string(file_name).map(|input| paragraphs(&input))
+}
+
+pub fn read_char_grid(file_name: &str) -> Result<Vec<Vec<char>>> {
+    read_to_string(file_name).map(|input| char_grid(&input))
+}
+
+pub fn read_numbers<T: FromStr>(file_name: &str) -> Result<Vec<T>> {
+    read_to_string(file_name)
+        .and_then(|input| numbers(&input).map_err(|err| err.in_file(file_name)))
+}
+
+/// Every line of the input with any trailing `\r` removed.
+pub fn lines(input: &str) -> Vec<String> {
+    input.lines()
+        .map(|line| line.to_owned())
+        .collect()
+}
+
+/// Groups of consecutive non-blank lines. Any number of blank lines
+/// separates one group from the next.
+pub fn paragraphs(input: &str) -> Vec<Vec<String>> {
+    let mut paragraphs = Vec::new();
+    let mut current = Vec::new();
+
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(current);
+                current = Vec::new();
+            }
+        } else {
+            current.push(line.to_owned());
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+
+    paragraphs
+}
+
+/// One row of characters per non-blank line.
+pub fn char_grid(input: &str) -> Vec<Vec<char>> {
+    input.lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.chars().collect())
+        .collect()
+}
+
+/// Every number in the input, whether separated by commas, spaces or
+/// newlines.
+pub fn numbers<T: FromStr>(input: &str) -> Result<Vec<T>> {
+    let mut numbers = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let tokens = line.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|token| !token.is_empty());
+
+        for token in tokens {
+            let number = token.parse::<T>()
+                .map_err(|_| Error::parse(index + 1, format!("invalid number '{}'", token)))?;
+            numbers.push(number);
+        }
+    }
+
+    Ok(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_strip_carriage_returns() {
+        assert_eq!(vec!["a", "", "b"], lines("a\r\n\r\nb\r\n"));
+    }
+
+    #[test]
+    fn paragraphs_split_on_blank_lines() {
+        let input = "1\n2\n\n3\n\n\n4\n5\n";
+        assert_eq!(
+            vec![vec!["1", "2"], vec!["3"], vec!["4", "5"]],
+            paragraphs(input)
+        );
+    }
+
+    #[test]
+    fn char_grid_skips_trailing_blank_line() {
+        assert_eq!(vec![vec!['#', '.'], vec!['.', '#']], char_grid("#.\n.#\n\n"));
+    }
+
+    #[test]
+    fn numbers_accept_commas_and_whitespace() {
+        assert_eq!(vec![3, 4, -3, 1, 2], numbers::<i32>("3,4,-3\n1 2\n").unwrap());
+    }
+
+    #[test]
+    fn numbers_report_the_bad_line() {
+        let err = numbers::<u32>("1\n2\nx\n").unwrap_err();
+        assert_eq!("line 3: invalid number 'x'", err.to_string());
+    }
+
+    #[test]
+    fn missing_file_is_named() {
+        let err = read_lines("does/not/exist.txt").unwrap_err();
+        assert!(err.to_string().starts_with("failed to read does/not/exist.txt"));
+    }
+}
@@ -0,0 +1,420 @@
+//! Ranges of whole numbers, sets of them and regions made from them.
+//!
+//! Puzzles give ranges with both ends included, like `3-7` or `x=10..12`, so
+//! an [`Interval`] includes both its ends too and is never empty: anything
+//! that might leave nothing behind returns an `Option` instead. An
+//! [`IntervalSet`] keeps any number of them merged together, and a [`Region`]
+//! takes one interval for each axis.
+
+use std::ops::{Add, RangeInclusive, Sub};
+
+/// The whole number types intervals can be made of.
+pub trait Discrete: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ONE: Self;
+
+    /// The number after this one, unless this is the largest there is.
+    fn next(self) -> Option<Self>;
+
+    /// How many whole numbers there are from `start` to `end`, both
+    /// included. Panics if that's more than a `u64` holds, which only every
+    /// number of a 64-bit type is.
+    fn count(start: Self, end: Self) -> u64;
+}
+
+macro_rules! discrete {
+    ($($t:ty),*) => {
+        $(impl Discrete for $t {
+            const ONE: $t = 1;
+
+            fn next(self) -> Option<$t> {
+                self.checked_add(1)
+            }
+
+            fn count(start: $t, end: $t) -> u64 {
+                u64::try_from(end as i128 - start as i128 + 1)
+                    .unwrap_or_else(|_| panic!("{}..={} holds more numbers than a u64 can count", start, end))
+            }
+        })*
+    };
+}
+
+discrete!(i32, i64, isize, u32, u64, usize);
+
+/// Every whole number from `start` to `end`, both included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    start: T,
+    end: T
+}
+
+/// The parts of an interval below, inside and above another one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Split<T> {
+    pub below: Option<Interval<T>>,
+    pub inside: Option<Interval<T>>,
+    pub above: Option<Interval<T>>
+}
+
+impl<T: Discrete> Interval<T> {
+    /// Panics if `start` is after `end`.
+    pub fn new(start: T, end: T) -> Interval<T> {
+        assert!(start <= end, "an interval can't start after it ends");
+        Interval { start, end }
+    }
+
+    /// Only `value`.
+    pub fn point(value: T) -> Interval<T> {
+        Interval::new(value, value)
+    }
+
+    /// From `start` to `end` if that includes anything.
+    fn between(start: T, end: T) -> Option<Interval<T>> {
+        match start <= end {
+            true => Some(Interval { start, end }),
+            false => None
+        }
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    pub fn range(&self) -> RangeInclusive<T> {
+        self.start..=self.end
+    }
+
+    /// How many whole numbers it includes.
+    pub fn size(&self) -> u64 {
+        T::count(self.start, self.end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether every number in `other` is in this one too.
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        Interval::between(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The numbers before `value`, and those from `value` on.
+    pub fn split_at(&self, value: T) -> (Option<Interval<T>>, Option<Interval<T>>) {
+        if value <= self.start {
+            (None, Some(*self))
+        } else if value > self.end {
+            (Some(*self), None)
+        } else {
+            // `value` is above `start`, so there's a number before it
+            (Some(Interval::new(self.start, value - T::ONE)), Some(Interval::new(value, self.end)))
+        }
+    }
+
+    /// Cuts this interval where `other` starts and ends.
+    pub fn split(&self, other: &Interval<T>) -> Split<T> {
+        let (below, rest) = self.split_at(other.start);
+        let (inside, above) = match (rest, other.end.next()) {
+            (Some(rest), Some(after)) => rest.split_at(after),
+            (rest, _) => (rest, None)
+        };
+        Split { below, inside, above }
+    }
+
+    /// What's left after taking out `other`, which can be nothing, one
+    /// interval or two.
+    pub fn subtract(&self, other: &Interval<T>) -> impl Iterator<Item = Interval<T>> {
+        let split = self.split(other);
+        split.below.into_iter().chain(split.above)
+    }
+
+    /// The same length of interval starting at `start` instead.
+    pub fn with_start(&self, start: T) -> Interval<T> {
+        Interval::new(start, start + (self.end - self.start))
+    }
+}
+
+/// Whole numbers kept as the fewest intervals that cover them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    // in order, none of them overlapping or touching
+    intervals: Vec<Interval<T>>
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { intervals: Vec::new() }
+    }
+
+    /// Adds every number in `interval`, merging it with any it overlaps or
+    /// touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let first = self.intervals.partition_point(|existing| existing.end.next().is_some_and(|after| after < interval.start));
+        let last = self.intervals.partition_point(|existing| interval.end.next().is_none_or(|after| existing.start <= after));
+
+        let mut merged = interval;
+        if first < last {
+            merged.start = merged.start.min(self.intervals[first].start);
+            merged.end = merged.end.max(self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Takes out every number in `interval`.
+    pub fn remove(&mut self, interval: &Interval<T>) {
+        let first = self.intervals.partition_point(|existing| existing.end < interval.start);
+        let last = self.intervals.partition_point(|existing| existing.start <= interval.end);
+
+        let left = self.intervals[first..last].iter()
+            .flat_map(|existing| existing.subtract(interval))
+            .collect::<Vec<_>>();
+        self.intervals.splice(first..last, left);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|interval| interval.end < value);
+        self.intervals.get(index).is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.iter().chain(other.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        // both are in order, so step through them together, moving on
+        // whichever ends first
+        let mut intervals = Vec::new();
+        let (mut mine, mut theirs) = (self.intervals.iter().peekable(), other.intervals.iter().peekable());
+        while let (Some(a), Some(b)) = (mine.peek(), theirs.peek()) {
+            intervals.extend(a.intersection(b));
+            match a.end < b.end {
+                true => mine.next(),
+                false => theirs.next()
+            };
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The numbers in this set and not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut difference = self.clone();
+        for interval in other.iter() {
+            difference.remove(interval);
+        }
+        difference
+    }
+
+    /// How many whole numbers it includes.
+    pub fn size(&self) -> u64 {
+        self.intervals.iter().map(|interval| interval.size()).sum()
+    }
+
+    /// The intervals, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// How many separate intervals it takes to cover the set.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+}
+
+impl<T: Discrete> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Discrete> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> IntervalSet<T> {
+        IntervalSet { intervals: vec![interval] }
+    }
+}
+
+/// Sorts the intervals once and merges them in a single pass, which beats
+/// inserting them one at a time.
+impl<T: Discrete> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> IntervalSet<T> {
+        let mut sorted = iter.into_iter().collect::<Vec<_>>();
+        sorted.sort();
+
+        let mut intervals = Vec::<Interval<T>>::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if last.end.next().is_none_or(|after| interval.start <= after) => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval)
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+/// Every point whose coordinate on each of the `N` axes is within that
+/// axis's interval.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Region<T, const N: usize> {
+    axes: [Interval<T>; N]
+}
+
+impl<T: Discrete, const N: usize> Region<T, N> {
+    pub fn new(axes: [Interval<T>; N]) -> Region<T, N> {
+        Region { axes }
+    }
+
+    pub fn axes(&self) -> &[Interval<T>; N] {
+        &self.axes
+    }
+
+    pub fn axis(&self, axis: usize) -> Interval<T> {
+        self.axes[axis]
+    }
+
+    /// How many whole points it includes.
+    /// Panics if the volume is more than a `u64` holds.
+    pub fn volume(&self) -> u64 {
+        self.axes.iter()
+            .try_fold(1u64, |volume, interval| volume.checked_mul(interval.size()))
+            .expect("a region's volume is more than a u64 can count")
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.axes.iter().zip(point).all(|(interval, value)| interval.contains(value))
+    }
+
+    pub fn overlaps(&self, other: &Region<T, N>) -> bool {
+        self.axes.iter().zip(other.axes.iter()).all(|(a, b)| a.overlaps(b))
+    }
+
+    pub fn intersection(&self, other: &Region<T, N>) -> Option<Region<T, N>> {
+        let mut axes = self.axes;
+        for (axis, interval) in axes.iter_mut().zip(other.axes.iter()) {
+            *axis = axis.intersection(interval)?;
+        }
+        Some(Region { axes })
+    }
+
+    /// The same region with `interval` for `axis` instead.
+    pub fn with_axis(&self, axis: usize, interval: Interval<T>) -> Region<T, N> {
+        let mut axes = self.axes;
+        axes[axis] = interval;
+        Region { axes }
+    }
+
+    /// The part of the region before `value` along `axis`, and the part from
+    /// `value` on.
+    pub fn split_at(&self, axis: usize, value: T) -> (Option<Region<T, N>>, Option<Region<T, N>>) {
+        let (before, after) = self.axes[axis].split_at(value);
+        (before.map(|before| self.with_axis(axis, before)), after.map(|after| self.with_axis(axis, after)))
+    }
+
+    /// What's left after taking out `other`, as at most two regions per axis
+    /// that don't overlap each other.
+    pub fn subtract(&self, other: &Region<T, N>) -> Vec<Region<T, N>> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+
+        // slice off whatever's outside `other` along each axis in turn,
+        // narrowing what's left to the part inside it
+        let mut pieces = Vec::new();
+        let mut rest = *self;
+        for axis in 0..N {
+            let split = rest.axes[axis].split(&other.axes[axis]);
+            for outside in split.below.into_iter().chain(split.above) {
+                pieces.push(rest.with_axis(axis, outside));
+            }
+            rest = rest.with_axis(axis, split.inside.unwrap());
+        }
+        pieces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_intervals() {
+        let interval = Interval::new(3, 9);
+        assert_eq!(7, interval.size());
+        assert_eq!((Some(Interval::new(3, 4)), Some(Interval::new(5, 9))), interval.split_at(5));
+        assert_eq!((None, Some(interval)), interval.split_at(3));
+        let split = interval.split(&Interval::new(5, 20));
+        assert_eq!((Some(Interval::new(3, 4)), Some(Interval::new(5, 9)), None), (split.below, split.inside, split.above));
+        assert_eq!(vec![Interval::new(3, 4), Interval::new(8, 9)], interval.subtract(&Interval::new(5, 7)).collect::<Vec<_>>());
+        assert_eq!(None, interval.intersection(&Interval::new(10, 12)));
+        assert_eq!(Interval::new(-2, 4), interval.with_start(-2));
+    }
+
+    #[test]
+    fn sets_merge_and_cut() {
+        let mut set = [Interval::new(1, 3), Interval::new(10, 12), Interval::new(4, 5)].into_iter().collect::<IntervalSet<_>>();
+        assert_eq!(vec![Interval::new(1, 5), Interval::new(10, 12)], set.iter().copied().collect::<Vec<_>>());
+        set.insert(Interval::new(6, 9));
+        assert_eq!(1, set.len());
+        set.remove(&Interval::new(4, 6));
+        assert_eq!(9, set.size());
+        assert!(set.contains(3) && !set.contains(5) && set.contains(12));
+
+        let other = IntervalSet::from(Interval::new(2, 8));
+        assert_eq!(vec![Interval::new(2, 3), Interval::new(7, 8)], set.intersection(&other).iter().copied().collect::<Vec<_>>());
+        assert_eq!(vec![Interval::new(1, 1), Interval::new(9, 12)], set.difference(&other).iter().copied().collect::<Vec<_>>());
+        assert_eq!(12, set.union(&other).size());
+    }
+
+    #[test]
+    fn handles_the_largest_numbers() {
+        let top = Interval::new(u64::MAX - 4, u64::MAX);
+        let mut set = [top, Interval::new(u64::MAX - 9, u64::MAX - 5)].into_iter().collect::<IntervalSet<_>>();
+        assert_eq!(vec![Interval::new(u64::MAX - 9, u64::MAX)], set.iter().copied().collect::<Vec<_>>());
+        set.insert(Interval::new(0, 1));
+        set.insert(Interval::point(u64::MAX));
+        set.insert(Interval::new(u64::MAX - 12, u64::MAX - 10));
+        assert_eq!(vec![Interval::new(0, 1), Interval::new(u64::MAX - 12, u64::MAX)], set.iter().copied().collect::<Vec<_>>());
+
+        let split = top.split(&Interval::new(u64::MAX - 2, u64::MAX));
+        assert_eq!((Some(Interval::new(u64::MAX - 4, u64::MAX - 3)), Some(Interval::new(u64::MAX - 2, u64::MAX)), None), (split.below, split.inside, split.above));
+        assert_eq!((Some(Interval::new(u64::MAX - 4, u64::MAX - 1)), Some(Interval::point(u64::MAX))), top.split_at(u64::MAX));
+        assert_eq!((None, Some(Interval::new(i32::MIN, 0))), Interval::new(i32::MIN, 0).split_at(i32::MIN));
+        assert_eq!(u64::MAX, Interval::new(1, u64::MAX).size());
+        assert_eq!(1 << 32, Interval::new(i32::MIN, i32::MAX).size());
+    }
+
+    #[test]
+    #[should_panic(expected = "more numbers than a u64 can count")]
+    fn refuses_to_count_every_u64() {
+        Interval::new(0, u64::MAX).size();
+    }
+
+    #[test]
+    #[should_panic(expected = "more than a u64 can count")]
+    fn refuses_a_volume_too_big_to_count() {
+        Region::new([Interval::new(0, u32::MAX); 3]).volume();
+    }
+
+    #[test]
+    fn subtracting_regions_keeps_the_volume() {
+        let outer = Region::new([Interval::new(0, 9), Interval::new(0, 9), Interval::new(0, 9)]);
+        let inner = Region::new([Interval::new(2, 4), Interval::new(-5, 4), Interval::new(9, 12)]);
+        let pieces = outer.subtract(&inner);
+        let overlap = outer.intersection(&inner).unwrap();
+        assert_eq!(outer.volume() - overlap.volume(), pieces.iter().map(|piece| piece.volume()).sum::<u64>());
+        assert!(pieces.iter().all(|piece| !piece.overlaps(&inner)));
+        assert!(outer.contains([3, 3, 3]) && !pieces.iter().any(|piece| piece.contains([3, 3, 9])));
+    }
+}
@@ -0,0 +1,13 @@
+//! Helpers shared by every day's solution.
+
+pub mod cycle;
+mod error;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod search;
+mod solution;
+
+pub use error::{Error, Result};
+pub use grid::Grid;
+pub use solution::{Answer, Solution};
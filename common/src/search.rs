@@ -0,0 +1,203 @@
+//! Shortest paths through anything that can list the moves out of a state.
+//!
+//! A puzzle implements [`Neighbours`] for whatever describes its moves, with
+//! any hashable `State`: a point on a map, or a point plus the time, the
+//! direction faced or whatever else decides where you can go next. [`bfs`]
+//! counts moves, [`dijkstra`] adds up their costs and [`astar`] does the same
+//! guided by a heuristic. Each takes several starts, searching from all of
+//! them at once, and returns the cheapest [`Path`] to a goal.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+pub trait Neighbours {
+    type State: Clone + Eq + Hash;
+
+    /// Every state one move away from `state`, with what that move costs.
+    /// [`bfs`] ignores the costs, so they can be anything there.
+    fn neighbours(&self, state: &Self::State) -> Vec<(Self::State, u64)>;
+}
+
+/// The cheapest way found to a goal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: u64,
+    /// Every state along the way, from the start through to the goal.
+    pub states: Vec<S>
+}
+
+impl<S> Path<S> {
+    pub fn start(&self) -> &S {
+        &self.states[0]
+    }
+
+    pub fn end(&self) -> &S {
+        &self.states[self.states.len() - 1]
+    }
+}
+
+/// The states seen so far, numbered in the order they were found, with the
+/// cheapest cost to each and the state it was reached from.
+struct Visited<S> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    costs: Vec<u64>,
+    parents: Vec<Option<usize>>
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new() -> Visited<S> {
+        Visited { states: Vec::new(), index: HashMap::new(), costs: Vec::new(), parents: Vec::new() }
+    }
+
+    /// Records reaching `state` for `cost`, returning its number if that's
+    /// cheaper than any way found to it before.
+    fn reach(&mut self, state: &S, cost: u64, parent: Option<usize>) -> Option<usize> {
+        match self.index.get(state) {
+            Some(&index) if self.costs[index] <= cost => None,
+            Some(&index) => {
+                self.costs[index] = cost;
+                self.parents[index] = parent;
+                Some(index)
+            },
+            None => {
+                let index = self.states.len();
+                self.states.push(state.clone());
+                self.index.insert(state.clone(), index);
+                self.costs.push(cost);
+                self.parents.push(parent);
+                Some(index)
+            }
+        }
+    }
+
+    fn path(&self, end: usize) -> Path<S> {
+        let mut states = vec![self.states[end].clone()];
+        let mut current = end;
+        while let Some(parent) = self.parents[current] {
+            states.push(self.states[parent].clone());
+            current = parent;
+        }
+        states.reverse();
+
+        Path { cost: self.costs[end], states }
+    }
+}
+
+/// The path with the fewest moves from any of `starts` to a state where
+/// `is_goal` holds.
+pub fn bfs<G, I, F>(graph: &G, starts: I, mut is_goal: F) -> Option<Path<G::State>>
+    where G: Neighbours, I: IntoIterator<Item = G::State>, F: FnMut(&G::State) -> bool
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Some(index) = visited.reach(&start, 0, None) {
+            queue.push_back(index);
+        }
+    }
+
+    while let Some(index) = queue.pop_front() {
+        let state = visited.states[index].clone();
+        if is_goal(&state) {
+            return Some(visited.path(index));
+        }
+        let cost = visited.costs[index] + 1;
+        for (next, _) in graph.neighbours(&state) {
+            // the first time a state's found in a breadth first search is
+            // always the fewest moves to it
+            if !visited.index.contains_key(&next) {
+                let next = visited.reach(&next, cost, Some(index)).unwrap();
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// The cheapest path from any of `starts` to a state where `is_goal` holds.
+pub fn dijkstra<G, I, F>(graph: &G, starts: I, is_goal: F) -> Option<Path<G::State>>
+    where G: Neighbours, I: IntoIterator<Item = G::State>, F: FnMut(&G::State) -> bool
+{
+    astar(graph, starts, is_goal, |_| 0)
+}
+
+/// The cheapest path from any of `starts` to a state where `is_goal` holds,
+/// trying first the states `heuristic` estimates are closest to a goal. The
+/// estimate must never be more than the real cost, or the path found might
+/// not be the cheapest.
+pub fn astar<G, I, F, H>(graph: &G, starts: I, mut is_goal: F, heuristic: H) -> Option<Path<G::State>>
+    where G: Neighbours, I: IntoIterator<Item = G::State>, F: FnMut(&G::State) -> bool, H: Fn(&G::State) -> u64
+{
+    let mut visited = Visited::new();
+    let mut frontier = BinaryHeap::new();
+    for start in starts {
+        if let Some(index) = visited.reach(&start, 0, None) {
+            frontier.push(Reverse((heuristic(&start), 0, index)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = frontier.pop() {
+        if cost > visited.costs[index] {
+            // a cheaper way here has been found since this was queued
+            continue;
+        }
+        let state = visited.states[index].clone();
+        if is_goal(&state) {
+            return Some(visited.path(index));
+        }
+        for (next, step) in graph.neighbours(&state) {
+            let next_cost = cost + step;
+            if let Some(next_index) = visited.reach(&next, next_cost, Some(index)) {
+                frontier.push(Reverse((next_cost + heuristic(&next), next_cost, next_index)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a line of stepping stones where each move goes one or two along, and
+    // costs the number of the stone landed on
+    struct Stones(u64);
+
+    impl Neighbours for Stones {
+        type State = u64;
+
+        fn neighbours(&self, &stone: &u64) -> Vec<(u64, u64)> {
+            [stone + 1, stone + 2].into_iter()
+                .filter(|next| *next <= self.0)
+                .map(|next| (next, next))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn bfs_counts_moves() {
+        let path = bfs(&Stones(7), [0], |stone| *stone == 7).unwrap();
+        assert_eq!(4, path.cost);
+        assert_eq!(5, path.states.len());
+        assert_eq!((&0, &7), (path.start(), path.end()));
+    }
+
+    #[test]
+    fn dijkstra_adds_up_costs() {
+        let path = dijkstra(&Stones(7), [0], |stone| *stone == 7).unwrap();
+        assert_eq!(vec![0, 1, 3, 5, 7], path.states);
+        assert_eq!(16, path.cost);
+        assert_eq!(None, dijkstra(&Stones(7), [0], |stone| *stone == 8));
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let path = astar(&Stones(20), [0, 1], |stone| *stone == 20, |stone| 20 - stone).unwrap();
+        assert_eq!(dijkstra(&Stones(20), [0, 1], |stone| *stone == 20).unwrap().cost, path.cost);
+        assert_eq!(&1, path.start());
+    }
+}
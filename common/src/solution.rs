@@ -0,0 +1,88 @@
+use std::fmt;
+
+use crate::Result;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// The part hasn't been solved yet.
+    Unsolved
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "incomplete")
+        }
+    }
+}
+
+macro_rules! from_number {
+    ($($number:ty),*) => {
+        $(impl From<$number> for Answer {
+            fn from(number: $number) -> Answer {
+                Answer::Number(number as i128)
+            }
+        })*
+    };
+}
+
+from_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(number: u128) -> Answer {
+        match i128::try_from(number) {
+            Ok(number) => Answer::Number(number),
+            Err(_) => Answer::Text(number.to_string())
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_owned())
+    }
+}
+
+/// A day's puzzle. `parse` turns the raw input into whatever both parts
+/// work from, so reading and parsing stay out of the parts themselves.
+pub trait Solution {
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+
+    fn part_one(parsed: &Self::Parsed) -> Answer;
+
+    fn part_two(parsed: &Self::Parsed) -> Answer;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_display_plainly() {
+        assert_eq!("-42", Answer::from(-42i64).to_string());
+        assert_eq!("18446744073709551615", Answer::from(u64::MAX).to_string());
+    }
+
+    #[test]
+    fn huge_unsigned_falls_back_to_text() {
+        assert_eq!(Answer::Text(u128::MAX.to_string()), Answer::from(u128::MAX));
+    }
+
+    #[test]
+    fn unsolved_reads_incomplete() {
+        assert_eq!("incomplete", Answer::Unsolved.to_string());
+    }
+}
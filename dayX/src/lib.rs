@@ -0,0 +1,20 @@
+use aoc_common::input::lines;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part_one(_lines: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part_two(_lines: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+}
@@ -1,30 +0,0 @@
-package ag.aoc;
-
-import java.io.BufferedReader;
-import java.io.FileNotFoundException;
-import java.io.FileReader;
-import java.io.IOException;
-
-public class DayX {
-
-    public static void partOne(String file) throws FileNotFoundException, IOException {
-        try (BufferedReader reader = new BufferedReader(new FileReader(file))) {
-            
-        }
-
-        System.out.println("Part 1: incomplete");
-    }
-    
-    public static void partTwo(String file) throws FileNotFoundException, IOException {
-        try (BufferedReader reader = new BufferedReader(new FileReader(file))) {
-            
-        }
-
-        System.out.println("Part 2: incomplete");
-    }
-
-    public static void main(String[] args) throws FileNotFoundException, IOException {
-        partOne("input.txt");
-        partOne("input.txt");
-    }
-}
\ No newline at end of file